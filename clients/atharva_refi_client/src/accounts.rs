@@ -0,0 +1,13 @@
+use anchor_lang::prelude::*;
+use anchor_lang::AccountDeserialize;
+use atharva_refi::states::Pool;
+
+/// Decodes any program account, checking its discriminator
+pub fn decode<T: AccountDeserialize>(data: &[u8]) -> Result<T> {
+    let mut data = data;
+    T::try_deserialize(&mut data)
+}
+
+pub fn decode_pool(data: &[u8]) -> Result<Pool> {
+    decode(data)
+}
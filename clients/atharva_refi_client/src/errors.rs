@@ -0,0 +1,146 @@
+use anchor_lang::error::ERROR_CODE_OFFSET;
+pub use atharva_refi::errors::ErrorCode;
+
+/// `ErrorCode` variants in declaration order, so index + offset is the code
+const ERROR_CODES: [ErrorCode; 80] = [
+    // Authorization Errors
+    ErrorCode::CreatePoolUnauthorized,
+    ErrorCode::StakingUnauthorized,
+    ErrorCode::UnauthorizedStream,
+    ErrorCode::InvalidOrganizationAuthority,
+    ErrorCode::UnauthorizedOrganization,
+    ErrorCode::AdminUnauthorized,
+    ErrorCode::PoolConfigUnauthorized,
+    ErrorCode::PendingAuthorityMismatch,
+    // State & Validation Errors
+    ErrorCode::MathError,
+    ErrorCode::PoolNotActive,
+    ErrorCode::InsufficientFunds,
+    ErrorCode::InsufficientWithdrawFunds,
+    ErrorCode::InvalidAmount,
+    ErrorCode::AmountTooSmall,
+    ErrorCode::InvalidStringLength,
+    ErrorCode::InvalidInput,
+    // Staking & Yield Errors
+    ErrorCode::MarinadeProgramError,
+    ErrorCode::SettlementTooFrequent,
+    ErrorCode::YieldTooSmall,
+    ErrorCode::StreamRecordRequired,
+    ErrorCode::CooldownTooShort,
+    ErrorCode::InvalidYieldPercentage,
+    ErrorCode::InvalidMarinadeState,
+    ErrorCode::InvalidMsolAccount,
+    ErrorCode::InvalidMsolMint,
+    ErrorCode::MissingOrganizationMsolAccount,
+    ErrorCode::InsufficientLiquidity,
+    ErrorCode::SlippageExceeded,
+    // MagicBlock Crank Errors
+    ErrorCode::InvalidMagicProgram,
+    ErrorCode::IntervalTooShort,
+    ErrorCode::InvalidIterations,
+    ErrorCode::SerializationError,
+    ErrorCode::CrankAlreadyScheduled,
+    ErrorCode::CrankNotScheduled,
+    ErrorCode::UnauthorizedCrankAuthority,
+    // Pool Lifecycle Errors
+    ErrorCode::InsufficientShares,
+    ErrorCode::PoolEmpty,
+    ErrorCode::InvalidTokenAccount,
+    // Organization Authority Errors
+    ErrorCode::InvalidNewAuthority,
+    ErrorCode::NoPendingAuthorityRotation,
+    ErrorCode::AuthorityRotationTimelocked,
+    ErrorCode::AdminRotationPending,
+    ErrorCode::RecoveryDelayTooShort,
+    // Organization Withdrawal Controls Errors
+    ErrorCode::WithdrawalCapExceeded,
+    ErrorCode::WithdrawalQueueDisabled,
+    ErrorCode::WithdrawalStillLocked,
+    ErrorCode::VetoWindowClosed,
+    ErrorCode::WithdrawalControlsLoosened,
+    // Field Report Errors
+    ErrorCode::InvalidReportPeriod,
+    ErrorCode::InvalidCoveredEntries,
+    // Impact Metrics Errors
+    ErrorCode::AttesterNotActive,
+    ErrorCode::InvalidSurveyDate,
+    // Milestone Escrow Errors
+    ErrorCode::EscrowRequiresSolPayout,
+    ErrorCode::FallbackBeneficiaryNotSet,
+    ErrorCode::InvalidMilestone,
+    ErrorCode::MilestoneNotPending,
+    ErrorCode::MilestoneDeadlinePassed,
+    ErrorCode::MilestoneDeadlineNotReached,
+    ErrorCode::InsufficientEscrowBalance,
+    ErrorCode::MilestoneAttesterMismatch,
+    ErrorCode::MilestonesLocked,
+    ErrorCode::EscrowModeEnabled,
+    ErrorCode::MilestoneOutOfOrder,
+    // Organization Standing Errors
+    ErrorCode::OrganizationNotActive,
+    ErrorCode::OrganizationNotSuspended,
+    ErrorCode::PoolNotReassigned,
+    ErrorCode::ReviewWindowNotElapsed,
+    // Organization Registry Errors
+    ErrorCode::OrganizationNotVerified,
+    ErrorCode::OrganizationAlreadyApplied,
+    ErrorCode::ApplicationNotPending,
+    ErrorCode::InvalidRejectionReason,
+    ErrorCode::SpeciesNotApproved,
+    // Pool Registry Errors
+    ErrorCode::RegistryPageFull,
+    ErrorCode::PoolNotListed,
+    // Pool History Errors
+    ErrorCode::SnapshotTooFrequent,
+    // Impact Badge Errors
+    ErrorCode::InvalidBadgeTier,
+    ErrorCode::InvalidBadgeThresholds,
+    ErrorCode::BadgeTierNotConfigured,
+    ErrorCode::BadgeThresholdNotReached,
+    ErrorCode::BadgeAlreadyClaimed,
+];
+
+/// Maps a custom program error code, as returned in
+/// `InstructionError::Custom`, back to its `ErrorCode`
+pub fn decode_error(code: u32) -> Option<ErrorCode> {
+    let index = code.checked_sub(ERROR_CODE_OFFSET)?;
+    let error = *ERROR_CODES.get(index as usize)?;
+
+    // Guards against the list drifting from the program's enum
+    (u32::from(error) == code).then_some(error)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Variant names in declaration order, read from the program's source
+    fn program_variants() -> Vec<&'static str> {
+        include_str!("../../../programs/atharva_refi/src/errors.rs")
+            .lines()
+            .filter_map(|line| line.strip_prefix("    ")?.strip_suffix(','))
+            .filter(|name| name.starts_with(|c: char| c.is_ascii_uppercase()))
+            .collect()
+    }
+
+    #[test]
+    fn decodes_every_program_error() {
+        let names = program_variants();
+        assert_eq!(names.len(), ERROR_CODES.len());
+
+        for (index, name) in names.iter().enumerate() {
+            let code = ERROR_CODE_OFFSET + index as u32;
+            let error = decode_error(code).unwrap_or_else(|| panic!("{name} not decoded"));
+
+            assert_eq!(error.name(), *name);
+            assert_eq!(u32::from(error), code);
+        }
+    }
+
+    #[test]
+    fn rejects_codes_outside_the_program_range() {
+        assert!(decode_error(0).is_none());
+        assert!(decode_error(ERROR_CODE_OFFSET - 1).is_none());
+        assert!(decode_error(ERROR_CODE_OFFSET + ERROR_CODES.len() as u32).is_none());
+    }
+}
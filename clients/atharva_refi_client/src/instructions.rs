@@ -0,0 +1,2145 @@
+//! Typed builders for every program instruction
+//!
+//! Architecture:
+//! - Builders take signers and a pool's `PoolAddresses`, and derive every
+//!   other PDA, ATA and Marinade account themselves
+//! - Sequence-numbered accounts (stream records, queued withdrawals, field
+//!   reports, milestones, registry pages) take the number the program will
+//!   read from on-chain state, so callers fetch it first
+//! - Read-only views return their result through simulation return data
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::instruction::Instruction;
+use anchor_lang::{system_program, InstructionData};
+use anchor_spl::{associated_token, token, token_2022};
+use atharva_refi::states::{
+    IucnCategory, MetricKind, PayoutAsset, PublishFieldReportArgs, ScheduleStreamArgs,
+    SpendingCategory,
+};
+use atharva_refi::{accounts, instruction, ID};
+use ephemeral_rollups_sdk::consts::{MAGIC_CONTEXT_ID, MAGIC_PROGRAM_ID};
+
+use crate::marinade::{
+    LIQ_POOL_MSOL_LEG, LIQ_POOL_MSOL_LEG_AUTHORITY, LIQ_POOL_SOL_LEG, MARINADE_PROGRAM_ID,
+    MARINADE_STATE, MSOL_MINT, MSOL_MINT_AUTHORITY, RESERVE_PDA, TREASURY_MSOL_ACCOUNT,
+};
+use crate::pda::{self, PoolAddresses};
+
+fn build(accounts: impl ToAccountMetas, data: impl InstructionData) -> Instruction {
+    Instruction {
+        program_id: ID,
+        accounts: accounts.to_account_metas(None),
+        data: data.data(),
+    }
+}
+
+// Organization & Species Registry
+
+pub fn register_organization(
+    admin: &Pubkey,
+    organization_pubkey: Pubkey,
+    name: String,
+    metadata_uri: String,
+) -> Instruction {
+    build(
+        accounts::RegisterOrganization {
+            admin: *admin,
+            organization: pda::organization(&organization_pubkey),
+            system_program: system_program::ID,
+            event_authority: pda::event_authority(),
+            program: ID,
+        },
+        instruction::RegisterOrganization {
+            organization_pubkey,
+            name,
+            metadata_uri,
+        },
+    )
+}
+
+pub fn apply_organization(
+    applicant: &Pubkey,
+    name: String,
+    metadata_uri: String,
+    document_hash: [u8; 32],
+    species_ids: Vec<[u8; 32]>,
+) -> Instruction {
+    build(
+        accounts::ApplyOrganization {
+            applicant: *applicant,
+            organization: pda::organization(applicant),
+            system_program: system_program::ID,
+            event_authority: pda::event_authority(),
+            program: ID,
+        },
+        instruction::ApplyOrganization {
+            name,
+            metadata_uri,
+            document_hash,
+            species_ids,
+        },
+    )
+}
+
+fn review_organization(
+    admin: &Pubkey,
+    organization_pubkey: &Pubkey,
+) -> accounts::ReviewOrganization {
+    accounts::ReviewOrganization {
+        admin: *admin,
+        organization: pda::organization(organization_pubkey),
+        event_authority: pda::event_authority(),
+        program: ID,
+    }
+}
+
+pub fn approve_organization(admin: &Pubkey, organization_pubkey: &Pubkey) -> Instruction {
+    build(
+        review_organization(admin, organization_pubkey),
+        instruction::ApproveOrganization {},
+    )
+}
+
+pub fn reject_organization(
+    admin: &Pubkey,
+    organization_pubkey: &Pubkey,
+    reason_code: u16,
+) -> Instruction {
+    build(
+        review_organization(admin, organization_pubkey),
+        instruction::RejectOrganization { reason_code },
+    )
+}
+
+pub fn register_species(
+    admin: &Pubkey,
+    scientific_name: String,
+    common_name: String,
+    iucn_category: IucnCategory,
+    region: String,
+    metadata_uri: String,
+) -> Instruction {
+    build(
+        accounts::RegisterSpecies {
+            admin: *admin,
+            species: pda::species(&pda::species_id(&scientific_name)),
+            system_program: system_program::ID,
+            event_authority: pda::event_authority(),
+            program: ID,
+        },
+        instruction::RegisterSpecies {
+            scientific_name,
+            common_name,
+            iucn_category,
+            region,
+            metadata_uri,
+        },
+    )
+}
+
+// Pool Lifecycle
+
+/// Page of each index a pool is listed in. For a new pool, the current
+/// page from `RegistryPage::page_for(pool_count)` of the registry, species
+/// and organization
+#[derive(Clone, Copy, Debug, Default)]
+pub struct RegistryPages {
+    pub registry: u32,
+    pub species: u32,
+    pub organization: u32,
+}
+impl RegistryPages {
+    /// Global, species and organization page addresses for `pool`
+    fn addresses(&self, pool: &PoolAddresses) -> [Pubkey; 3] {
+        [
+            pda::registry_page(&pda::pool_registry(), self.registry),
+            pda::registry_page(&pda::species(&pool.species_id), self.species),
+            pda::registry_page(
+                &pda::organization(&pool.organization_pubkey),
+                self.organization,
+            ),
+        ]
+    }
+}
+
+pub fn create_pool(authority: &Pubkey, pool: &PoolAddresses, pages: RegistryPages) -> Instruction {
+    let organization = pda::organization(&pool.organization_pubkey);
+    let species = pda::species(&pool.species_id);
+    let pool_registry = pda::pool_registry();
+
+    build(
+        accounts::CreatePool {
+            authority: *authority,
+            organization,
+            species,
+            msol_mint: MSOL_MINT,
+            pool: pool.pool,
+            pool_mint: pool.pool_mint,
+            pool_vault: pool.pool_vault,
+            organization_vault: pool.org_vault,
+            escrow_vault: pool.escrow_vault,
+            pool_registry,
+            registry_page: pda::registry_page(&pool_registry, pages.registry),
+            species_page: pda::registry_page(&species, pages.species),
+            organization_page: pda::registry_page(&organization, pages.organization),
+            protocol_stats: pda::protocol_stats(),
+            pool_msol_account: pool.pool_msol_account,
+            token_program: token::ID,
+            associated_token_program: associated_token::ID,
+            system_program: system_program::ID,
+            event_authority: pda::event_authority(),
+            program: ID,
+        },
+        instruction::CreatePool {},
+    )
+}
+
+pub fn sync_pool_listing(pool: &PoolAddresses, registry_page: &Pubkey) -> Instruction {
+    build(
+        accounts::SyncPoolListing {
+            pool: pool.pool,
+            registry_page: *registry_page,
+            event_authority: pda::event_authority(),
+            program: ID,
+        },
+        instruction::SyncPoolListing {},
+    )
+}
+
+pub fn deposit(supporter: &Pubkey, pool: &PoolAddresses, amount: u64) -> Instruction {
+    build(
+        accounts::Deposit {
+            supporter: *supporter,
+            pool: pool.pool,
+            pool_mint: pool.pool_mint,
+            pool_vault: pool.pool_vault,
+            supporter_pool_token_account: associated_token::get_associated_token_address(
+                supporter,
+                &pool.pool_mint,
+            ),
+            supporter_position: pda::supporter_position(&pool.pool, supporter),
+            protocol_stats: pda::protocol_stats(),
+            token_program: token::ID,
+            associated_token_program: associated_token::ID,
+            system_program: system_program::ID,
+            event_authority: pda::event_authority(),
+            program: ID,
+        },
+        instruction::Deposit { amount },
+    )
+}
+
+pub fn supporter_withdraw(
+    supporter: &Pubkey,
+    pool: &PoolAddresses,
+    share_amount: u64,
+    min_sol_out: u64,
+) -> Instruction {
+    build(
+        accounts::SupporterWithdraw {
+            supporter: *supporter,
+            pool: pool.pool,
+            pool_mint: pool.pool_mint,
+            supporter_pool_token_account: associated_token::get_associated_token_address(
+                supporter,
+                &pool.pool_mint,
+            ),
+            supporter_position: pda::supporter_position(&pool.pool, supporter),
+            protocol_stats: pda::protocol_stats(),
+            marinade_state: MARINADE_STATE,
+            msol_mint: MSOL_MINT,
+            liq_pool_sol_leg: LIQ_POOL_SOL_LEG,
+            liq_pool_msol_leg: LIQ_POOL_MSOL_LEG,
+            treasury_msol_account: TREASURY_MSOL_ACCOUNT,
+            pool_msol_account: pool.pool_msol_account,
+            pool_vault: pool.pool_vault,
+            system_program: system_program::ID,
+            token_program: token::ID,
+            marinade_program: MARINADE_PROGRAM_ID,
+            event_authority: pda::event_authority(),
+            program: ID,
+        },
+        instruction::SupporterWithdraw {
+            share_amount,
+            min_sol_out,
+        },
+    )
+}
+
+// Staking & Yield
+
+pub fn stake(pool: &PoolAddresses, amount: u64) -> Instruction {
+    build(
+        accounts::Stake {
+            pool: pool.pool,
+            marinade_state: MARINADE_STATE,
+            msol_mint: MSOL_MINT,
+            liq_pool_sol_leg: LIQ_POOL_SOL_LEG,
+            liq_pool_msol_leg: LIQ_POOL_MSOL_LEG,
+            liq_pool_msol_leg_authority: LIQ_POOL_MSOL_LEG_AUTHORITY,
+            reserve_pda: RESERVE_PDA,
+            pool_vault: pool.pool_vault,
+            pool_msol_account: pool.pool_msol_account,
+            msol_mint_authority: MSOL_MINT_AUTHORITY,
+            system_program: system_program::ID,
+            token_program: token::ID,
+            marinade_program: MARINADE_PROGRAM_ID,
+            associated_token_program: associated_token::ID,
+            event_authority: pda::event_authority(),
+            program: ID,
+        },
+        instruction::Stake { amount },
+    )
+}
+
+pub fn unstake(pool: &PoolAddresses, amount: u64) -> Instruction {
+    build(
+        accounts::Unstake {
+            pool: pool.pool,
+            marinade_state: MARINADE_STATE,
+            msol_mint: MSOL_MINT,
+            liq_pool_sol_leg: LIQ_POOL_SOL_LEG,
+            liq_pool_msol_leg: LIQ_POOL_MSOL_LEG,
+            treasury_msol_account: TREASURY_MSOL_ACCOUNT,
+            pool_msol_account: pool.pool_msol_account,
+            pool_vault: pool.pool_vault,
+            system_program: system_program::ID,
+            token_program: token::ID,
+            marinade_program: MARINADE_PROGRAM_ID,
+            event_authority: pda::event_authority(),
+            program: ID,
+        },
+        instruction::Unstake { amount },
+    )
+}
+
+/// Optional accounts of `stream`
+#[derive(Clone, Copy, Debug, Default)]
+pub struct StreamOptions {
+    /// Pool's current `stream_count`, required for permissionless calls
+    pub stream_sequence: Option<u64>,
+    /// Pays the stream record's rent, required with `stream_sequence`
+    pub payer: Option<Pubkey>,
+    /// Pool pays the organization in mSOL
+    pub msol_payout: bool,
+    /// Protocol stats exist and should be updated
+    pub protocol_stats: bool,
+    /// Pool history exists and should be updated
+    pub pool_history: bool,
+}
+
+pub fn stream(pool: &PoolAddresses, options: StreamOptions) -> Instruction {
+    build(
+        accounts::Stream {
+            pool: pool.pool,
+            organization_vault: pool.org_vault,
+            escrow_vault: pool.escrow_vault,
+            marinade_state: MARINADE_STATE,
+            msol_mint: MSOL_MINT,
+            liq_pool_sol_leg: LIQ_POOL_SOL_LEG,
+            liq_pool_msol_leg: LIQ_POOL_MSOL_LEG,
+            treasury_msol_account: TREASURY_MSOL_ACCOUNT,
+            pool_msol_account: pool.pool_msol_account,
+            pool_vault: pool.pool_vault,
+            system_program: system_program::ID,
+            token_program: token::ID,
+            marinade_program: MARINADE_PROGRAM_ID,
+            payer: options.payer,
+            stream_record: options
+                .stream_sequence
+                .map(|sequence| pda::stream_record(&pool.pool, sequence)),
+            organization_msol_account: options
+                .msol_payout
+                .then(|| pda::msol_account(&pool.org_vault)),
+            protocol_stats: options.protocol_stats.then(pda::protocol_stats),
+            pool_history: options.pool_history.then(|| pda::pool_history(&pool.pool)),
+            event_authority: pda::event_authority(),
+            program: ID,
+        },
+        instruction::Stream {},
+    )
+}
+
+pub fn snapshot(payer: &Pubkey, pool: &PoolAddresses) -> Instruction {
+    build(
+        accounts::Snapshot {
+            payer: *payer,
+            pool: pool.pool,
+            pool_history: pda::pool_history(&pool.pool),
+            marinade_state: MARINADE_STATE,
+            pool_msol_account: pool.pool_msol_account,
+            system_program: system_program::ID,
+            event_authority: pda::event_authority(),
+            program: ID,
+        },
+        instruction::Snapshot {},
+    )
+}
+
+// MagicBlock Crank
+
+fn schedule_stream(authority: &Pubkey, pool: &PoolAddresses) -> accounts::ScheduleStream {
+    accounts::ScheduleStream {
+        authority: *authority,
+        pool: pool.pool,
+        pool_vault: pool.pool_vault,
+        organization_vault: pool.org_vault,
+        marinade_state: MARINADE_STATE,
+        msol_mint: MSOL_MINT,
+        liq_pool_sol_leg: LIQ_POOL_SOL_LEG,
+        liq_pool_msol_leg: LIQ_POOL_MSOL_LEG,
+        treasury_msol_account: TREASURY_MSOL_ACCOUNT,
+        system_program: system_program::ID,
+        token_program: token::ID,
+        marinade_program: MARINADE_PROGRAM_ID,
+        magic_program: MAGIC_PROGRAM_ID,
+        event_authority: pda::event_authority(),
+        program: ID,
+    }
+}
+
+pub fn schedule_streams(
+    authority: &Pubkey,
+    pool: &PoolAddresses,
+    args: ScheduleStreamArgs,
+) -> Instruction {
+    build(
+        schedule_stream(authority, pool),
+        instruction::ScheduleStreams { args },
+    )
+}
+
+pub fn reschedule_stream(
+    authority: &Pubkey,
+    pool: &PoolAddresses,
+    args: ScheduleStreamArgs,
+) -> Instruction {
+    build(
+        schedule_stream(authority, pool),
+        instruction::RescheduleStream { args },
+    )
+}
+
+pub fn cancel_stream_schedule(authority: &Pubkey, pool: &PoolAddresses) -> Instruction {
+    build(
+        accounts::CancelStreamSchedule {
+            authority: *authority,
+            pool: pool.pool,
+            pool_vault: pool.pool_vault,
+            magic_program: MAGIC_PROGRAM_ID,
+            event_authority: pda::event_authority(),
+            program: ID,
+        },
+        instruction::CancelStreamSchedule {},
+    )
+}
+
+/// Delegates the pool to an ephemeral rollup, optionally pinned to `validator`
+pub fn delegate(payer: &Pubkey, pool: &PoolAddresses, validator: Option<Pubkey>) -> Instruction {
+    let mut ix = build(
+        accounts::DelegatePool {
+            payer: *payer,
+            buffer_pool: pda::delegate_buffer(&pool.pool),
+            delegation_record_pool: pda::delegation_record(&pool.pool),
+            delegation_metadata_pool: pda::delegation_metadata(&pool.pool),
+            pool: pool.pool,
+            owner_program: ID,
+            delegation_program: ephemeral_rollups_sdk::id(),
+            system_program: system_program::ID,
+            event_authority: pda::event_authority(),
+            program: ID,
+        },
+        instruction::Delegate {},
+    );
+
+    // The program reads the validator from the first remaining account
+    if let Some(validator) = validator {
+        ix.accounts
+            .push(AccountMeta::new_readonly(validator, false));
+    }
+
+    ix
+}
+
+pub fn undelegate(payer: &Pubkey, pool: &PoolAddresses) -> Instruction {
+    build(
+        accounts::UndelegatePool {
+            payer: *payer,
+            pool: pool.pool,
+            magic_program: MAGIC_PROGRAM_ID,
+            magic_context: MAGIC_CONTEXT_ID,
+            event_authority: pda::event_authority(),
+            program: ID,
+        },
+        instruction::Undelegate {},
+    )
+}
+
+// Organization Withdrawals
+
+pub fn organization_withdraw(
+    organization: &Pubkey,
+    pool: &PoolAddresses,
+    amount: u64,
+    category: SpendingCategory,
+    memo_hash: [u8; 32],
+) -> Instruction {
+    build(
+        accounts::OrganizationWithdraw {
+            organization: *organization,
+            pool: pool.pool,
+            org_vault: pool.org_vault,
+            spending_ledger: pda::spending_ledger(&pool.pool),
+            protocol_stats: pda::protocol_stats(),
+            system_program: system_program::ID,
+            event_authority: pda::event_authority(),
+            program: ID,
+        },
+        instruction::OrganizationWithdraw {
+            amount,
+            category,
+            memo_hash,
+        },
+    )
+}
+
+pub fn organization_withdraw_msol(
+    organization: &Pubkey,
+    pool: &PoolAddresses,
+    amount: u64,
+    category: SpendingCategory,
+    memo_hash: [u8; 32],
+) -> Instruction {
+    build(
+        accounts::OrganizationWithdrawMsol {
+            organization: *organization,
+            pool: pool.pool,
+            org_vault: pool.org_vault,
+            msol_mint: MSOL_MINT,
+            org_vault_msol_account: pda::msol_account(&pool.org_vault),
+            organization_msol_account: pda::msol_account(organization),
+            spending_ledger: pda::spending_ledger(&pool.pool),
+            marinade_state: MARINADE_STATE,
+            protocol_stats: pda::protocol_stats(),
+            token_program: token::ID,
+            associated_token_program: associated_token::ID,
+            system_program: system_program::ID,
+            event_authority: pda::event_authority(),
+            program: ID,
+        },
+        instruction::OrganizationWithdrawMsol {
+            amount,
+            category,
+            memo_hash,
+        },
+    )
+}
+
+/// `sequence` is the pool's current `queued_withdraw_count`
+pub fn queue_organization_withdrawal(
+    organization: &Pubkey,
+    pool: &PoolAddresses,
+    sequence: u64,
+    amount: u64,
+    asset: PayoutAsset,
+    category: SpendingCategory,
+    memo_hash: [u8; 32],
+) -> Instruction {
+    build(
+        accounts::QueueOrganizationWithdrawal {
+            organization: *organization,
+            pool: pool.pool,
+            org_vault: pool.org_vault,
+            queued_withdrawal: pda::queued_withdrawal(&pool.pool, sequence),
+            org_vault_msol_account: (asset == PayoutAsset::Msol)
+                .then(|| pda::msol_account(&pool.org_vault)),
+            marinade_state: MARINADE_STATE,
+            system_program: system_program::ID,
+            event_authority: pda::event_authority(),
+            program: ID,
+        },
+        instruction::QueueOrganizationWithdrawal {
+            amount,
+            asset,
+            category,
+            memo_hash,
+        },
+    )
+}
+
+/// `asset` is the queued withdrawal's asset, which decides whether the
+/// mSOL accounts are passed
+pub fn release_queued_withdrawal(
+    recipient: &Pubkey,
+    pool: &PoolAddresses,
+    sequence: u64,
+    asset: PayoutAsset,
+) -> Instruction {
+    let pays_msol = asset == PayoutAsset::Msol;
+
+    build(
+        accounts::ReleaseQueuedWithdrawal {
+            recipient: *recipient,
+            pool: pool.pool,
+            org_vault: pool.org_vault,
+            queued_withdrawal: pda::queued_withdrawal(&pool.pool, sequence),
+            spending_ledger: pda::spending_ledger(&pool.pool),
+            protocol_stats: pda::protocol_stats(),
+            org_vault_msol_account: pays_msol.then(|| pda::msol_account(&pool.org_vault)),
+            recipient_msol_account: pays_msol.then(|| pda::msol_account(recipient)),
+            token_program: token::ID,
+            system_program: system_program::ID,
+            event_authority: pda::event_authority(),
+            program: ID,
+        },
+        instruction::ReleaseQueuedWithdrawal {},
+    )
+}
+
+pub fn veto_queued_withdrawal(
+    admin: &Pubkey,
+    pool: &PoolAddresses,
+    recipient: &Pubkey,
+    sequence: u64,
+) -> Instruction {
+    build(
+        accounts::VetoQueuedWithdrawal {
+            admin: *admin,
+            pool: pool.pool,
+            recipient: *recipient,
+            queued_withdrawal: pda::queued_withdrawal(&pool.pool, sequence),
+            event_authority: pda::event_authority(),
+            program: ID,
+        },
+        instruction::VetoQueuedWithdrawal {},
+    )
+}
+
+pub fn set_withdrawal_controls(
+    authority: &Pubkey,
+    pool: &PoolAddresses,
+    withdraw_cap: u64,
+    withdraw_window: u64,
+    withdraw_delay: u64,
+) -> Instruction {
+    build(
+        accounts::SetWithdrawalControls {
+            authority: *authority,
+            pool: pool.pool,
+            event_authority: pda::event_authority(),
+            program: ID,
+        },
+        instruction::SetWithdrawalControls {
+            withdraw_cap,
+            withdraw_window,
+            withdraw_delay,
+        },
+    )
+}
+
+// Read-only Views
+
+pub fn organization_claimable(pool: &PoolAddresses) -> Instruction {
+    build(
+        accounts::OrganizationClaimable {
+            pool: pool.pool,
+            org_vault: pool.org_vault,
+        },
+        instruction::OrganizationClaimable {},
+    )
+}
+
+fn quote_pool(pool: &PoolAddresses) -> accounts::QuotePool {
+    accounts::QuotePool {
+        pool: pool.pool,
+        pool_mint: pool.pool_mint,
+        pool_vault: pool.pool_vault,
+        pool_msol_account: pool.pool_msol_account,
+        marinade_state: MARINADE_STATE,
+        liq_pool_sol_leg: LIQ_POOL_SOL_LEG,
+    }
+}
+
+pub fn quote_deposit(pool: &PoolAddresses, amount: u64) -> Instruction {
+    build(quote_pool(pool), instruction::QuoteDeposit { amount })
+}
+
+pub fn quote_withdraw(pool: &PoolAddresses, share_amount: u64) -> Instruction {
+    build(
+        quote_pool(pool),
+        instruction::QuoteWithdraw { share_amount },
+    )
+}
+
+pub fn pool_nav(pool: &PoolAddresses) -> Instruction {
+    build(quote_pool(pool), instruction::PoolNav {})
+}
+
+pub fn pending_org_yield(pool: &PoolAddresses) -> Instruction {
+    build(quote_pool(pool), instruction::PendingOrgYield {})
+}
+
+pub fn position_value(pool: &PoolAddresses, supporter: &Pubkey) -> Instruction {
+    build(
+        accounts::QuotePosition {
+            pool: pool.pool,
+            pool_mint: pool.pool_mint,
+            supporter_position: pda::supporter_position(&pool.pool, supporter),
+            supporter_pool_token_account: associated_token::get_associated_token_address(
+                supporter,
+                &pool.pool_mint,
+            ),
+            pool_msol_account: pool.pool_msol_account,
+            marinade_state: MARINADE_STATE,
+        },
+        instruction::PositionValue {},
+    )
+}
+
+// Pool Configuration
+
+pub fn set_badge_thresholds(
+    admin: &Pubkey,
+    pool: &PoolAddresses,
+    thresholds: [u64; atharva_refi::constants::BADGE_TIERS],
+) -> Instruction {
+    build(
+        accounts::SetBadgeThresholds {
+            admin: *admin,
+            pool: pool.pool,
+            event_authority: pda::event_authority(),
+            program: ID,
+        },
+        instruction::SetBadgeThresholds { thresholds },
+    )
+}
+
+pub fn set_stream_cooldown(
+    admin: &Pubkey,
+    pool: &PoolAddresses,
+    stream_cooldown: u64,
+) -> Instruction {
+    build(
+        accounts::SetStreamCooldown {
+            admin: *admin,
+            pool: pool.pool,
+            event_authority: pda::event_authority(),
+            program: ID,
+        },
+        instruction::SetStreamCooldown { stream_cooldown },
+    )
+}
+
+pub fn set_payout_asset(
+    authority: &Pubkey,
+    pool: &PoolAddresses,
+    payout_asset: PayoutAsset,
+) -> Instruction {
+    build(
+        accounts::SetPayoutAsset {
+            authority: *authority,
+            pool: pool.pool,
+            organization_vault: pool.org_vault,
+            msol_mint: MSOL_MINT,
+            organization_msol_account: pda::msol_account(&pool.org_vault),
+            token_program: token::ID,
+            associated_token_program: associated_token::ID,
+            system_program: system_program::ID,
+            event_authority: pda::event_authority(),
+            program: ID,
+        },
+        instruction::SetPayoutAsset { payout_asset },
+    )
+}
+
+pub fn set_escrow_mode(
+    admin: &Pubkey,
+    pool: &PoolAddresses,
+    escrow_mode: bool,
+    fallback_beneficiary: Pubkey,
+) -> Instruction {
+    build(
+        accounts::SetEscrowMode {
+            admin: *admin,
+            pool: pool.pool,
+            event_authority: pda::event_authority(),
+            program: ID,
+        },
+        instruction::SetEscrowMode {
+            escrow_mode,
+            fallback_beneficiary,
+        },
+    )
+}
+
+// Organization Authority
+
+fn organization_authority(
+    authority: &Pubkey,
+    pool: &PoolAddresses,
+) -> accounts::ProposeOrganizationAuthority {
+    accounts::ProposeOrganizationAuthority {
+        authority: *authority,
+        pool: pool.pool,
+        event_authority: pda::event_authority(),
+        program: ID,
+    }
+}
+
+pub fn propose_organization_authority(
+    authority: &Pubkey,
+    pool: &PoolAddresses,
+    new_authority: Pubkey,
+) -> Instruction {
+    build(
+        organization_authority(authority, pool),
+        instruction::ProposeOrganizationAuthority { new_authority },
+    )
+}
+
+pub fn cancel_organization_authority_rotation(
+    authority: &Pubkey,
+    pool: &PoolAddresses,
+) -> Instruction {
+    build(
+        organization_authority(authority, pool),
+        instruction::CancelOrganizationAuthorityRotation {},
+    )
+}
+
+/// Brings the rotation's ETA forward to `delay` seconds from now, which
+/// must be at least `MIN_RECOVERY_DELAY`
+pub fn approve_organization_authority_rotation(
+    admin: &Pubkey,
+    pool: &PoolAddresses,
+    delay: u64,
+) -> Instruction {
+    build(
+        accounts::ApproveOrganizationAuthority {
+            admin: *admin,
+            pool: pool.pool,
+            event_authority: pda::event_authority(),
+            program: ID,
+        },
+        instruction::ApproveOrganizationAuthorityRotation { delay },
+    )
+}
+
+pub fn accept_organization_authority(new_authority: &Pubkey, pool: &PoolAddresses) -> Instruction {
+    build(
+        accounts::AcceptOrganizationAuthority {
+            new_authority: *new_authority,
+            pool: pool.pool,
+            event_authority: pda::event_authority(),
+            program: ID,
+        },
+        instruction::AcceptOrganizationAuthority {},
+    )
+}
+
+// Impact Reporting
+
+/// `sequence` is the pool's current `field_report_count`
+pub fn publish_field_report(
+    organization: &Pubkey,
+    pool: &PoolAddresses,
+    sequence: u64,
+    args: PublishFieldReportArgs,
+) -> Instruction {
+    build(
+        accounts::PublishFieldReport {
+            organization: *organization,
+            pool: pool.pool,
+            field_report: pda::field_report(&pool.pool, sequence),
+            spending_ledger: args
+                .covered_entries
+                .map(|_| pda::spending_ledger(&pool.pool)),
+            system_program: system_program::ID,
+            event_authority: pda::event_authority(),
+            program: ID,
+        },
+        instruction::PublishFieldReport { args },
+    )
+}
+
+pub fn set_attester(admin: &Pubkey, attester: Pubkey, is_active: bool) -> Instruction {
+    build(
+        accounts::SetAttester {
+            admin: *admin,
+            attester_account: pda::attester(&attester),
+            system_program: system_program::ID,
+            event_authority: pda::event_authority(),
+            program: ID,
+        },
+        instruction::SetAttester {
+            attester,
+            is_active,
+        },
+    )
+}
+
+pub fn record_impact_metric(
+    attester: &Pubkey,
+    pool: &PoolAddresses,
+    kind: MetricKind,
+    value: u64,
+    survey_date: u64,
+    source_hash: [u8; 32],
+) -> Instruction {
+    build(
+        accounts::RecordImpactMetric {
+            attester: *attester,
+            attester_account: pda::attester(attester),
+            pool: pool.pool,
+            impact_metrics: pda::impact_metrics(&pool.pool),
+            system_program: system_program::ID,
+            event_authority: pda::event_authority(),
+            program: ID,
+        },
+        instruction::RecordImpactMetric {
+            kind,
+            value,
+            survey_date,
+            source_hash,
+        },
+    )
+}
+
+pub fn claim_impact_badge(supporter: &Pubkey, pool: &PoolAddresses, tier: u8) -> Instruction {
+    let badge_mint = pda::badge_mint(&pool.pool, supporter, tier);
+
+    build(
+        accounts::ClaimImpactBadge {
+            supporter: *supporter,
+            pool: pool.pool,
+            supporter_position: pda::supporter_position(&pool.pool, supporter),
+            supporter_pool_token_account: associated_token::get_associated_token_address(
+                supporter,
+                &pool.pool_mint,
+            ),
+            badge_mint,
+            supporter_badge_account: pda::badge_account(supporter, &badge_mint),
+            token_2022_program: token_2022::ID,
+            associated_token_program: associated_token::ID,
+            system_program: system_program::ID,
+            event_authority: pda::event_authority(),
+            program: ID,
+        },
+        instruction::ClaimImpactBadge { tier },
+    )
+}
+
+// Milestone Escrow
+
+/// `index` is the pool's current `milestone_count`
+/// `attester` is the registered attester approved to confirm the milestone
+pub fn add_milestone(
+    admin: &Pubkey,
+    pool: &PoolAddresses,
+    index: u8,
+    attester: &Pubkey,
+    amount: u64,
+    deadline: u64,
+    criteria_hash: [u8; 32],
+) -> Instruction {
+    build(
+        accounts::AddMilestone {
+            admin: *admin,
+            pool: pool.pool,
+            attester_account: pda::attester(attester),
+            milestone: pda::milestone(&pool.pool, index),
+            system_program: system_program::ID,
+            event_authority: pda::event_authority(),
+            program: ID,
+        },
+        instruction::AddMilestone {
+            amount,
+            deadline,
+            criteria_hash,
+        },
+    )
+}
+
+pub fn attest_milestone(
+    attester: &Pubkey,
+    pool: &PoolAddresses,
+    index: u8,
+    evidence_hash: [u8; 32],
+) -> Instruction {
+    build(
+        accounts::AttestMilestone {
+            attester: *attester,
+            attester_account: pda::attester(attester),
+            pool: pool.pool,
+            milestone: pda::milestone(&pool.pool, index),
+            escrow_vault: pool.escrow_vault,
+            organization_vault: pool.org_vault,
+            system_program: system_program::ID,
+            event_authority: pda::event_authority(),
+            program: ID,
+        },
+        instruction::AttestMilestone { evidence_hash },
+    )
+}
+
+pub fn reclaim_milestone(
+    pool: &PoolAddresses,
+    index: u8,
+    fallback_beneficiary: &Pubkey,
+) -> Instruction {
+    build(
+        accounts::ReclaimMilestone {
+            pool: pool.pool,
+            milestone: pda::milestone(&pool.pool, index),
+            escrow_vault: pool.escrow_vault,
+            fallback_beneficiary: *fallback_beneficiary,
+            system_program: system_program::ID,
+            event_authority: pda::event_authority(),
+            program: ID,
+        },
+        instruction::ReclaimMilestone {},
+    )
+}
+
+pub fn release_excess_escrow(pool: &PoolAddresses) -> Instruction {
+    build(
+        accounts::ReleaseExcessEscrow {
+            pool: pool.pool,
+            escrow_vault: pool.escrow_vault,
+            organization_vault: pool.org_vault,
+            system_program: system_program::ID,
+            event_authority: pda::event_authority(),
+            program: ID,
+        },
+        instruction::ReleaseExcessEscrow {},
+    )
+}
+
+// Organization Standing
+
+/// `pages` locate the pool's listings, updated to its new standing
+pub fn suspend_organization(
+    admin: &Pubkey,
+    pool: &PoolAddresses,
+    pages: RegistryPages,
+) -> Instruction {
+    let [registry_page, species_page, organization_page] = pages.addresses(pool);
+
+    build(
+        accounts::SuspendOrganization {
+            admin: *admin,
+            pool: pool.pool,
+            registry_page,
+            species_page,
+            organization_page,
+            event_authority: pda::event_authority(),
+            program: ID,
+        },
+        instruction::SuspendOrganization {},
+    )
+}
+
+pub fn reinstate_organization(
+    admin: &Pubkey,
+    pool: &PoolAddresses,
+    pages: RegistryPages,
+) -> Instruction {
+    let [registry_page, species_page, organization_page] = pages.addresses(pool);
+
+    build(
+        accounts::ReinstateOrganization {
+            admin: *admin,
+            pool: pool.pool,
+            registry_page,
+            species_page,
+            organization_page,
+            escrow_vault: pool.escrow_vault,
+            organization_vault: pool.org_vault,
+            system_program: system_program::ID,
+            event_authority: pda::event_authority(),
+            program: ID,
+        },
+        instruction::ReinstateOrganization {},
+    )
+}
+
+pub fn reassign_to_fallback(
+    admin: &Pubkey,
+    pool: &PoolAddresses,
+    pages: RegistryPages,
+    fallback_organization_pubkey: &Pubkey,
+) -> Instruction {
+    let [registry_page, species_page, organization_page] = pages.addresses(pool);
+
+    build(
+        accounts::ReassignToFallback {
+            admin: *admin,
+            pool: pool.pool,
+            registry_page,
+            species_page,
+            organization_page,
+            fallback_organization: pda::organization(fallback_organization_pubkey),
+            organization_vault: pool.org_vault,
+            escrow_vault: pool.escrow_vault,
+            system_program: system_program::ID,
+            event_authority: pda::event_authority(),
+            program: ID,
+        },
+        instruction::ReassignToFallback {},
+    )
+}
+
+/// `fallback_beneficiary` is the pool's `reassigned_to` organization
+pub fn sweep_held_funds(pool: &PoolAddresses, fallback_beneficiary: &Pubkey) -> Instruction {
+    build(
+        accounts::SweepHeldFunds {
+            pool: pool.pool,
+            escrow_vault: pool.escrow_vault,
+            fallback_beneficiary: *fallback_beneficiary,
+            system_program: system_program::ID,
+            event_authority: pda::event_authority(),
+            program: ID,
+        },
+        instruction::SweepHeldFunds {},
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anchor_lang::Discriminator;
+    use atharva_refi::states::LedgerEntryRange;
+
+    const ADMIN: Pubkey = Pubkey::new_from_array([1; 32]);
+    const ORGANIZATION: Pubkey = Pubkey::new_from_array([2; 32]);
+    const SUPPORTER: Pubkey = Pubkey::new_from_array([3; 32]);
+    const ATTESTER: Pubkey = Pubkey::new_from_array([4; 32]);
+    const RECIPIENT: Pubkey = Pubkey::new_from_array([5; 32]);
+    const NEW_AUTHORITY: Pubkey = Pubkey::new_from_array([6; 32]);
+    const FALLBACK: Pubkey = Pubkey::new_from_array([7; 32]);
+    const VALIDATOR: Pubkey = Pubkey::new_from_array([8; 32]);
+
+    fn lion_pool() -> PoolAddresses {
+        PoolAddresses::new(ORGANIZATION, pda::species_id("Panthera leo"))
+    }
+
+    fn ata(owner: &Pubkey, mint: &Pubkey) -> Pubkey {
+        associated_token::get_associated_token_address(owner, mint)
+    }
+
+    /// Checks the instruction's discriminator, its accounts in the order
+    /// the program's accounts struct declares them, and which accounts sign
+    fn assert_ix<T: Discriminator>(ix: &Instruction, accounts: &[Pubkey], signers: &[Pubkey]) {
+        assert_eq!(ix.program_id, ID);
+        assert!(ix.data.starts_with(T::DISCRIMINATOR));
+
+        let keys: Vec<Pubkey> = ix.accounts.iter().map(|meta| meta.pubkey).collect();
+        assert_eq!(keys, accounts);
+
+        let signing: Vec<Pubkey> = ix
+            .accounts
+            .iter()
+            .filter(|meta| meta.is_signer)
+            .map(|meta| meta.pubkey)
+            .collect();
+        assert_eq!(signing, signers);
+    }
+
+    // Organization & Species Registry
+
+    #[test]
+    fn register_organization_accounts() {
+        let ix = register_organization(&ADMIN, ORGANIZATION, "Org".into(), "".into());
+        assert_ix::<instruction::RegisterOrganization>(
+            &ix,
+            &[
+                ADMIN,
+                pda::organization(&ORGANIZATION),
+                system_program::ID,
+                pda::event_authority(),
+                ID,
+            ],
+            &[ADMIN],
+        );
+    }
+
+    #[test]
+    fn apply_organization_accounts() {
+        let ix = apply_organization(
+            &ORGANIZATION,
+            "Org".into(),
+            "".into(),
+            [9; 32],
+            vec![pda::species_id("Panthera leo")],
+        );
+        assert_ix::<instruction::ApplyOrganization>(
+            &ix,
+            &[
+                ORGANIZATION,
+                pda::organization(&ORGANIZATION),
+                system_program::ID,
+                pda::event_authority(),
+                ID,
+            ],
+            &[ORGANIZATION],
+        );
+    }
+
+    #[test]
+    fn review_organization_accounts() {
+        let accounts = [
+            ADMIN,
+            pda::organization(&ORGANIZATION),
+            pda::event_authority(),
+            ID,
+        ];
+
+        assert_ix::<instruction::ApproveOrganization>(
+            &approve_organization(&ADMIN, &ORGANIZATION),
+            &accounts,
+            &[ADMIN],
+        );
+        assert_ix::<instruction::RejectOrganization>(
+            &reject_organization(&ADMIN, &ORGANIZATION, 3),
+            &accounts,
+            &[ADMIN],
+        );
+    }
+
+    #[test]
+    fn register_species_accounts() {
+        let ix = register_species(
+            &ADMIN,
+            "Panthera leo".into(),
+            "African Lion".into(),
+            IucnCategory::Vulnerable,
+            "".into(),
+            "".into(),
+        );
+        assert_ix::<instruction::RegisterSpecies>(
+            &ix,
+            &[
+                ADMIN,
+                pda::species(&pda::species_id("Panthera leo")),
+                system_program::ID,
+                pda::event_authority(),
+                ID,
+            ],
+            &[ADMIN],
+        );
+    }
+
+    // Pool Lifecycle
+
+    #[test]
+    fn create_pool_accounts() {
+        let pool = lion_pool();
+        let organization = pda::organization(&ORGANIZATION);
+        let species = pda::species(&pool.species_id);
+        let pages = RegistryPages {
+            registry: 1,
+            species: 2,
+            organization: 3,
+        };
+
+        assert_ix::<instruction::CreatePool>(
+            &create_pool(&ADMIN, &pool, pages),
+            &[
+                ADMIN,
+                organization,
+                species,
+                MSOL_MINT,
+                pool.pool,
+                pool.pool_mint,
+                pool.pool_vault,
+                pool.org_vault,
+                pool.escrow_vault,
+                pda::pool_registry(),
+                pda::registry_page(&pda::pool_registry(), 1),
+                pda::registry_page(&species, 2),
+                pda::registry_page(&organization, 3),
+                pda::protocol_stats(),
+                pool.pool_msol_account,
+                token::ID,
+                associated_token::ID,
+                system_program::ID,
+                pda::event_authority(),
+                ID,
+            ],
+            &[ADMIN],
+        );
+    }
+
+    #[test]
+    fn sync_pool_listing_accounts() {
+        let pool = lion_pool();
+        let page = pda::registry_page(&pda::pool_registry(), 0);
+
+        assert_ix::<instruction::SyncPoolListing>(
+            &sync_pool_listing(&pool, &page),
+            &[pool.pool, page, pda::event_authority(), ID],
+            &[],
+        );
+    }
+
+    #[test]
+    fn deposit_accounts() {
+        let pool = lion_pool();
+
+        assert_ix::<instruction::Deposit>(
+            &deposit(&SUPPORTER, &pool, 1),
+            &[
+                SUPPORTER,
+                pool.pool,
+                pool.pool_mint,
+                pool.pool_vault,
+                ata(&SUPPORTER, &pool.pool_mint),
+                pda::supporter_position(&pool.pool, &SUPPORTER),
+                pda::protocol_stats(),
+                token::ID,
+                associated_token::ID,
+                system_program::ID,
+                pda::event_authority(),
+                ID,
+            ],
+            &[SUPPORTER],
+        );
+    }
+
+    #[test]
+    fn supporter_withdraw_accounts() {
+        let pool = lion_pool();
+
+        assert_ix::<instruction::SupporterWithdraw>(
+            &supporter_withdraw(&SUPPORTER, &pool, 1, 0),
+            &[
+                SUPPORTER,
+                pool.pool,
+                pool.pool_mint,
+                ata(&SUPPORTER, &pool.pool_mint),
+                pda::supporter_position(&pool.pool, &SUPPORTER),
+                pda::protocol_stats(),
+                MARINADE_STATE,
+                MSOL_MINT,
+                LIQ_POOL_SOL_LEG,
+                LIQ_POOL_MSOL_LEG,
+                TREASURY_MSOL_ACCOUNT,
+                pool.pool_msol_account,
+                pool.pool_vault,
+                system_program::ID,
+                token::ID,
+                MARINADE_PROGRAM_ID,
+                pda::event_authority(),
+                ID,
+            ],
+            &[SUPPORTER],
+        );
+    }
+
+    // Staking & Yield
+
+    #[test]
+    fn stake_accounts() {
+        let pool = lion_pool();
+
+        assert_ix::<instruction::Stake>(
+            &stake(&pool, 1),
+            &[
+                pool.pool,
+                MARINADE_STATE,
+                MSOL_MINT,
+                LIQ_POOL_SOL_LEG,
+                LIQ_POOL_MSOL_LEG,
+                LIQ_POOL_MSOL_LEG_AUTHORITY,
+                RESERVE_PDA,
+                pool.pool_vault,
+                pool.pool_msol_account,
+                MSOL_MINT_AUTHORITY,
+                system_program::ID,
+                token::ID,
+                MARINADE_PROGRAM_ID,
+                associated_token::ID,
+                pda::event_authority(),
+                ID,
+            ],
+            &[],
+        );
+    }
+
+    #[test]
+    fn unstake_accounts() {
+        let pool = lion_pool();
+
+        assert_ix::<instruction::Unstake>(
+            &unstake(&pool, 1),
+            &[
+                pool.pool,
+                MARINADE_STATE,
+                MSOL_MINT,
+                LIQ_POOL_SOL_LEG,
+                LIQ_POOL_MSOL_LEG,
+                TREASURY_MSOL_ACCOUNT,
+                pool.pool_msol_account,
+                pool.pool_vault,
+                system_program::ID,
+                token::ID,
+                MARINADE_PROGRAM_ID,
+                pda::event_authority(),
+                ID,
+            ],
+            &[],
+        );
+    }
+
+    fn stream_accounts(pool: &PoolAddresses, optional: [Pubkey; 5]) -> Vec<Pubkey> {
+        let mut accounts = vec![
+            pool.pool,
+            pool.org_vault,
+            pool.escrow_vault,
+            MARINADE_STATE,
+            MSOL_MINT,
+            LIQ_POOL_SOL_LEG,
+            LIQ_POOL_MSOL_LEG,
+            TREASURY_MSOL_ACCOUNT,
+            pool.pool_msol_account,
+            pool.pool_vault,
+            system_program::ID,
+            token::ID,
+            MARINADE_PROGRAM_ID,
+        ];
+        accounts.extend(optional);
+        accounts.extend([pda::event_authority(), ID]);
+        accounts
+    }
+
+    #[test]
+    fn stream_accounts_for_public_callers() {
+        let pool = lion_pool();
+        let options = StreamOptions {
+            stream_sequence: Some(4),
+            payer: Some(SUPPORTER),
+            msol_payout: true,
+            protocol_stats: true,
+            pool_history: true,
+        };
+
+        assert_ix::<instruction::Stream>(
+            &stream(&pool, options),
+            &stream_accounts(
+                &pool,
+                [
+                    SUPPORTER,
+                    pda::stream_record(&pool.pool, 4),
+                    pda::msol_account(&pool.org_vault),
+                    pda::protocol_stats(),
+                    pda::pool_history(&pool.pool),
+                ],
+            ),
+            &[SUPPORTER],
+        );
+    }
+
+    #[test]
+    fn stream_accounts_for_crank_runs() {
+        let pool = lion_pool();
+
+        // Omitted optional accounts are passed as the program ID
+        assert_ix::<instruction::Stream>(
+            &stream(&pool, StreamOptions::default()),
+            &stream_accounts(&pool, [ID; 5]),
+            &[],
+        );
+    }
+
+    #[test]
+    fn snapshot_accounts() {
+        let pool = lion_pool();
+
+        assert_ix::<instruction::Snapshot>(
+            &snapshot(&SUPPORTER, &pool),
+            &[
+                SUPPORTER,
+                pool.pool,
+                pda::pool_history(&pool.pool),
+                MARINADE_STATE,
+                pool.pool_msol_account,
+                system_program::ID,
+                pda::event_authority(),
+                ID,
+            ],
+            &[SUPPORTER],
+        );
+    }
+
+    // MagicBlock Crank
+
+    #[test]
+    fn schedule_stream_accounts() {
+        let pool = lion_pool();
+        let args = ScheduleStreamArgs {
+            task_id: 1,
+            execution_interval_millis: 1_000,
+            iterations: 1,
+        };
+        let accounts = [
+            ADMIN,
+            pool.pool,
+            pool.pool_vault,
+            pool.org_vault,
+            MARINADE_STATE,
+            MSOL_MINT,
+            LIQ_POOL_SOL_LEG,
+            LIQ_POOL_MSOL_LEG,
+            TREASURY_MSOL_ACCOUNT,
+            system_program::ID,
+            token::ID,
+            MARINADE_PROGRAM_ID,
+            MAGIC_PROGRAM_ID,
+            pda::event_authority(),
+            ID,
+        ];
+
+        assert_ix::<instruction::ScheduleStreams>(
+            &schedule_streams(&ADMIN, &pool, args.clone()),
+            &accounts,
+            &[ADMIN],
+        );
+        assert_ix::<instruction::RescheduleStream>(
+            &reschedule_stream(&ADMIN, &pool, args),
+            &accounts,
+            &[ADMIN],
+        );
+    }
+
+    #[test]
+    fn cancel_stream_schedule_accounts() {
+        let pool = lion_pool();
+
+        assert_ix::<instruction::CancelStreamSchedule>(
+            &cancel_stream_schedule(&ADMIN, &pool),
+            &[
+                ADMIN,
+                pool.pool,
+                pool.pool_vault,
+                MAGIC_PROGRAM_ID,
+                pda::event_authority(),
+                ID,
+            ],
+            &[ADMIN],
+        );
+    }
+
+    #[test]
+    fn delegate_accounts() {
+        let pool = lion_pool();
+        let mut accounts = vec![
+            ADMIN,
+            pda::delegate_buffer(&pool.pool),
+            pda::delegation_record(&pool.pool),
+            pda::delegation_metadata(&pool.pool),
+            pool.pool,
+            ID,
+            ephemeral_rollups_sdk::id(),
+            system_program::ID,
+            pda::event_authority(),
+            ID,
+        ];
+
+        assert_ix::<instruction::Delegate>(&delegate(&ADMIN, &pool, None), &accounts, &[ADMIN]);
+
+        // The validator trails as the first remaining account
+        accounts.push(VALIDATOR);
+        assert_ix::<instruction::Delegate>(
+            &delegate(&ADMIN, &pool, Some(VALIDATOR)),
+            &accounts,
+            &[ADMIN],
+        );
+    }
+
+    #[test]
+    fn undelegate_accounts() {
+        let pool = lion_pool();
+
+        assert_ix::<instruction::Undelegate>(
+            &undelegate(&ADMIN, &pool),
+            &[
+                ADMIN,
+                pool.pool,
+                MAGIC_PROGRAM_ID,
+                MAGIC_CONTEXT_ID,
+                pda::event_authority(),
+                ID,
+            ],
+            &[ADMIN],
+        );
+    }
+
+    // Organization Withdrawals
+
+    #[test]
+    fn organization_withdraw_accounts() {
+        let pool = lion_pool();
+
+        assert_ix::<instruction::OrganizationWithdraw>(
+            &organization_withdraw(&ORGANIZATION, &pool, 1, SpendingCategory::Habitat, [9; 32]),
+            &[
+                ORGANIZATION,
+                pool.pool,
+                pool.org_vault,
+                pda::spending_ledger(&pool.pool),
+                pda::protocol_stats(),
+                system_program::ID,
+                pda::event_authority(),
+                ID,
+            ],
+            &[ORGANIZATION],
+        );
+    }
+
+    #[test]
+    fn organization_withdraw_msol_accounts() {
+        let pool = lion_pool();
+
+        assert_ix::<instruction::OrganizationWithdrawMsol>(
+            &organization_withdraw_msol(
+                &ORGANIZATION,
+                &pool,
+                1,
+                SpendingCategory::Research,
+                [9; 32],
+            ),
+            &[
+                ORGANIZATION,
+                pool.pool,
+                pool.org_vault,
+                MSOL_MINT,
+                ata(&pool.org_vault, &MSOL_MINT),
+                ata(&ORGANIZATION, &MSOL_MINT),
+                pda::spending_ledger(&pool.pool),
+                MARINADE_STATE,
+                pda::protocol_stats(),
+                token::ID,
+                associated_token::ID,
+                system_program::ID,
+                pda::event_authority(),
+                ID,
+            ],
+            &[ORGANIZATION],
+        );
+    }
+
+    #[test]
+    fn queue_organization_withdrawal_accounts() {
+        let pool = lion_pool();
+        let queue = |asset| {
+            queue_organization_withdrawal(
+                &ORGANIZATION,
+                &pool,
+                2,
+                1,
+                asset,
+                SpendingCategory::Operations,
+                [9; 32],
+            )
+        };
+        let accounts = |org_vault_msol_account| {
+            [
+                ORGANIZATION,
+                pool.pool,
+                pool.org_vault,
+                pda::queued_withdrawal(&pool.pool, 2),
+                org_vault_msol_account,
+                MARINADE_STATE,
+                system_program::ID,
+                pda::event_authority(),
+                ID,
+            ]
+        };
+
+        assert_ix::<instruction::QueueOrganizationWithdrawal>(
+            &queue(PayoutAsset::Sol),
+            &accounts(ID),
+            &[ORGANIZATION],
+        );
+        assert_ix::<instruction::QueueOrganizationWithdrawal>(
+            &queue(PayoutAsset::Msol),
+            &accounts(ata(&pool.org_vault, &MSOL_MINT)),
+            &[ORGANIZATION],
+        );
+    }
+
+    #[test]
+    fn release_queued_withdrawal_accounts() {
+        let pool = lion_pool();
+        let accounts = |org_vault_msol_account, recipient_msol_account| {
+            [
+                RECIPIENT,
+                pool.pool,
+                pool.org_vault,
+                pda::queued_withdrawal(&pool.pool, 2),
+                pda::spending_ledger(&pool.pool),
+                pda::protocol_stats(),
+                org_vault_msol_account,
+                recipient_msol_account,
+                token::ID,
+                system_program::ID,
+                pda::event_authority(),
+                ID,
+            ]
+        };
+
+        assert_ix::<instruction::ReleaseQueuedWithdrawal>(
+            &release_queued_withdrawal(&RECIPIENT, &pool, 2, PayoutAsset::Sol),
+            &accounts(ID, ID),
+            &[RECIPIENT],
+        );
+        assert_ix::<instruction::ReleaseQueuedWithdrawal>(
+            &release_queued_withdrawal(&RECIPIENT, &pool, 2, PayoutAsset::Msol),
+            &accounts(
+                ata(&pool.org_vault, &MSOL_MINT),
+                ata(&RECIPIENT, &MSOL_MINT),
+            ),
+            &[RECIPIENT],
+        );
+    }
+
+    #[test]
+    fn veto_queued_withdrawal_accounts() {
+        let pool = lion_pool();
+
+        assert_ix::<instruction::VetoQueuedWithdrawal>(
+            &veto_queued_withdrawal(&ADMIN, &pool, &RECIPIENT, 2),
+            &[
+                ADMIN,
+                pool.pool,
+                RECIPIENT,
+                pda::queued_withdrawal(&pool.pool, 2),
+                pda::event_authority(),
+                ID,
+            ],
+            &[ADMIN],
+        );
+    }
+
+    #[test]
+    fn set_withdrawal_controls_accounts() {
+        let pool = lion_pool();
+
+        assert_ix::<instruction::SetWithdrawalControls>(
+            &set_withdrawal_controls(&ORGANIZATION, &pool, 1, 2, 3),
+            &[ORGANIZATION, pool.pool, pda::event_authority(), ID],
+            &[ORGANIZATION],
+        );
+    }
+
+    // Read-only Views
+
+    #[test]
+    fn organization_claimable_accounts() {
+        let pool = lion_pool();
+
+        assert_ix::<instruction::OrganizationClaimable>(
+            &organization_claimable(&pool),
+            &[pool.pool, pool.org_vault],
+            &[],
+        );
+    }
+
+    #[test]
+    fn quote_pool_accounts() {
+        let pool = lion_pool();
+        let accounts = [
+            pool.pool,
+            pool.pool_mint,
+            pool.pool_vault,
+            pool.pool_msol_account,
+            MARINADE_STATE,
+            LIQ_POOL_SOL_LEG,
+        ];
+
+        assert_ix::<instruction::QuoteDeposit>(&quote_deposit(&pool, 1), &accounts, &[]);
+        assert_ix::<instruction::QuoteWithdraw>(&quote_withdraw(&pool, 1), &accounts, &[]);
+        assert_ix::<instruction::PoolNav>(&pool_nav(&pool), &accounts, &[]);
+        assert_ix::<instruction::PendingOrgYield>(&pending_org_yield(&pool), &accounts, &[]);
+    }
+
+    #[test]
+    fn position_value_accounts() {
+        let pool = lion_pool();
+
+        assert_ix::<instruction::PositionValue>(
+            &position_value(&pool, &SUPPORTER),
+            &[
+                pool.pool,
+                pool.pool_mint,
+                ata(&SUPPORTER, &pool.pool_mint),
+                pda::supporter_position(&pool.pool, &SUPPORTER),
+                pool.pool_msol_account,
+                MARINADE_STATE,
+            ],
+            &[],
+        );
+    }
+
+    // Pool Configuration
+
+    #[test]
+    fn pool_configuration_accounts() {
+        let pool = lion_pool();
+        let accounts = [ADMIN, pool.pool, pda::event_authority(), ID];
+
+        assert_ix::<instruction::SetBadgeThresholds>(
+            &set_badge_thresholds(&ADMIN, &pool, [1, 2, 3]),
+            &accounts,
+            &[ADMIN],
+        );
+        assert_ix::<instruction::SetStreamCooldown>(
+            &set_stream_cooldown(&ADMIN, &pool, 1),
+            &accounts,
+            &[ADMIN],
+        );
+        assert_ix::<instruction::SetEscrowMode>(
+            &set_escrow_mode(&ADMIN, &pool, true, FALLBACK),
+            &accounts,
+            &[ADMIN],
+        );
+    }
+
+    #[test]
+    fn set_payout_asset_accounts() {
+        let pool = lion_pool();
+
+        assert_ix::<instruction::SetPayoutAsset>(
+            &set_payout_asset(&ORGANIZATION, &pool, PayoutAsset::Msol),
+            &[
+                ORGANIZATION,
+                pool.pool,
+                pool.org_vault,
+                MSOL_MINT,
+                ata(&pool.org_vault, &MSOL_MINT),
+                token::ID,
+                associated_token::ID,
+                system_program::ID,
+                pda::event_authority(),
+                ID,
+            ],
+            &[ORGANIZATION],
+        );
+    }
+
+    // Organization Authority
+
+    #[test]
+    fn organization_authority_rotation_accounts() {
+        let pool = lion_pool();
+
+        assert_ix::<instruction::ProposeOrganizationAuthority>(
+            &propose_organization_authority(&ORGANIZATION, &pool, NEW_AUTHORITY),
+            &[ORGANIZATION, pool.pool, pda::event_authority(), ID],
+            &[ORGANIZATION],
+        );
+        assert_ix::<instruction::CancelOrganizationAuthorityRotation>(
+            &cancel_organization_authority_rotation(&ORGANIZATION, &pool),
+            &[ORGANIZATION, pool.pool, pda::event_authority(), ID],
+            &[ORGANIZATION],
+        );
+        assert_ix::<instruction::ApproveOrganizationAuthorityRotation>(
+            &approve_organization_authority_rotation(&ADMIN, &pool, 86_400),
+            &[ADMIN, pool.pool, pda::event_authority(), ID],
+            &[ADMIN],
+        );
+        assert_ix::<instruction::AcceptOrganizationAuthority>(
+            &accept_organization_authority(&NEW_AUTHORITY, &pool),
+            &[NEW_AUTHORITY, pool.pool, pda::event_authority(), ID],
+            &[NEW_AUTHORITY],
+        );
+    }
+
+    // Impact Reporting
+
+    #[test]
+    fn publish_field_report_accounts() {
+        let pool = lion_pool();
+        let args = |covered_entries| PublishFieldReportArgs {
+            content_hash: [9; 32],
+            uri: "https://example.org/report.json".into(),
+            period_start: 0,
+            period_end: 1,
+            covered_entries,
+        };
+        let accounts = |spending_ledger| {
+            [
+                ORGANIZATION,
+                pool.pool,
+                pda::field_report(&pool.pool, 5),
+                spending_ledger,
+                system_program::ID,
+                pda::event_authority(),
+                ID,
+            ]
+        };
+
+        assert_ix::<instruction::PublishFieldReport>(
+            &publish_field_report(&ORGANIZATION, &pool, 5, args(None)),
+            &accounts(ID),
+            &[ORGANIZATION],
+        );
+
+        let covered = LedgerEntryRange {
+            first_entry: 0,
+            last_entry: 1,
+        };
+        assert_ix::<instruction::PublishFieldReport>(
+            &publish_field_report(&ORGANIZATION, &pool, 5, args(Some(covered))),
+            &accounts(pda::spending_ledger(&pool.pool)),
+            &[ORGANIZATION],
+        );
+    }
+
+    #[test]
+    fn set_attester_accounts() {
+        assert_ix::<instruction::SetAttester>(
+            &set_attester(&ADMIN, ATTESTER, true),
+            &[
+                ADMIN,
+                pda::attester(&ATTESTER),
+                system_program::ID,
+                pda::event_authority(),
+                ID,
+            ],
+            &[ADMIN],
+        );
+    }
+
+    #[test]
+    fn record_impact_metric_accounts() {
+        let pool = lion_pool();
+
+        assert_ix::<instruction::RecordImpactMetric>(
+            &record_impact_metric(
+                &ATTESTER,
+                &pool,
+                MetricKind::PopulationCount,
+                79,
+                1,
+                [9; 32],
+            ),
+            &[
+                ATTESTER,
+                pda::attester(&ATTESTER),
+                pool.pool,
+                pda::impact_metrics(&pool.pool),
+                system_program::ID,
+                pda::event_authority(),
+                ID,
+            ],
+            &[ATTESTER],
+        );
+    }
+
+    #[test]
+    fn claim_impact_badge_accounts() {
+        let pool = lion_pool();
+        let badge_mint = pda::badge_mint(&pool.pool, &SUPPORTER, 1);
+
+        assert_ix::<instruction::ClaimImpactBadge>(
+            &claim_impact_badge(&SUPPORTER, &pool, 1),
+            &[
+                SUPPORTER,
+                pool.pool,
+                pda::supporter_position(&pool.pool, &SUPPORTER),
+                ata(&SUPPORTER, &pool.pool_mint),
+                badge_mint,
+                pda::badge_account(&SUPPORTER, &badge_mint),
+                token_2022::ID,
+                associated_token::ID,
+                system_program::ID,
+                pda::event_authority(),
+                ID,
+            ],
+            &[SUPPORTER],
+        );
+    }
+
+    // Milestone Escrow
+
+    #[test]
+    fn add_milestone_accounts() {
+        let pool = lion_pool();
+
+        assert_ix::<instruction::AddMilestone>(
+            &add_milestone(&ADMIN, &pool, 0, &ATTESTER, 1, 1, [9; 32]),
+            &[
+                ADMIN,
+                pool.pool,
+                pda::attester(&ATTESTER),
+                pda::milestone(&pool.pool, 0),
+                system_program::ID,
+                pda::event_authority(),
+                ID,
+            ],
+            &[ADMIN],
+        );
+    }
+
+    #[test]
+    fn attest_milestone_accounts() {
+        let pool = lion_pool();
+
+        assert_ix::<instruction::AttestMilestone>(
+            &attest_milestone(&ATTESTER, &pool, 0, [9; 32]),
+            &[
+                ATTESTER,
+                pda::attester(&ATTESTER),
+                pool.pool,
+                pda::milestone(&pool.pool, 0),
+                pool.escrow_vault,
+                pool.org_vault,
+                system_program::ID,
+                pda::event_authority(),
+                ID,
+            ],
+            &[ATTESTER],
+        );
+    }
+
+    #[test]
+    fn reclaim_milestone_accounts() {
+        let pool = lion_pool();
+
+        assert_ix::<instruction::ReclaimMilestone>(
+            &reclaim_milestone(&pool, 0, &FALLBACK),
+            &[
+                pool.pool,
+                pda::milestone(&pool.pool, 0),
+                pool.escrow_vault,
+                FALLBACK,
+                system_program::ID,
+                pda::event_authority(),
+                ID,
+            ],
+            &[],
+        );
+    }
+
+    #[test]
+    fn release_excess_escrow_accounts() {
+        let pool = lion_pool();
+
+        assert_ix::<instruction::ReleaseExcessEscrow>(
+            &release_excess_escrow(&pool),
+            &[
+                pool.pool,
+                pool.escrow_vault,
+                pool.org_vault,
+                system_program::ID,
+                pda::event_authority(),
+                ID,
+            ],
+            &[],
+        );
+    }
+
+    // Organization Standing
+
+    /// Listing pages on the global, species and organization indexes
+    fn listing_pages(pool: &PoolAddresses) -> (RegistryPages, [Pubkey; 3]) {
+        let pages = RegistryPages {
+            registry: 1,
+            species: 2,
+            organization: 3,
+        };
+        let addresses = [
+            pda::registry_page(&pda::pool_registry(), 1),
+            pda::registry_page(&pda::species(&pool.species_id), 2),
+            pda::registry_page(&pda::organization(&ORGANIZATION), 3),
+        ];
+
+        (pages, addresses)
+    }
+
+    #[test]
+    fn suspend_organization_accounts() {
+        let pool = lion_pool();
+        let (pages, [registry_page, species_page, organization_page]) = listing_pages(&pool);
+
+        assert_ix::<instruction::SuspendOrganization>(
+            &suspend_organization(&ADMIN, &pool, pages),
+            &[
+                ADMIN,
+                pool.pool,
+                registry_page,
+                species_page,
+                organization_page,
+                pda::event_authority(),
+                ID,
+            ],
+            &[ADMIN],
+        );
+    }
+
+    #[test]
+    fn reinstate_organization_accounts() {
+        let pool = lion_pool();
+        let (pages, [registry_page, species_page, organization_page]) = listing_pages(&pool);
+
+        assert_ix::<instruction::ReinstateOrganization>(
+            &reinstate_organization(&ADMIN, &pool, pages),
+            &[
+                ADMIN,
+                pool.pool,
+                registry_page,
+                species_page,
+                organization_page,
+                pool.escrow_vault,
+                pool.org_vault,
+                system_program::ID,
+                pda::event_authority(),
+                ID,
+            ],
+            &[ADMIN],
+        );
+    }
+
+    #[test]
+    fn reassign_to_fallback_accounts() {
+        let pool = lion_pool();
+        let (pages, [registry_page, species_page, organization_page]) = listing_pages(&pool);
+
+        assert_ix::<instruction::ReassignToFallback>(
+            &reassign_to_fallback(&ADMIN, &pool, pages, &FALLBACK),
+            &[
+                ADMIN,
+                pool.pool,
+                registry_page,
+                species_page,
+                organization_page,
+                pda::organization(&FALLBACK),
+                pool.org_vault,
+                pool.escrow_vault,
+                system_program::ID,
+                pda::event_authority(),
+                ID,
+            ],
+            &[ADMIN],
+        );
+    }
+
+    #[test]
+    fn sweep_held_funds_accounts() {
+        let pool = lion_pool();
+
+        assert_ix::<instruction::SweepHeldFunds>(
+            &sweep_held_funds(&pool, &FALLBACK),
+            &[
+                pool.pool,
+                pool.escrow_vault,
+                FALLBACK,
+                system_program::ID,
+                pda::event_authority(),
+                ID,
+            ],
+            &[],
+        );
+    }
+}
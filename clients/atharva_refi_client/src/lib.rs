@@ -0,0 +1,16 @@
+//! Rust client for the Atharva ReFi program
+//!
+//! Mirrors the TypeScript helpers in `tests/utilities.ts` for backend services:
+//! - `pda`: program-derived addresses built from the program's `constants` seeds
+//! - `instructions`: typed builders for every program instruction
+//! - `accounts`: decoders for program accounts
+//! - `errors`: mapping of custom error codes back to `ErrorCode`
+//! - `marinade`: the Marinade accounts wired into staking instructions
+
+pub mod accounts;
+pub mod errors;
+pub mod instructions;
+pub mod marinade;
+pub mod pda;
+
+pub use atharva_refi::{constants, events, states, ID};
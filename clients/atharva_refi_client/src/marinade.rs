@@ -0,0 +1,15 @@
+use anchor_lang::prelude::*;
+
+pub use atharva_refi::constants::{
+    LIQ_POOL_MSOL_LEG, LIQ_POOL_SOL_LEG, MARINADE_PROGRAM_ID, MARINADE_STATE, MSOL_MINT,
+    TREASURY_MSOL_ACCOUNT,
+};
+
+// Accounts the program only receives from callers, so they live here
+// rather than in the program's `constants`
+pub const MSOL_MINT_AUTHORITY: Pubkey = pubkey!("3JLPCS1qM2zRw3Dp6V4hZnYHd4toMNPkNesXdX9tg6KM");
+
+pub const LIQ_POOL_MSOL_LEG_AUTHORITY: Pubkey =
+    pubkey!("EyaSjUtSgo9aRD1f8LWXwdvkpDTmXAW54yoSHZRF14WL");
+
+pub const RESERVE_PDA: Pubkey = pubkey!("Du3Ysj1wKbxPKkuPPnvzQLQh8oMSVifs3jGZjJWXFmHN");
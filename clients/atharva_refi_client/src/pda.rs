@@ -0,0 +1,356 @@
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::{
+    get_associated_token_address, get_associated_token_address_with_program_id,
+};
+use atharva_refi::constants::{
+    ATTESTER_SEED, BADGE_MINT_SEED, ESCROW_VAULT_SEED, FIELD_REPORT_SEED, IMPACT_METRICS_SEED,
+    MILESTONE_SEED, MSOL_MINT, ORGANIZATION_SEED, ORG_VAULT_SEED, POOL_HISTORY_SEED,
+    POOL_MINT_SEED, POOL_REGISTRY_SEED, POOL_SEED, POOL_VAULT_SEED, PROTOCOL_STATS_SEED,
+    QUEUED_WITHDRAWAL_SEED, REGISTRY_PAGE_SEED, SPECIES_SEED, SPENDING_LEDGER_SEED,
+    STREAM_RECORD_SEED, SUPPORTER_POSITION_SEED,
+};
+use atharva_refi::states::Species;
+use atharva_refi::ID;
+use ephemeral_rollups_sdk::pda::{
+    DELEGATE_BUFFER_TAG, DELEGATION_METADATA_TAG, DELEGATION_RECORD_TAG,
+};
+
+/// Seed anchor's `#[event_cpi]` derives the event authority from
+const EVENT_AUTHORITY_SEED: &[u8] = b"__event_authority";
+
+fn find(seeds: &[&[u8]]) -> Pubkey {
+    Pubkey::find_program_address(seeds, &ID).0
+}
+
+/// Species ID is the SHA-256 hash of the normalized, lowercased scientific name
+pub fn species_id(scientific_name: &str) -> [u8; 32] {
+    Species::id_for(scientific_name)
+}
+
+// Pool
+
+pub fn pool(organization_pubkey: &Pubkey, species_id: &[u8; 32]) -> Pubkey {
+    find(&[
+        POOL_SEED.as_bytes(),
+        organization_pubkey.as_ref(),
+        species_id,
+    ])
+}
+
+pub fn pool_vault(organization_pubkey: &Pubkey, species_id: &[u8; 32]) -> Pubkey {
+    find(&[
+        POOL_VAULT_SEED.as_bytes(),
+        organization_pubkey.as_ref(),
+        species_id,
+    ])
+}
+
+pub fn pool_mint(organization_pubkey: &Pubkey, species_id: &[u8; 32]) -> Pubkey {
+    find(&[
+        POOL_MINT_SEED.as_bytes(),
+        organization_pubkey.as_ref(),
+        species_id,
+    ])
+}
+
+pub fn org_vault(organization_pubkey: &Pubkey, species_id: &[u8; 32]) -> Pubkey {
+    find(&[
+        ORG_VAULT_SEED.as_bytes(),
+        organization_pubkey.as_ref(),
+        species_id,
+    ])
+}
+
+pub fn escrow_vault(organization_pubkey: &Pubkey, species_id: &[u8; 32]) -> Pubkey {
+    find(&[
+        ESCROW_VAULT_SEED.as_bytes(),
+        organization_pubkey.as_ref(),
+        species_id,
+    ])
+}
+
+/// mSOL token account owned by a vault, e.g. the pool's staked position
+pub fn msol_account(owner: &Pubkey) -> Pubkey {
+    get_associated_token_address(owner, &MSOL_MINT)
+}
+
+/// Every address derived from a pool's (organization, species) pair
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct PoolAddresses {
+    pub organization_pubkey: Pubkey,
+    pub species_id: [u8; 32],
+    pub pool: Pubkey,
+    pub pool_vault: Pubkey,
+    pub pool_mint: Pubkey,
+    pub org_vault: Pubkey,
+    pub escrow_vault: Pubkey,
+    pub pool_msol_account: Pubkey,
+}
+impl PoolAddresses {
+    pub fn new(organization_pubkey: Pubkey, species_id: [u8; 32]) -> Self {
+        let pool_vault = pool_vault(&organization_pubkey, &species_id);
+
+        Self {
+            organization_pubkey,
+            species_id,
+            pool: pool(&organization_pubkey, &species_id),
+            pool_vault,
+            pool_mint: pool_mint(&organization_pubkey, &species_id),
+            org_vault: org_vault(&organization_pubkey, &species_id),
+            escrow_vault: escrow_vault(&organization_pubkey, &species_id),
+            pool_msol_account: msol_account(&pool_vault),
+        }
+    }
+}
+
+pub fn supporter_position(pool: &Pubkey, supporter: &Pubkey) -> Pubkey {
+    find(&[
+        SUPPORTER_POSITION_SEED.as_bytes(),
+        pool.as_ref(),
+        supporter.as_ref(),
+    ])
+}
+
+pub fn badge_mint(pool: &Pubkey, supporter: &Pubkey, tier: u8) -> Pubkey {
+    find(&[
+        BADGE_MINT_SEED.as_bytes(),
+        pool.as_ref(),
+        supporter.as_ref(),
+        &[tier],
+    ])
+}
+
+/// Supporter's Token-2022 account holding a badge
+pub fn badge_account(supporter: &Pubkey, badge_mint: &Pubkey) -> Pubkey {
+    get_associated_token_address_with_program_id(supporter, badge_mint, &anchor_spl::token_2022::ID)
+}
+
+pub fn stream_record(pool: &Pubkey, sequence: u64) -> Pubkey {
+    find(&[
+        STREAM_RECORD_SEED.as_bytes(),
+        pool.as_ref(),
+        &sequence.to_le_bytes(),
+    ])
+}
+
+pub fn pool_history(pool: &Pubkey) -> Pubkey {
+    find(&[POOL_HISTORY_SEED.as_bytes(), pool.as_ref()])
+}
+
+pub fn spending_ledger(pool: &Pubkey) -> Pubkey {
+    find(&[SPENDING_LEDGER_SEED.as_bytes(), pool.as_ref()])
+}
+
+pub fn queued_withdrawal(pool: &Pubkey, sequence: u64) -> Pubkey {
+    find(&[
+        QUEUED_WITHDRAWAL_SEED.as_bytes(),
+        pool.as_ref(),
+        &sequence.to_le_bytes(),
+    ])
+}
+
+pub fn field_report(pool: &Pubkey, sequence: u64) -> Pubkey {
+    find(&[
+        FIELD_REPORT_SEED.as_bytes(),
+        pool.as_ref(),
+        &sequence.to_le_bytes(),
+    ])
+}
+
+pub fn impact_metrics(pool: &Pubkey) -> Pubkey {
+    find(&[IMPACT_METRICS_SEED.as_bytes(), pool.as_ref()])
+}
+
+pub fn milestone(pool: &Pubkey, index: u8) -> Pubkey {
+    find(&[MILESTONE_SEED.as_bytes(), pool.as_ref(), &[index]])
+}
+
+// Registries
+
+pub fn organization(organization_pubkey: &Pubkey) -> Pubkey {
+    find(&[ORGANIZATION_SEED.as_bytes(), organization_pubkey.as_ref()])
+}
+
+pub fn species(species_id: &[u8; 32]) -> Pubkey {
+    find(&[SPECIES_SEED.as_bytes(), species_id])
+}
+
+pub fn attester(attester: &Pubkey) -> Pubkey {
+    find(&[ATTESTER_SEED.as_bytes(), attester.as_ref()])
+}
+
+pub fn pool_registry() -> Pubkey {
+    find(&[POOL_REGISTRY_SEED.as_bytes()])
+}
+
+/// Page of an index, keyed by the `PoolRegistry`, `Species` or `Organization` account
+pub fn registry_page(index_key: &Pubkey, page: u32) -> Pubkey {
+    find(&[
+        REGISTRY_PAGE_SEED.as_bytes(),
+        index_key.as_ref(),
+        &page.to_le_bytes(),
+    ])
+}
+
+pub fn protocol_stats() -> Pubkey {
+    find(&[PROTOCOL_STATS_SEED.as_bytes()])
+}
+
+pub fn event_authority() -> Pubkey {
+    find(&[EVENT_AUTHORITY_SEED])
+}
+
+// MagicBlock Delegation
+
+pub fn delegate_buffer(pool: &Pubkey) -> Pubkey {
+    find(&[DELEGATE_BUFFER_TAG, pool.as_ref()])
+}
+
+pub fn delegation_record(pool: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(
+        &[DELEGATION_RECORD_TAG, pool.as_ref()],
+        &ephemeral_rollups_sdk::id(),
+    )
+    .0
+}
+
+pub fn delegation_metadata(pool: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(
+        &[DELEGATION_METADATA_TAG, pool.as_ref()],
+        &ephemeral_rollups_sdk::id(),
+    )
+    .0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ephemeral_rollups_sdk::pda as dlp_pda;
+    use solana_program::hash::hash;
+
+    const ORGANIZATION: Pubkey = Pubkey::new_from_array([2; 32]);
+    const SUPPORTER: Pubkey = Pubkey::new_from_array([3; 32]);
+    const ATTESTER: Pubkey = Pubkey::new_from_array([4; 32]);
+
+    // Seeds are spelled out as literals, so a renamed constant shows up here
+    fn program_address(seeds: &[&[u8]]) -> Pubkey {
+        Pubkey::find_program_address(seeds, &ID).0
+    }
+
+    fn lion() -> [u8; 32] {
+        species_id("Panthera leo")
+    }
+
+    #[test]
+    fn species_id_hashes_the_normalized_name() {
+        assert_eq!(lion(), hash(b"panthera leo").to_bytes());
+        assert_eq!(species_id("  panthera   LEO "), lion());
+    }
+
+    #[test]
+    fn pool_addresses_match_program_seeds() {
+        let id = lion();
+        let org = ORGANIZATION.as_ref();
+        let pool = PoolAddresses::new(ORGANIZATION, id);
+
+        assert_eq!(pool.pool, program_address(&[b"pool", org, &id]));
+        assert_eq!(pool.pool_vault, program_address(&[b"pool_vault", org, &id]));
+        assert_eq!(pool.pool_mint, program_address(&[b"pool_mint", org, &id]));
+        assert_eq!(
+            pool.org_vault,
+            program_address(&[b"organization_vault", org, &id])
+        );
+        assert_eq!(
+            pool.escrow_vault,
+            program_address(&[b"escrow_vault", org, &id])
+        );
+        assert_eq!(
+            pool.pool_msol_account,
+            get_associated_token_address(&pool.pool_vault, &MSOL_MINT)
+        );
+    }
+
+    #[test]
+    fn pool_scoped_addresses_match_program_seeds() {
+        let pool = PoolAddresses::new(ORGANIZATION, lion()).pool;
+        let key = pool.as_ref();
+        let sequence = 7u64.to_le_bytes();
+
+        assert_eq!(
+            supporter_position(&pool, &SUPPORTER),
+            program_address(&[b"supporter_position", key, SUPPORTER.as_ref()])
+        );
+        assert_eq!(
+            badge_mint(&pool, &SUPPORTER, 2),
+            program_address(&[b"badge_mint", key, SUPPORTER.as_ref(), &[2]])
+        );
+        assert_eq!(
+            stream_record(&pool, 7),
+            program_address(&[b"stream_record", key, &sequence])
+        );
+        assert_eq!(
+            queued_withdrawal(&pool, 7),
+            program_address(&[b"queued_withdrawal", key, &sequence])
+        );
+        assert_eq!(
+            field_report(&pool, 7),
+            program_address(&[b"field_report", key, &sequence])
+        );
+        assert_eq!(
+            pool_history(&pool),
+            program_address(&[b"pool_history", key])
+        );
+        assert_eq!(
+            spending_ledger(&pool),
+            program_address(&[b"spending_ledger", key])
+        );
+        assert_eq!(
+            impact_metrics(&pool),
+            program_address(&[b"impact_metrics", key])
+        );
+        assert_eq!(
+            milestone(&pool, 3),
+            program_address(&[b"milestone", key, &[3]])
+        );
+    }
+
+    #[test]
+    fn registry_addresses_match_program_seeds() {
+        let registry = program_address(&[b"pool_registry"]);
+
+        assert_eq!(
+            organization(&ORGANIZATION),
+            program_address(&[b"organization", ORGANIZATION.as_ref()])
+        );
+        assert_eq!(species(&lion()), program_address(&[b"species", &lion()]));
+        assert_eq!(
+            attester(&ATTESTER),
+            program_address(&[b"attester", ATTESTER.as_ref()])
+        );
+        assert_eq!(pool_registry(), registry);
+        assert_eq!(
+            registry_page(&registry, 5),
+            program_address(&[b"registry_page", registry.as_ref(), &5u32.to_le_bytes()])
+        );
+        assert_eq!(protocol_stats(), program_address(&[b"protocol_stats"]));
+        assert_eq!(event_authority(), program_address(&[b"__event_authority"]));
+    }
+
+    #[test]
+    fn delegation_addresses_match_delegation_program() {
+        let pool = PoolAddresses::new(ORGANIZATION, lion()).pool;
+        // The delegation program builds on its own `Pubkey` type
+        let dlp_key = |key: Pubkey| key.to_bytes().into();
+
+        let buffer = dlp_pda::delegate_buffer_pda_from_delegated_account_and_owner_program(
+            &dlp_key(pool),
+            &dlp_key(ID),
+        );
+        let record = dlp_pda::delegation_record_pda_from_delegated_account(&dlp_key(pool));
+        let metadata = dlp_pda::delegation_metadata_pda_from_delegated_account(&dlp_key(pool));
+
+        assert_eq!(delegate_buffer(&pool).to_bytes(), buffer.to_bytes());
+        assert_eq!(delegation_record(&pool).to_bytes(), record.to_bytes());
+        assert_eq!(delegation_metadata(&pool).to_bytes(), metadata.to_bytes());
+    }
+}
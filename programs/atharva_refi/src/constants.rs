@@ -6,12 +6,70 @@ pub const POOL_SEED: &str = "pool";
 pub const POOL_VAULT_SEED: &str = "pool_vault";
 pub const POOL_MINT_SEED: &str = "pool_mint";
 pub const ORG_VAULT_SEED: &str = "organization_vault";
-// pub const STREAM_INTERVAL: u64 = 172_800; // 2 days in seconds
+pub const ORGANIZATION_SEED: &str = "organization";
+pub const SPECIES_SEED: &str = "species";
+pub const POOL_REGISTRY_SEED: &str = "pool_registry";
+pub const REGISTRY_PAGE_SEED: &str = "registry_page";
+pub const PROTOCOL_STATS_SEED: &str = "protocol_stats";
+pub const POOL_HISTORY_SEED: &str = "pool_history";
+pub const ESCROW_VAULT_SEED: &str = "escrow_vault";
+pub const MILESTONE_SEED: &str = "milestone";
+pub const SUPPORTER_POSITION_SEED: &str = "supporter_position";
+pub const BADGE_MINT_SEED: &str = "badge_mint";
+pub const STREAM_RECORD_SEED: &str = "stream_record";
+pub const QUEUED_WITHDRAWAL_SEED: &str = "queued_withdrawal";
+pub const SPENDING_LEDGER_SEED: &str = "spending_ledger";
+pub const FIELD_REPORT_SEED: &str = "field_report";
+pub const ATTESTER_SEED: &str = "attester";
+pub const IMPACT_METRICS_SEED: &str = "impact_metrics";
+pub const STREAM_INTERVAL: u64 = 172_800; // 2 days in seconds
+pub const MIN_STREAM_COOLDOWN: u64 = 86_400; // 1 day in seconds
+pub const AUTHORITY_ROTATION_DELAY: u64 = 172_800; // 2 days in seconds
+pub const MIN_RECOVERY_DELAY: u64 = 86_400; // 1 day in seconds
+pub const DEFAULT_WITHDRAW_WINDOW: u64 = 86_400; // 1 day in seconds
+pub const SUSPENSION_REVIEW_WINDOW: u64 = 604_800; // 7 days in seconds
 pub const STREAM_INTERVAL_MS: u64 = 172_800_000; // 2 days in milliseconds
 pub const MIN_YIELD_AMOUNT: u64 = 1_000_000; // 0.001 SOL
 pub const ORG_YIELD_BPS: u128 = 2_000; // 20%
+pub const YIELD_PER_SHARE_PRECISION: u128 = 1_000_000_000_000; // 1e12
+pub const MSOL_PRICE_PRECISION: u128 = 1_000_000_000_000; // 1e12
+pub const ACCRUAL_RATE_PRECISION: u128 = 1_000_000_000_000; // 1e12
+
+// Spending Ledger
+pub const SPENDING_CATEGORIES: usize = 5; // Variants of `SpendingCategory`
+pub const SPENDING_LEDGER_LEN: usize = 32;
+
+// Impact Metrics
+pub const METRIC_KINDS: usize = 3; // Variants of `MetricKind`
+pub const IMPACT_HISTORY_LEN: usize = 16;
+
+// Organization Registry
+pub const MAX_ORGANIZATION_SPECIES: usize = 8;
+
+// Pool Registry
+pub const REGISTRY_PAGE_CAPACITY: usize = 32;
+
+// Pool History
+pub const POOL_HISTORY_LEN: usize = 32;
+pub const MIN_SNAPSHOT_INTERVAL: u64 = 3_600; // 1 hour in seconds
+pub const SECONDS_PER_YEAR: u64 = 31_536_000; // 365 days
+
+// Events
+pub const EVENT_SCHEMA_VERSION: u8 = 1; // Bumped on any event layout change
+
+// Impact Badges
+pub const BADGE_TIERS: usize = 3; // Bronze, Silver, Gold
+pub const BADGE_SYMBOL: &str = "ARFIB";
 
 // // Marinade Finance
 pub const MARINADE_PROGRAM_ID: Pubkey = pubkey!("MarBmsSgKXdrN1egZf5sqe1TMai9K1rChYNDJgjq7aD");
 
 pub const MSOL_MINT: Pubkey = pubkey!("mSoLzYCxHdYgdzU16g5QSh3i5K3z3KZK7ytfqcJm7So");
+
+pub const MARINADE_STATE: Pubkey = pubkey!("8szGkuLTAux9XMgZ2vtY39jVSowEcpBfFfD8hXSEqdGC");
+
+pub const LIQ_POOL_SOL_LEG: Pubkey = pubkey!("UefNb6z6yvArqe4cJHTXCqStRsKmWhGxnZzuHbikP5Q");
+
+pub const LIQ_POOL_MSOL_LEG: Pubkey = pubkey!("7GgPYjS5Dza89wV6FpZ23kUJRG5vbQ1GM25ezspYFSoE");
+
+pub const TREASURY_MSOL_ACCOUNT: Pubkey = pubkey!("8ZUcztoAEhpAeC2ixWewJKQJsSUGYSGPVAjkhDJYf5Gd");
@@ -3,7 +3,7 @@ use anchor_lang::prelude::*;
 #[error_code]
 pub enum ErrorCode {
     // --- Authorization Errors ---
-    #[msg("Unauthorized to create pool. Only the designated admin can.")]
+    #[msg("Unauthorized to create pool. Only the admin or the verified organization can.")]
     CreatePoolUnauthorized,
     #[msg("Unauthorized to stake. Only the designated organization or admin can.")]
     StakingUnauthorized,
@@ -13,6 +13,12 @@ pub enum ErrorCode {
     InvalidOrganizationAuthority,
     #[msg("Only the organization can withdraw")]
     UnauthorizedOrganization,
+    #[msg("Only the designated admin can perform this action")]
+    AdminUnauthorized,
+    #[msg("Only the admin or the pool's organization can perform this action")]
+    PoolConfigUnauthorized,
+    #[msg("Only the pending organization authority can accept the rotation")]
+    PendingAuthorityMismatch,
 
     // --- State & Validation Errors ---
     #[msg("Arithmetic overflow")]
@@ -35,10 +41,14 @@ pub enum ErrorCode {
     // --- Staking & Yield Errors ---
     #[msg("Unmatched Program Key")]
     MarinadeProgramError,
-    #[msg("Settlement can only occur once per day")]
+    #[msg("Stream cooldown has not elapsed since the last stream")]
     SettlementTooFrequent,
     #[msg("Yield amount too small to settle")]
     YieldTooSmall,
+    #[msg("A stream record account is required for this stream")]
+    StreamRecordRequired,
+    #[msg("Stream cooldown is below the minimum allowed")]
+    CooldownTooShort,
     #[msg("Invalid yield percentage")]
     InvalidYieldPercentage,
     #[msg("Invalid Marinade state account")]
@@ -47,6 +57,12 @@ pub enum ErrorCode {
     InvalidMsolAccount,
     #[msg("Invalid mSOL mint")]
     InvalidMsolMint,
+    #[msg("Organization mSOL account is required for mSOL payouts")]
+    MissingOrganizationMsolAccount,
+    #[msg("Marinade liquidity pool cannot cover the unstake")]
+    InsufficientLiquidity,
+    #[msg("SOL received is below the requested minimum")]
+    SlippageExceeded,
 
     // --- MagicBlock Crank Errors ---
     #[msg("Invalid Magic Program account provided")]
@@ -59,6 +75,10 @@ pub enum ErrorCode {
     SerializationError,
     #[msg("Crank automation is already scheduled for this pool")]
     CrankAlreadyScheduled,
+    #[msg("No crank automation is scheduled for this pool")]
+    CrankNotScheduled,
+    #[msg("Only the admin or the pool's organization can manage its crank")]
+    UnauthorizedCrankAuthority,
 
     // --- Pool Lifecycle Errors ---
     #[msg("Insufficient shares to withdraw requested amount")]
@@ -67,4 +87,108 @@ pub enum ErrorCode {
     PoolEmpty,
     #[msg("Invalid token account provided")]
     InvalidTokenAccount,
+
+    // --- Organization Authority Errors ---
+    #[msg("New organization authority must differ from the current one")]
+    InvalidNewAuthority,
+    #[msg("No organization authority rotation is pending")]
+    NoPendingAuthorityRotation,
+    #[msg("Organization authority rotation timelock has not elapsed")]
+    AuthorityRotationTimelocked,
+    #[msg("Only the admin can change an admin-proposed authority rotation")]
+    AdminRotationPending,
+    #[msg("Admin approval cannot shorten a rotation below the minimum recovery delay")]
+    RecoveryDelayTooShort,
+
+    // --- Organization Withdrawal Controls Errors ---
+    #[msg("Withdrawal exceeds the pool's rolling withdrawal cap")]
+    WithdrawalCapExceeded,
+    #[msg("Withdrawal queue is disabled for this pool")]
+    WithdrawalQueueDisabled,
+    #[msg("Queued withdrawal is still in its delay window")]
+    WithdrawalStillLocked,
+    #[msg("Queued withdrawal can no longer be vetoed")]
+    VetoWindowClosed,
+    #[msg("Only the admin may loosen withdrawal controls")]
+    WithdrawalControlsLoosened,
+
+    // --- Field Report Errors ---
+    #[msg("Field report period is invalid")]
+    InvalidReportPeriod,
+    #[msg("Covered ledger entries overlap an earlier report or are not yet recorded")]
+    InvalidCoveredEntries,
+
+    // --- Impact Metrics Errors ---
+    #[msg("Attester is not registered or has been revoked")]
+    AttesterNotActive,
+    #[msg("Survey date must be set and not in the future")]
+    InvalidSurveyDate,
+
+    // --- Milestone Escrow Errors ---
+    #[msg("Escrow mode requires the pool to pay out in SOL")]
+    EscrowRequiresSolPayout,
+    #[msg("A fallback beneficiary must be set to enable escrow mode")]
+    FallbackBeneficiaryNotSet,
+    #[msg("Milestone amount and deadline are invalid")]
+    InvalidMilestone,
+    #[msg("Milestone has already been released or reclaimed")]
+    MilestoneNotPending,
+    #[msg("Milestone deadline has passed")]
+    MilestoneDeadlinePassed,
+    #[msg("Milestone deadline has not passed yet")]
+    MilestoneDeadlineNotReached,
+    #[msg("Escrow balance does not cover the milestone tranche")]
+    InsufficientEscrowBalance,
+    #[msg("Attester is not approved for this milestone")]
+    MilestoneAttesterMismatch,
+    #[msg("Milestones can only be added before the pool takes deposits")]
+    MilestonesLocked,
+    #[msg("Escrow mode must be off to release excess escrow")]
+    EscrowModeEnabled,
+    #[msg("Milestones resolve in order; an earlier milestone is still pending")]
+    MilestoneOutOfOrder,
+
+    // --- Organization Standing Errors ---
+    #[msg("Organization is not active for this pool")]
+    OrganizationNotActive,
+    #[msg("Organization is not suspended for this pool")]
+    OrganizationNotSuspended,
+    #[msg("Pool has not been reassigned to a fallback beneficiary")]
+    PoolNotReassigned,
+    #[msg("Suspension review window has not elapsed")]
+    ReviewWindowNotElapsed,
+
+    // --- Organization Registry Errors ---
+    #[msg("Organization is not verified")]
+    OrganizationNotVerified,
+    #[msg("Organization already has a pending or approved application")]
+    OrganizationAlreadyApplied,
+    #[msg("Organization application is not pending review")]
+    ApplicationNotPending,
+    #[msg("Rejection reason code must be non-zero")]
+    InvalidRejectionReason,
+    #[msg("Species is not approved for this organization")]
+    SpeciesNotApproved,
+
+    // --- Pool Registry Errors ---
+    #[msg("Registry page is full")]
+    RegistryPageFull,
+    #[msg("Pool is not listed on this registry page")]
+    PoolNotListed,
+
+    // --- Pool History Errors ---
+    #[msg("Pool snapshots must be at least an hour apart")]
+    SnapshotTooFrequent,
+
+    // --- Impact Badge Errors ---
+    #[msg("Invalid impact badge tier")]
+    InvalidBadgeTier,
+    #[msg("Badge thresholds must be non-zero and strictly increasing")]
+    InvalidBadgeThresholds,
+    #[msg("Impact badge tier is not configured for this pool")]
+    BadgeTierNotConfigured,
+    #[msg("Donated yield has not reached the badge tier threshold")]
+    BadgeThresholdNotReached,
+    #[msg("Impact badge tier already claimed")]
+    BadgeAlreadyClaimed,
 }
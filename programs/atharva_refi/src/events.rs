@@ -1,38 +1,523 @@
 use anchor_lang::prelude::*;
 
+use crate::constants::BADGE_TIERS;
+use crate::states::{
+    IucnCategory, MetricKind, OrganizationStanding, PayoutAsset, PoolListingStatus,
+    SpendingCategory,
+};
+
 #[event]
 pub struct PoolCreated {
+    pub version: u8,
     pub pool: Pubkey,
-    pub organization_pubkey: Pubkey,
+    pub organization: Pubkey,
     pub organization_name: String,
+    pub species: Pubkey,
+    pub species_id: [u8; 32],
     pub species_name: String,
-    pub species_id: String,
+    pub scientific_name: String,
     pub timestamp: u64,
 }
 
 #[event]
-pub struct SupporterDeposited {
+pub struct SpeciesRegistered {
+    pub version: u8,
+    pub species: Pubkey,
+    pub species_id: [u8; 32],
+    pub scientific_name: String,
+    pub common_name: String,
+    pub iucn_category: IucnCategory,
+    pub region: String,
+    pub registered_by: Pubkey,
+    pub timestamp: u64,
+}
+
+#[event]
+pub struct PoolListingSynced {
+    pub version: u8,
+    pub pool: Pubkey,
+    pub registry_page: Pubkey,
+    pub status: PoolListingStatus,
+    pub timestamp: u64,
+}
+
+#[event]
+pub struct PoolSnapshotRecorded {
+    pub version: u8,
+    pub pool: Pubkey,
+    pub nav: u64,
+    pub msol_price: u128,
+    pub total_shares: u64,
+    /// Zero until the history spans two distinct timestamps
+    pub trailing_apy_bps: u64,
+    pub timestamp: u64,
+}
+
+#[event]
+pub struct OrganizationRegistered {
+    pub version: u8,
+    pub organization: Pubkey,
     pub organization_pubkey: Pubkey,
-    pub species_name: String,
+    pub name: String,
+    pub metadata_uri: String,
+    pub verified_by: Pubkey,
+    pub timestamp: u64,
+}
+
+#[event]
+pub struct OrganizationApplied {
+    pub version: u8,
+    pub organization: Pubkey,
+    pub organization_pubkey: Pubkey,
+    pub name: String,
+    pub metadata_uri: String,
+    pub document_hash: [u8; 32],
+    pub species_ids: Vec<[u8; 32]>,
+    pub timestamp: u64,
+}
+
+#[event]
+pub struct OrganizationApproved {
+    pub version: u8,
+    pub organization: Pubkey,
+    pub organization_pubkey: Pubkey,
+    pub verified_by: Pubkey,
+    pub timestamp: u64,
+}
+
+#[event]
+pub struct OrganizationRejected {
+    pub version: u8,
+    pub organization: Pubkey,
+    pub organization_pubkey: Pubkey,
+    pub reason_code: u16,
+    pub timestamp: u64,
+}
+
+#[event]
+pub struct OrganizationAuthorityRotationProposed {
+    pub version: u8,
+    pub pool: Pubkey,
+    pub current_authority: Pubkey,
+    pub pending_authority: Pubkey,
+    pub proposed_by: Pubkey,
+    pub eta: u64,
+    pub timestamp: u64,
+}
+
+#[event]
+pub struct OrganizationAuthorityRotationCancelled {
+    pub version: u8,
+    pub pool: Pubkey,
+    pub pending_authority: Pubkey,
+    pub cancelled_by: Pubkey,
+    pub timestamp: u64,
+}
+
+#[event]
+pub struct OrganizationAuthorityRotationApproved {
+    pub version: u8,
+    pub pool: Pubkey,
+    pub pending_authority: Pubkey,
+    pub eta: u64,
+    pub timestamp: u64,
+}
+
+#[event]
+pub struct OrganizationAuthorityRotated {
+    pub version: u8,
+    pub pool: Pubkey,
+    pub previous_authority: Pubkey,
+    pub new_authority: Pubkey,
+    pub timestamp: u64,
+}
+
+#[event]
+pub struct WithdrawalControlsUpdated {
+    pub version: u8,
+    pub pool: Pubkey,
+    pub withdraw_cap: u64,
+    pub withdraw_window: u64,
+    pub withdraw_delay: u64,
+    pub updated_by: Pubkey,
+    pub timestamp: u64,
+}
+
+#[event]
+pub struct WithdrawalQueued {
+    pub version: u8,
+    pub pool: Pubkey,
+    pub queued_withdrawal: Pubkey,
+    pub recipient: Pubkey,
+    pub amount: u64,
+    pub asset: PayoutAsset,
+    pub release_ts: u64,
+    pub timestamp: u64,
+}
+
+#[event]
+pub struct QueuedWithdrawalReleased {
+    pub version: u8,
+    pub pool: Pubkey,
+    pub queued_withdrawal: Pubkey,
+    pub recipient: Pubkey,
+    pub amount: u64,
+    pub asset: PayoutAsset,
+    pub timestamp: u64,
+}
+
+#[event]
+pub struct QueuedWithdrawalVetoed {
+    pub version: u8,
+    pub pool: Pubkey,
+    pub queued_withdrawal: Pubkey,
+    pub recipient: Pubkey,
+    pub amount: u64,
+    pub asset: PayoutAsset,
+    pub vetoed_by: Pubkey,
+    pub timestamp: u64,
+}
+
+#[event]
+pub struct SpendingRecorded {
+    pub version: u8,
+    pub pool: Pubkey,
+    pub organization: Pubkey,
+    pub category: SpendingCategory,
+    pub amount: u64,
+    pub memo_hash: [u8; 32],
+    pub entry: u64,
+    pub category_total: u64,
+    pub timestamp: u64,
+}
+
+#[event]
+pub struct FieldReportPublished {
+    pub version: u8,
+    pub pool: Pubkey,
+    pub field_report: Pubkey,
+    pub publisher: Pubkey,
+    pub sequence: u64,
+    pub content_hash: [u8; 32],
+    pub uri: String,
+    pub period_start: u64,
+    pub period_end: u64,
+    pub timestamp: u64,
+}
+
+#[event]
+pub struct AttesterUpdated {
+    pub version: u8,
+    pub attester: Pubkey,
+    pub is_active: bool,
+    pub timestamp: u64,
+}
+
+#[event]
+pub struct ImpactMetricRecorded {
+    pub version: u8,
+    pub pool: Pubkey,
+    pub attester: Pubkey,
+    pub kind: MetricKind,
+    pub value: u64,
+    pub survey_date: u64,
+    pub source_hash: [u8; 32],
+    pub timestamp: u64,
+}
+
+#[event]
+pub struct EscrowModeUpdated {
+    pub version: u8,
+    pub pool: Pubkey,
+    pub escrow_mode: bool,
+    pub fallback_beneficiary: Pubkey,
+    pub timestamp: u64,
+}
+
+#[event]
+pub struct MilestoneAdded {
+    pub version: u8,
+    pub pool: Pubkey,
+    pub milestone: Pubkey,
+    pub index: u8,
+    pub amount: u64,
+    pub deadline: u64,
+    pub criteria_hash: [u8; 32],
+    pub attester: Pubkey,
+    pub timestamp: u64,
+}
+
+#[event]
+pub struct MilestoneReleased {
+    pub version: u8,
+    pub pool: Pubkey,
+    pub milestone: Pubkey,
+    pub index: u8,
+    pub amount: u64,
+    pub attester: Pubkey,
+    pub evidence_hash: [u8; 32],
+    pub timestamp: u64,
+}
+
+#[event]
+pub struct ExcessEscrowReleased {
+    pub version: u8,
+    pub pool: Pubkey,
+    pub amount: u64,
+    pub timestamp: u64,
+}
+
+#[event]
+pub struct MilestoneReclaimed {
+    pub version: u8,
+    pub pool: Pubkey,
+    pub milestone: Pubkey,
+    pub index: u8,
+    pub amount: u64,
+    pub fallback_beneficiary: Pubkey,
+    pub timestamp: u64,
+}
+
+#[event]
+pub struct YieldHeld {
+    pub version: u8,
+    pub pool: Pubkey,
+    pub organization: Pubkey,
+    pub standing: OrganizationStanding,
+    pub amount: u64,
+    pub held_balance: u64,
+    pub timestamp: u64,
+}
+
+#[event]
+pub struct OrganizationSuspended {
+    pub version: u8,
+    pub pool: Pubkey,
+    pub organization: Pubkey,
+    pub review_ends_at: u64,
+    pub timestamp: u64,
+}
+
+#[event]
+pub struct OrganizationReinstated {
+    pub version: u8,
+    pub pool: Pubkey,
+    pub organization: Pubkey,
+    pub released_amount: u64,
+    pub timestamp: u64,
+}
+
+#[event]
+pub struct PoolReassignedToFallback {
+    pub version: u8,
+    pub pool: Pubkey,
+    pub organization: Pubkey,
+    pub fallback_beneficiary: Pubkey,
+    /// Unvested organization yield moved to the held balance
+    pub unvested_held: u64,
+    pub timestamp: u64,
+}
+
+#[event]
+pub struct HeldFundsSwept {
+    pub version: u8,
+    pub pool: Pubkey,
+    pub fallback_beneficiary: Pubkey,
     pub amount: u64,
+    pub timestamp: u64,
+}
+
+#[event]
+pub struct SupporterDeposited {
+    pub version: u8,
+    pub pool: Pubkey,
+    pub supporter: Pubkey,
+    pub amount: u64,
+    pub shares_minted: u64,
+    pub timestamp: u64,
+}
+
+#[event]
+pub struct PoolStaked {
+    pub version: u8,
+    pub pool: Pubkey,
+    pub sol_amount: u64,
+    pub msol_amount: u64,
+    pub timestamp: u64,
+}
+
+#[event]
+pub struct PoolUnstaked {
+    pub version: u8,
+    pub pool: Pubkey,
+    pub msol_amount: u64,
+    pub sol_amount: u64,
+    pub timestamp: u64,
 }
 
 #[event]
 pub struct YieldStreamed {
+    pub version: u8,
     pub pool: Pubkey,
     pub organization: Pubkey,
     pub total_yield: u64,
     pub org_amount: u64,
+    pub msol_amount: u64,
     pub pool_amount: u64,
     pub timestamp: u64,
 }
 
+#[event]
+pub struct OrganizationAccrualStarted {
+    pub version: u8,
+    pub pool: Pubkey,
+    pub organization: Pubkey,
+    pub unvested: u64,
+    pub rate: u128,
+    pub period_end: u64,
+    pub timestamp: u64,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum StreamSkipReason {
+    YieldBelowMinimum,
+    BelowHighWaterMark,
+}
+
+#[event]
+pub struct StreamSkipped {
+    pub version: u8,
+    pub pool: Pubkey,
+    pub organization: Pubkey,
+    pub observed_sol_value: u64,
+    pub pending_yield: u64,
+    pub min_yield: u64,
+    pub reason: StreamSkipReason,
+    pub timestamp: u64,
+}
+
+#[event]
+pub struct LossRecorded {
+    pub version: u8,
+    pub pool: Pubkey,
+    pub peak_msol_price: u128,
+    pub current_msol_price: u128,
+    pub peak_sol_value: u64,
+    pub current_sol_value: u64,
+    pub shortfall: u64,
+    pub timestamp: u64,
+}
+
+#[event]
+pub struct LossRecovered {
+    pub version: u8,
+    pub pool: Pubkey,
+    pub peak_msol_price: u128,
+    pub current_sol_value: u64,
+    pub timestamp: u64,
+}
+
+#[event]
+pub struct PayoutAssetUpdated {
+    pub version: u8,
+    pub pool: Pubkey,
+    pub payout_asset: PayoutAsset,
+    pub timestamp: u64,
+}
+
+#[event]
+pub struct OrganizationWithdrew {
+    pub version: u8,
+    pub pool: Pubkey,
+    pub organization: Pubkey,
+    pub amount: u64,
+    pub timestamp: u64,
+}
+
+#[event]
+pub struct OrganizationWithdrewMsol {
+    pub version: u8,
+    pub pool: Pubkey,
+    pub organization: Pubkey,
+    pub msol_amount: u64,
+    pub sol_value: u64,
+    pub timestamp: u64,
+}
+
 #[event]
 pub struct SupporterWithdrew {
-    pub supporter: Pubkey,
+    pub version: u8,
     pub pool: Pubkey,
+    pub supporter: Pubkey,
     pub share_amount: u64,
     pub msol_amount: u64,
+    /// SOL received after Marinade's liquid-unstake fee
     pub sol_amount: u64,
+    pub unstake_fee_bps: u32,
+    pub timestamp: u64,
+}
+
+#[event]
+pub struct BadgeThresholdsUpdated {
+    pub version: u8,
+    pub pool: Pubkey,
+    pub thresholds: [u64; BADGE_TIERS],
+    pub timestamp: u64,
+}
+
+#[event]
+pub struct ImpactBadgeClaimed {
+    pub version: u8,
+    pub pool: Pubkey,
+    pub supporter: Pubkey,
+    pub badge_mint: Pubkey,
+    pub tier: u8,
+    pub donated_yield: u64,
+    pub timestamp: u64,
+}
+
+#[event]
+pub struct StreamScheduled {
+    pub version: u8,
+    pub pool: Pubkey,
+    pub task_id: u64,
+    pub execution_interval_millis: u64,
+    pub iterations: u64,
+    pub scheduled_by: Pubkey,
+    pub timestamp: u64,
+}
+
+#[event]
+pub struct StreamScheduleCancelled {
+    pub version: u8,
+    pub pool: Pubkey,
+    pub task_id: u64,
+    pub cancelled_by: Pubkey,
+    pub timestamp: u64,
+}
+
+#[event]
+pub struct PoolDelegated {
+    pub version: u8,
+    pub pool: Pubkey,
+    /// Rollup validator, if one was pinned
+    pub validator: Option<Pubkey>,
+    pub delegated_by: Pubkey,
+    pub timestamp: u64,
+}
+
+#[event]
+pub struct PoolUndelegated {
+    pub version: u8,
+    pub pool: Pubkey,
+    pub undelegated_by: Pubkey,
+    pub timestamp: u64,
+}
+
+#[event]
+pub struct StreamCooldownUpdated {
+    pub version: u8,
+    pub pool: Pubkey,
+    pub stream_cooldown: u64,
     pub timestamp: u64,
 }
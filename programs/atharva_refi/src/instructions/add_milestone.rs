@@ -0,0 +1,117 @@
+use crate::constants::{
+    ADMIN_PUBKEY, ATTESTER_SEED, EVENT_SCHEMA_VERSION, MILESTONE_SEED, POOL_SEED,
+};
+use crate::errors::ErrorCode;
+use crate::events::MilestoneAdded;
+use crate::states::{Attester, Milestone, MilestoneStatus, Pool};
+use crate::utilities::emit_cpi_event;
+use anchor_lang::prelude::*;
+
+/// Defines the pool's next escrow milestone
+///
+/// Milestones are append-only and immutable once added, so donors
+/// can rely on the terms their yield is held against. They can only be
+/// added while the pool is being set up, before it takes deposits, and
+/// each names the registered attester that may confirm it
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct AddMilestone<'info> {
+    #[account(
+        mut,
+        address = ADMIN_PUBKEY @ ErrorCode::AdminUnauthorized
+    )]
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            POOL_SEED.as_bytes(),
+            pool.organization_pubkey.as_ref(),
+            &pool.new_species_id
+        ],
+        bump = pool.pool_bump,
+        constraint = pool.total_shares == 0 @ ErrorCode::MilestonesLocked,
+    )]
+    pub pool: Account<'info, Pool>,
+
+    #[account(
+        seeds = [ATTESTER_SEED.as_bytes(), attester_account.attester.as_ref()],
+        bump = attester_account.bump,
+        constraint = attester_account.is_active @ ErrorCode::AttesterNotActive,
+    )]
+    pub attester_account: Account<'info, Attester>,
+
+    #[account(
+        init,
+        payer = admin,
+        space = 8 + Milestone::INIT_SPACE,
+        seeds = [
+            MILESTONE_SEED.as_bytes(),
+            pool.key().as_ref(),
+            &[pool.milestone_count],
+        ],
+        bump,
+    )]
+    pub milestone: Account<'info, Milestone>,
+
+    pub system_program: Program<'info, System>,
+}
+impl<'info> AddMilestone<'info> {
+    pub fn process(
+        &mut self,
+        amount: u64,
+        deadline: u64,
+        criteria_hash: [u8; 32],
+        bumps: &AddMilestoneBumps,
+    ) -> Result<()> {
+        let current_time = Clock::get()?.unix_timestamp as u64;
+
+        // Validation
+        require!(
+            amount > 0 && deadline > current_time,
+            ErrorCode::InvalidMilestone
+        );
+        require!(criteria_hash != [0; 32], ErrorCode::InvalidInput);
+
+        let index = self.pool.milestone_count;
+
+        let milestone = &mut self.milestone;
+        milestone.pool = self.pool.key();
+        milestone.index = index;
+        milestone.amount = amount;
+        milestone.deadline = deadline;
+        milestone.criteria_hash = criteria_hash;
+        milestone.attester = self.attester_account.attester;
+        milestone.status = MilestoneStatus::Pending;
+        milestone.attested_by = Pubkey::default();
+        milestone.evidence_hash = [0; 32];
+        milestone.resolved_at = 0;
+        milestone.bump = bumps.milestone;
+
+        self.pool.milestone_count = index.checked_add(1).ok_or(ErrorCode::MathError)?;
+        self.pool.escrow_committed = self
+            .pool
+            .escrow_committed
+            .checked_add(amount)
+            .ok_or(ErrorCode::MathError)?;
+
+        emit_cpi_event(
+            &self.event_authority,
+            bumps.event_authority,
+            MilestoneAdded {
+                version: EVENT_SCHEMA_VERSION,
+                pool: self.pool.key(),
+                milestone: milestone.key(),
+                index,
+                amount,
+                deadline,
+                criteria_hash,
+                attester: self.attester_account.attester,
+                timestamp: current_time,
+            },
+        )?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,98 @@
+use crate::constants::{EVENT_SCHEMA_VERSION, MAX_ORGANIZATION_SPECIES, ORGANIZATION_SEED};
+use crate::errors::ErrorCode;
+use crate::events::OrganizationApplied;
+use crate::states::{Organization, VerificationStatus};
+use crate::utilities::emit_cpi_event;
+use anchor_lang::prelude::*;
+
+/// Submits an organization's application to the registry
+///
+/// The verification documents stay off-chain; the application records
+/// their content hash and a metadata URI for the admin to review, along
+/// with the species the organization will run pools for. Approval covers
+/// exactly these species. Rejected organizations may apply again with new documents
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ApplyOrganization<'info> {
+    #[account(mut)]
+    pub applicant: Signer<'info>,
+
+    #[account(
+        init_if_needed,
+        payer = applicant,
+        space = 8 + Organization::INIT_SPACE,
+        seeds = [ORGANIZATION_SEED.as_bytes(), applicant.key().as_ref()],
+        bump,
+    )]
+    pub organization: Account<'info, Organization>,
+
+    pub system_program: Program<'info, System>,
+}
+impl<'info> ApplyOrganization<'info> {
+    pub fn process(
+        &mut self,
+        name: String,
+        metadata_uri: String,
+        document_hash: [u8; 32],
+        species_ids: Vec<[u8; 32]>,
+        bumps: &ApplyOrganizationBumps,
+    ) -> Result<()> {
+        // Validation
+        require!(
+            !name.is_empty() && name.len() <= 50,
+            ErrorCode::InvalidStringLength
+        );
+        require!(metadata_uri.len() <= 200, ErrorCode::InvalidStringLength);
+        require!(document_hash != [0; 32], ErrorCode::InvalidInput);
+        require!(
+            !species_ids.is_empty() && species_ids.len() <= MAX_ORGANIZATION_SPECIES,
+            ErrorCode::InvalidInput
+        );
+
+        let organization = &mut self.organization;
+        require!(
+            matches!(
+                organization.status,
+                VerificationStatus::Unverified | VerificationStatus::Rejected
+            ),
+            ErrorCode::OrganizationAlreadyApplied
+        );
+
+        let current_time = Clock::get()?.unix_timestamp as u64;
+
+        // Fresh accounts only; a re-application keeps its history
+        if organization.created_at == 0 {
+            organization.organization_pubkey = self.applicant.key();
+            organization.authority = self.applicant.key();
+            organization.pool_count = 0;
+            organization.created_at = current_time;
+            organization.bump = bumps.organization;
+        }
+
+        organization.name = name.clone();
+        organization.metadata_uri = metadata_uri.clone();
+        organization.document_hash = document_hash;
+        organization.species_ids = species_ids.clone();
+        organization.status = VerificationStatus::Pending;
+
+        emit_cpi_event(
+            &self.event_authority,
+            bumps.event_authority,
+            OrganizationApplied {
+                version: EVENT_SCHEMA_VERSION,
+                organization: organization.key(),
+                organization_pubkey: self.applicant.key(),
+                name,
+                metadata_uri,
+                document_hash,
+                species_ids,
+                timestamp: current_time,
+            },
+        )?;
+
+        msg!("Organization application submitted: {}", organization.key());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,161 @@
+use crate::constants::{
+    ATTESTER_SEED, ESCROW_VAULT_SEED, EVENT_SCHEMA_VERSION, MILESTONE_SEED, ORG_VAULT_SEED,
+    POOL_SEED,
+};
+use crate::errors::ErrorCode;
+use crate::events::MilestoneReleased;
+use crate::states::{Attester, Milestone, MilestoneStatus, OrganizationStanding, Pool};
+use crate::utilities::emit_cpi_event;
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{transfer, Transfer};
+
+/// Confirms a milestone was met and releases its tranche from the
+/// escrow vault to the organization vault
+///
+/// Only the attester named on the milestone may confirm it, only while
+/// the organization is active for the pool, and only once every earlier
+/// milestone is resolved
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct AttestMilestone<'info> {
+    pub attester: Signer<'info>,
+
+    #[account(
+        seeds = [ATTESTER_SEED.as_bytes(), attester.key().as_ref()],
+        bump = attester_account.bump,
+        constraint = attester_account.is_active @ ErrorCode::AttesterNotActive,
+    )]
+    pub attester_account: Account<'info, Attester>,
+
+    #[account(
+        mut,
+        seeds = [
+            POOL_SEED.as_bytes(),
+            pool.organization_pubkey.as_ref(),
+            &pool.new_species_id
+        ],
+        bump = pool.pool_bump,
+        constraint = pool.org_standing == OrganizationStanding::Active
+            @ ErrorCode::OrganizationNotActive,
+    )]
+    pub pool: Account<'info, Pool>,
+
+    #[account(
+        mut,
+        seeds = [
+            MILESTONE_SEED.as_bytes(),
+            pool.key().as_ref(),
+            &[milestone.index],
+        ],
+        bump = milestone.bump,
+        has_one = pool,
+        constraint = milestone.attester == attester.key() @ ErrorCode::MilestoneAttesterMismatch,
+        constraint = milestone.index == pool.next_milestone
+            @ ErrorCode::MilestoneOutOfOrder,
+    )]
+    pub milestone: Account<'info, Milestone>,
+
+    #[account(
+        mut,
+        seeds = [
+            ESCROW_VAULT_SEED.as_bytes(),
+            pool.organization_pubkey.as_ref(),
+            &pool.new_species_id
+        ],
+        bump = pool.escrow_vault_bump,
+    )]
+    pub escrow_vault: SystemAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            ORG_VAULT_SEED.as_bytes(),
+            pool.organization_pubkey.as_ref(),
+            &pool.new_species_id
+        ],
+        bump = pool.org_vault_bump,
+    )]
+    pub organization_vault: SystemAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+impl<'info> AttestMilestone<'info> {
+    pub fn process(&mut self, evidence_hash: [u8; 32], bumps: &AttestMilestoneBumps) -> Result<()> {
+        let current_time = Clock::get()?.unix_timestamp as u64;
+        let amount = self.milestone.amount;
+
+        // Validation
+        require!(evidence_hash != [0; 32], ErrorCode::InvalidInput);
+        require!(
+            self.milestone.status == MilestoneStatus::Pending,
+            ErrorCode::MilestoneNotPending
+        );
+        require!(
+            current_time <= self.milestone.deadline,
+            ErrorCode::MilestoneDeadlinePassed
+        );
+        require!(
+            self.pool.escrow_balance >= amount,
+            ErrorCode::InsufficientEscrowBalance
+        );
+
+        let pool = &self.pool;
+        let seeds = &[
+            ESCROW_VAULT_SEED.as_bytes(),
+            pool.organization_pubkey.as_ref(),
+            &pool.new_species_id,
+            &[pool.escrow_vault_bump],
+        ];
+        let signer_seeds = &[&seeds[..]];
+
+        transfer(
+            CpiContext::new_with_signer(
+                self.system_program.to_account_info(),
+                Transfer {
+                    from: self.escrow_vault.to_account_info(),
+                    to: self.organization_vault.to_account_info(),
+                },
+                signer_seeds,
+            ),
+            amount,
+        )?;
+
+        self.pool.escrow_balance -= amount;
+        self.pool.escrow_committed = self.pool.escrow_committed.saturating_sub(amount);
+        self.pool.next_milestone = self
+            .pool
+            .next_milestone
+            .checked_add(1)
+            .ok_or(ErrorCode::MathError)?;
+
+        let milestone = &mut self.milestone;
+        milestone.status = MilestoneStatus::Released;
+        milestone.attested_by = self.attester.key();
+        milestone.evidence_hash = evidence_hash;
+        milestone.resolved_at = current_time;
+
+        emit_cpi_event(
+            &self.event_authority,
+            bumps.event_authority,
+            MilestoneReleased {
+                version: EVENT_SCHEMA_VERSION,
+                pool: self.pool.key(),
+                milestone: milestone.key(),
+                index: milestone.index,
+                amount,
+                attester: self.attester.key(),
+                evidence_hash,
+                timestamp: current_time,
+            },
+        )?;
+
+        msg!(
+            "Milestone {} released: {} lamports",
+            milestone.index,
+            amount
+        );
+
+        Ok(())
+    }
+}
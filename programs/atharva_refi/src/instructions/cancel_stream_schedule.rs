@@ -0,0 +1,97 @@
+use anchor_lang::prelude::*;
+use ephemeral_rollups_sdk::consts::MAGIC_PROGRAM_ID;
+
+use crate::constants::{ADMIN_PUBKEY, EVENT_SCHEMA_VERSION, POOL_SEED, POOL_VAULT_SEED};
+use crate::errors::ErrorCode;
+use crate::events::StreamScheduleCancelled;
+use crate::magicblock::{magicblock_cancel_task, TaskAccounts};
+use crate::states::Pool;
+use crate::utilities::emit_cpi_event;
+
+/// Cancels the pool's MagicBlock stream crank
+///
+/// Clears `is_crank_scheduled` so the pool can be scheduled again,
+/// e.g. with a new interval or after its task died
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct CancelStreamSchedule<'info> {
+    #[account(
+        mut,
+        constraint = authority.key() == ADMIN_PUBKEY
+            || authority.key() == pool.organization_authority
+            @ ErrorCode::UnauthorizedCrankAuthority,
+    )]
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            POOL_SEED.as_bytes(),
+            pool.organization_pubkey.as_ref(),
+            &pool.new_species_id
+        ],
+        bump = pool.pool_bump,
+        constraint = pool.is_crank_scheduled @ ErrorCode::CrankNotScheduled,
+    )]
+    pub pool: Account<'info, Pool>,
+
+    /// Task authority that signed the original schedule CPI
+    #[account(
+        mut,
+        seeds = [
+            POOL_VAULT_SEED.as_bytes(),
+            pool.organization_pubkey.as_ref(),
+            &pool.new_species_id,
+        ],
+        bump = pool.pool_vault_bump,
+    )]
+    pub pool_vault: SystemAccount<'info>,
+
+    /// CHECK: used for MagicBlock program CPI
+    #[account(address = MAGIC_PROGRAM_ID)]
+    pub magic_program: AccountInfo<'info>,
+}
+impl<'info> CancelStreamSchedule<'info> {
+    pub fn process(&mut self, bumps: &CancelStreamScheduleBumps) -> Result<()> {
+        let task_id = self.pool.crank_task_id;
+
+        let seeds = &[
+            POOL_VAULT_SEED.as_bytes(),
+            self.pool.organization_pubkey.as_ref(),
+            &self.pool.new_species_id,
+            &[self.pool.pool_vault_bump],
+        ];
+        let signer_seeds = &[&seeds[..]];
+
+        magicblock_cancel_task(
+            task_id,
+            TaskAccounts {
+                magic_program: self.magic_program.to_account_info(),
+                task_authority: self.pool_vault.to_account_info(),
+                task_context: self.pool.to_account_info(),
+            },
+            signer_seeds,
+        )?;
+
+        // Update pool state
+        self.pool.is_crank_scheduled = false;
+        self.pool.crank_task_id = 0;
+
+        emit_cpi_event(
+            &self.event_authority,
+            bumps.event_authority,
+            StreamScheduleCancelled {
+                version: EVENT_SCHEMA_VERSION,
+                pool: self.pool.key(),
+                task_id,
+                cancelled_by: self.authority.key(),
+                timestamp: Clock::get()?.unix_timestamp as u64,
+            },
+        )?;
+
+        msg!("Crank cancelled: task_id={}", task_id);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,401 @@
+use crate::constants::{
+    BADGE_MINT_SEED, BADGE_SYMBOL, BADGE_TIERS, EVENT_SCHEMA_VERSION, POOL_SEED,
+    SUPPORTER_POSITION_SEED,
+};
+use crate::errors::ErrorCode;
+use crate::events::ImpactBadgeClaimed;
+use crate::states::{Pool, SupporterPosition};
+use crate::utilities::emit_cpi_event;
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{
+    allocate, assign, create_account, transfer, Allocate, Assign, CreateAccount, Transfer,
+};
+use anchor_spl::associated_token::{
+    create, get_associated_token_address, get_associated_token_address_with_program_id,
+    AssociatedToken, Create,
+};
+use anchor_spl::token::TokenAccount;
+use anchor_spl::token_2022::spl_token_2022::{
+    extension::ExtensionType, instruction::AuthorityType, state::Mint,
+};
+use anchor_spl::token_2022::{
+    initialize_mint2, mint_to, set_authority, InitializeMint2, MintTo, SetAuthority, Token2022,
+};
+use anchor_spl::token_2022_extensions::spl_token_metadata_interface::state::{
+    Field, TokenMetadata,
+};
+use anchor_spl::token_2022_extensions::{
+    metadata_pointer_initialize, non_transferable_mint_initialize,
+    spl_pod::optional_keys::OptionalNonZeroPubkey, token_metadata_initialize,
+    token_metadata_update_field, MetadataPointerInitialize, NonTransferableMintInitialize,
+    TokenMetadataInitialize, TokenMetadataUpdateField,
+};
+
+/// Mints a soulbound (non-transferable) Token-2022 impact badge to a supporter
+///
+/// Architecture:
+/// - One badge mint per (pool, supporter, tier)
+/// - Tier unlocks once the supporter's lifetime donated yield passes
+///   the pool's configured threshold
+/// - Donated yield is only credited on pool tokens the supporter still
+///   holds, so transferring receipts away stops earning toward badges
+/// - Badge metadata lives on the mint itself and records pool, species and tier
+/// - Mint authority is revoked after the single token is issued
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(tier: u8)]
+pub struct ClaimImpactBadge<'info> {
+    #[account(mut)]
+    pub supporter: Signer<'info>,
+
+    #[account(
+        seeds = [
+            POOL_SEED.as_bytes(),
+            pool.organization_pubkey.as_ref(),
+            &pool.new_species_id
+        ],
+        bump = pool.pool_bump,
+    )]
+    pub pool: Account<'info, Pool>,
+
+    #[account(
+        mut,
+        seeds = [
+            SUPPORTER_POSITION_SEED.as_bytes(),
+            pool.key().as_ref(),
+            supporter.key().as_ref(),
+        ],
+        bump = supporter_position.bump,
+    )]
+    pub supporter_position: Account<'info, SupporterPosition>,
+
+    #[account(
+        address = get_associated_token_address(&supporter.key(), &pool.pool_mint)
+            @ ErrorCode::InvalidTokenAccount,
+    )]
+    pub supporter_pool_token_account: Account<'info, TokenAccount>,
+
+    /// Soulbound badge mint, created in `process` because the
+    /// non-transferable extension must precede mint initialization
+    /// CHECK: Uninitialized PDA, seeds enforce one badge per tier
+    #[account(
+        mut,
+        seeds = [
+            BADGE_MINT_SEED.as_bytes(),
+            pool.key().as_ref(),
+            supporter.key().as_ref(),
+            &[tier],
+        ],
+        bump,
+    )]
+    pub badge_mint: UncheckedAccount<'info>,
+
+    /// CHECK: Supporter's Token-2022 ATA for the badge, created in `process`
+    #[account(
+        mut,
+        address = get_associated_token_address_with_program_id(
+            &supporter.key(),
+            &badge_mint.key(),
+            &token_2022_program.key(),
+        ) @ ErrorCode::InvalidTokenAccount,
+    )]
+    pub supporter_badge_account: UncheckedAccount<'info>,
+
+    pub token_2022_program: Program<'info, Token2022>,
+
+    pub associated_token_program: Program<'info, AssociatedToken>,
+
+    pub system_program: Program<'info, System>,
+}
+impl<'info> ClaimImpactBadge<'info> {
+    pub fn process(&mut self, tier: u8, bumps: &ClaimImpactBadgeBumps) -> Result<()> {
+        // Validation
+        require!((tier as usize) < BADGE_TIERS, ErrorCode::InvalidBadgeTier);
+
+        let threshold = self.pool.badge_thresholds[tier as usize];
+        require!(threshold > 0, ErrorCode::BadgeTierNotConfigured);
+
+        let position = &mut self.supporter_position;
+        require!(
+            position.badges_claimed & (1 << tier) == 0,
+            ErrorCode::BadgeAlreadyClaimed
+        );
+
+        position.settle(
+            self.pool.donated_yield_per_share,
+            self.supporter_pool_token_account.amount,
+        )?;
+        require!(
+            position.donated_yield >= threshold,
+            ErrorCode::BadgeThresholdNotReached
+        );
+
+        position.badges_claimed |= 1 << tier;
+        let donated_yield = position.donated_yield;
+
+        self.create_badge_mint(tier, bumps.badge_mint)?;
+        self.initialize_metadata(tier)?;
+        self.mint_badge()?;
+
+        emit_cpi_event(
+            &self.event_authority,
+            bumps.event_authority,
+            ImpactBadgeClaimed {
+                version: EVENT_SCHEMA_VERSION,
+                pool: self.pool.key(),
+                supporter: self.supporter.key(),
+                badge_mint: self.badge_mint.key(),
+                tier,
+                donated_yield,
+                timestamp: Clock::get()?.unix_timestamp as u64,
+            },
+        )?;
+
+        msg!(
+            "Impact badge tier {} claimed: {}",
+            tier,
+            self.badge_mint.key()
+        );
+
+        Ok(())
+    }
+
+    fn create_badge_mint(&self, tier: u8, bump: u8) -> Result<()> {
+        let pool_key = self.pool.key();
+        let supporter_key = self.supporter.key();
+        let seeds = &[
+            BADGE_MINT_SEED.as_bytes(),
+            pool_key.as_ref(),
+            supporter_key.as_ref(),
+            &[tier],
+            &[bump],
+        ];
+        let signer_seeds = &[&seeds[..]];
+
+        let space = ExtensionType::try_calculate_account_len::<Mint>(&[
+            ExtensionType::NonTransferable,
+            ExtensionType::MetadataPointer,
+        ])?;
+
+        let rent = Rent::get()?.minimum_balance(space);
+        let current_lamports = self.badge_mint.lamports();
+
+        // Tolerate lamports sent to the PDA ahead of time
+        if current_lamports == 0 {
+            create_account(
+                CpiContext::new_with_signer(
+                    self.system_program.to_account_info(),
+                    CreateAccount {
+                        from: self.supporter.to_account_info(),
+                        to: self.badge_mint.to_account_info(),
+                    },
+                    signer_seeds,
+                ),
+                rent,
+                space as u64,
+                &self.token_2022_program.key(),
+            )?;
+        } else {
+            let top_up = rent.saturating_sub(current_lamports);
+            if top_up > 0 {
+                transfer(
+                    CpiContext::new(
+                        self.system_program.to_account_info(),
+                        Transfer {
+                            from: self.supporter.to_account_info(),
+                            to: self.badge_mint.to_account_info(),
+                        },
+                    ),
+                    top_up,
+                )?;
+            }
+            allocate(
+                CpiContext::new_with_signer(
+                    self.system_program.to_account_info(),
+                    Allocate {
+                        account_to_allocate: self.badge_mint.to_account_info(),
+                    },
+                    signer_seeds,
+                ),
+                space as u64,
+            )?;
+            assign(
+                CpiContext::new_with_signer(
+                    self.system_program.to_account_info(),
+                    Assign {
+                        account_to_assign: self.badge_mint.to_account_info(),
+                    },
+                    signer_seeds,
+                ),
+                &self.token_2022_program.key(),
+            )?;
+        }
+
+        // Extensions must be initialized before the mint itself
+        non_transferable_mint_initialize(CpiContext::new(
+            self.token_2022_program.to_account_info(),
+            NonTransferableMintInitialize {
+                token_program_id: self.token_2022_program.to_account_info(),
+                mint: self.badge_mint.to_account_info(),
+            },
+        ))?;
+
+        metadata_pointer_initialize(
+            CpiContext::new(
+                self.token_2022_program.to_account_info(),
+                MetadataPointerInitialize {
+                    token_program_id: self.token_2022_program.to_account_info(),
+                    mint: self.badge_mint.to_account_info(),
+                },
+            ),
+            Some(pool_key),
+            Some(self.badge_mint.key()),
+        )?;
+
+        initialize_mint2(
+            CpiContext::new(
+                self.token_2022_program.to_account_info(),
+                InitializeMint2 {
+                    mint: self.badge_mint.to_account_info(),
+                },
+            ),
+            0,
+            &pool_key,
+            None,
+        )?;
+
+        create(CpiContext::new(
+            self.associated_token_program.to_account_info(),
+            Create {
+                payer: self.supporter.to_account_info(),
+                associated_token: self.supporter_badge_account.to_account_info(),
+                authority: self.supporter.to_account_info(),
+                mint: self.badge_mint.to_account_info(),
+                system_program: self.system_program.to_account_info(),
+                token_program: self.token_2022_program.to_account_info(),
+            },
+        ))
+    }
+
+    fn initialize_metadata(&self, tier: u8) -> Result<()> {
+        let pool = &self.pool;
+
+        let name = format!("{} Guardian Tier {}", pool.species_name, tier + 1);
+        let additional_metadata = vec![
+            ("pool".to_string(), pool.key().to_string()),
+            ("species".to_string(), pool.scientific_name.clone()),
+            ("tier".to_string(), (tier + 1).to_string()),
+        ];
+
+        let metadata = TokenMetadata {
+            update_authority: OptionalNonZeroPubkey(pool.key()),
+            mint: self.badge_mint.key(),
+            name: name.clone(),
+            symbol: BADGE_SYMBOL.to_string(),
+            uri: String::new(),
+            additional_metadata: additional_metadata.clone(),
+        };
+
+        // Token-2022 reallocs the mint for metadata, so fund the extra rent upfront
+        let metadata_len = metadata.tlv_size_of()?;
+        let mint_info = self.badge_mint.to_account_info();
+        let required_lamports = Rent::get()?.minimum_balance(mint_info.data_len() + metadata_len);
+        let extra_lamports = required_lamports.saturating_sub(mint_info.lamports());
+
+        if extra_lamports > 0 {
+            transfer(
+                CpiContext::new(
+                    self.system_program.to_account_info(),
+                    Transfer {
+                        from: self.supporter.to_account_info(),
+                        to: mint_info,
+                    },
+                ),
+                extra_lamports,
+            )?;
+        }
+
+        let seeds = &[
+            POOL_SEED.as_bytes(),
+            pool.organization_pubkey.as_ref(),
+            &pool.new_species_id,
+            &[pool.pool_bump],
+        ];
+        let signer_seeds = &[&seeds[..]];
+
+        token_metadata_initialize(
+            CpiContext::new_with_signer(
+                self.token_2022_program.to_account_info(),
+                TokenMetadataInitialize {
+                    program_id: self.token_2022_program.to_account_info(),
+                    metadata: self.badge_mint.to_account_info(),
+                    update_authority: pool.to_account_info(),
+                    mint_authority: pool.to_account_info(),
+                    mint: self.badge_mint.to_account_info(),
+                },
+                signer_seeds,
+            ),
+            name,
+            BADGE_SYMBOL.to_string(),
+            String::new(),
+        )?;
+
+        for (key, value) in additional_metadata {
+            token_metadata_update_field(
+                CpiContext::new_with_signer(
+                    self.token_2022_program.to_account_info(),
+                    TokenMetadataUpdateField {
+                        program_id: self.token_2022_program.to_account_info(),
+                        metadata: self.badge_mint.to_account_info(),
+                        update_authority: pool.to_account_info(),
+                    },
+                    signer_seeds,
+                ),
+                Field::Key(key),
+                value,
+            )?;
+        }
+
+        Ok(())
+    }
+
+    fn mint_badge(&self) -> Result<()> {
+        let pool = &self.pool;
+
+        let seeds = &[
+            POOL_SEED.as_bytes(),
+            pool.organization_pubkey.as_ref(),
+            &pool.new_species_id,
+            &[pool.pool_bump],
+        ];
+        let signer_seeds = &[&seeds[..]];
+
+        mint_to(
+            CpiContext::new_with_signer(
+                self.token_2022_program.to_account_info(),
+                MintTo {
+                    mint: self.badge_mint.to_account_info(),
+                    to: self.supporter_badge_account.to_account_info(),
+                    authority: pool.to_account_info(),
+                },
+                signer_seeds,
+            ),
+            1,
+        )?;
+
+        // Fix supply at one so the badge is a true NFT
+        set_authority(
+            CpiContext::new_with_signer(
+                self.token_2022_program.to_account_info(),
+                SetAuthority {
+                    current_authority: pool.to_account_info(),
+                    account_or_mint: self.badge_mint.to_account_info(),
+                },
+                signer_seeds,
+            ),
+            AuthorityType::MintTokens,
+            None,
+        )
+    }
+}
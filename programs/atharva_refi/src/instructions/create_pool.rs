@@ -1,10 +1,17 @@
 use crate::constants::{
-    ADMIN_PUBKEY, MSOL_MINT, ORG_VAULT_SEED, POOL_MINT_SEED, POOL_SEED, POOL_VAULT_SEED,
+    ADMIN_PUBKEY, BADGE_TIERS, DEFAULT_WITHDRAW_WINDOW, ESCROW_VAULT_SEED, EVENT_SCHEMA_VERSION,
+    MSOL_MINT, ORGANIZATION_SEED, ORG_VAULT_SEED, POOL_MINT_SEED, POOL_REGISTRY_SEED, POOL_SEED,
+    POOL_VAULT_SEED, PROTOCOL_STATS_SEED, REGISTRY_PAGE_SEED, SPECIES_SEED, STREAM_INTERVAL,
 };
 use crate::errors::ErrorCode;
 use crate::events::PoolCreated;
-use crate::states::Pool;
+use crate::states::{
+    Organization, OrganizationStanding, PayoutAsset, Pool, PoolListing, PoolListingStatus,
+    PoolRegistry, ProtocolStats, RegistryIndex, RegistryPage, Species, VerificationStatus,
+};
+use crate::utilities::emit_cpi_event;
 use anchor_lang::prelude::*;
+use anchor_lang::system_program::{transfer, Transfer};
 use anchor_spl::associated_token::AssociatedToken;
 use anchor_spl::token::{Mint, Token, TokenAccount};
 
@@ -12,34 +19,60 @@ use anchor_spl::token::{Mint, Token, TokenAccount};
 ///
 /// Architecture:
 /// - One pool per (organization, species) pair
+/// - Species must be registered; several organizations may share one
 /// - Each pool has isolated vault for deposits
 /// - Organization has isolated vault for yield collection
+/// - Organization must be verified in the registry
+/// - Admin or the verified organization's current authority may create the pool;
+///   the organization only for a species approved with its application
+/// - Pool is listed in the global, species and organization registry pages
+/// - Escrow vault is funded rent-exempt, so escrowed and held streams of
+///   any size can land in it; the reserve is never counted as escrow
 
+#[event_cpi]
 #[derive(Accounts)]
-#[instruction(
-    organization_name: String,
-    organization_pubkey: Pubkey,
-    species_name: String,
-    species_id: [u8; 32],
-)]
 pub struct CreatePool<'info> {
     #[account(
         mut,
-        address = ADMIN_PUBKEY @ ErrorCode::CreatePoolUnauthorized
+        constraint = authority.key() == ADMIN_PUBKEY
+            || authority.key() == organization.authority
+            @ ErrorCode::CreatePoolUnauthorized,
+    )]
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            ORGANIZATION_SEED.as_bytes(),
+            organization.organization_pubkey.as_ref(),
+        ],
+        bump = organization.bump,
+        constraint = organization.status == VerificationStatus::Verified
+            @ ErrorCode::OrganizationNotVerified,
+    )]
+    pub organization: Account<'info, Organization>,
+
+    #[account(
+        mut,
+        seeds = [SPECIES_SEED.as_bytes(), &species.species_id],
+        bump = species.bump,
+        constraint = authority.key() == ADMIN_PUBKEY
+            || organization.species_ids.contains(&species.species_id)
+            @ ErrorCode::SpeciesNotApproved,
     )]
-    pub admin: Signer<'info>,
+    pub species: Account<'info, Species>,
 
     #[account(address = MSOL_MINT)]
     pub msol_mint: Account<'info, Mint>,
 
     #[account(
         init,
-        payer = admin,
+        payer = authority,
         space = 8 + Pool::INIT_SPACE,
         seeds = [
             POOL_SEED.as_bytes(),
-            organization_pubkey.as_ref(),
-            &species_id,
+            organization.organization_pubkey.as_ref(),
+            &species.species_id,
         ],
         bump,
     )]
@@ -47,14 +80,14 @@ pub struct CreatePool<'info> {
 
     #[account(
         init,
-        payer = admin,
+        payer = authority,
         mint::decimals = 9,
         mint::authority = pool,
         mint::token_program = token_program,
         seeds = [
             POOL_MINT_SEED.as_bytes(),
-            organization_pubkey.as_ref(),
-            &species_id,
+            organization.organization_pubkey.as_ref(),
+            &species.species_id,
         ],
         bump,
     )]
@@ -63,8 +96,8 @@ pub struct CreatePool<'info> {
     #[account(
         seeds = [
             POOL_VAULT_SEED.as_bytes(),
-            organization_pubkey.as_ref(),
-            &species_id
+            organization.organization_pubkey.as_ref(),
+            &species.species_id
         ],
         bump,
     )]
@@ -73,18 +106,89 @@ pub struct CreatePool<'info> {
     #[account(
         seeds = [
             ORG_VAULT_SEED.as_bytes(),
-            organization_pubkey.as_ref(),
-            &species_id
+            organization.organization_pubkey.as_ref(),
+            &species.species_id
         ],
         bump,
     )]
     pub organization_vault: SystemAccount<'info>,
 
+    #[account(
+        mut,
+        seeds = [
+            ESCROW_VAULT_SEED.as_bytes(),
+            organization.organization_pubkey.as_ref(),
+            &species.species_id
+        ],
+        bump,
+    )]
+    pub escrow_vault: SystemAccount<'info>,
+
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + PoolRegistry::INIT_SPACE,
+        seeds = [POOL_REGISTRY_SEED.as_bytes()],
+        bump,
+    )]
+    pub pool_registry: Box<Account<'info, PoolRegistry>>,
+
+    /// Current page of the global index
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + RegistryPage::INIT_SPACE,
+        seeds = [
+            REGISTRY_PAGE_SEED.as_bytes(),
+            pool_registry.key().as_ref(),
+            &RegistryPage::page_for(pool_registry.pool_count).to_le_bytes(),
+        ],
+        bump,
+    )]
+    pub registry_page: Box<Account<'info, RegistryPage>>,
+
+    /// Current page of the species index
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + RegistryPage::INIT_SPACE,
+        seeds = [
+            REGISTRY_PAGE_SEED.as_bytes(),
+            species.key().as_ref(),
+            &RegistryPage::page_for(species.pool_count).to_le_bytes(),
+        ],
+        bump,
+    )]
+    pub species_page: Box<Account<'info, RegistryPage>>,
+
+    /// Current page of the organization index
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + RegistryPage::INIT_SPACE,
+        seeds = [
+            REGISTRY_PAGE_SEED.as_bytes(),
+            organization.key().as_ref(),
+            &RegistryPage::page_for(organization.pool_count).to_le_bytes(),
+        ],
+        bump,
+    )]
+    pub organization_page: Box<Account<'info, RegistryPage>>,
+
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + ProtocolStats::INIT_SPACE,
+        seeds = [PROTOCOL_STATS_SEED.as_bytes()],
+        bump,
+    )]
+    pub protocol_stats: Box<Account<'info, ProtocolStats>>,
+
     /// mSOL goes here
     /// Equivalent to Marinade's `mint_to`
     #[account(
         init_if_needed,
-        payer = admin,
+        payer = authority,
         associated_token::mint = msol_mint,
         associated_token::authority = pool_vault,
     )]
@@ -97,51 +201,123 @@ pub struct CreatePool<'info> {
     pub system_program: Program<'info, System>,
 }
 impl<'info> CreatePool<'info> {
-    pub fn process(
-        &mut self,
-        organization_name: String,
-        organization_pubkey: Pubkey,
-        species_name: String,
-        species_id: [u8; 32],
-        bumps: &CreatePoolBumps,
-    ) -> Result<()> {
-        // Validation
-        require!(species_id[0] != 0, ErrorCode::InvalidStringLength);
+    pub fn process(&mut self, bumps: &CreatePoolBumps) -> Result<()> {
+        let organization_pubkey = self.organization.organization_pubkey;
+        let organization_authority = self.organization.authority;
+        let organization_name = self.organization.name.clone();
+
+        let listing = PoolListing {
+            pool: self.pool.key(),
+            status: PoolListingStatus::Active,
+            category: self.species.iucn_category,
+        };
+        self.list_pool(listing, bumps)?;
+        self.fund_escrow_vault()?;
+
+        let stats = &mut self.protocol_stats;
+        stats.pool_count = stats
+            .pool_count
+            .checked_add(1)
+            .ok_or(ErrorCode::MathError)?;
+        stats.updated_at = Clock::get()?.unix_timestamp as u64;
+        stats.bump = bumps.protocol_stats;
+
+        self.organization.pool_count = self
+            .organization
+            .pool_count
+            .checked_add(1)
+            .ok_or(ErrorCode::MathError)?;
+
+        let species = &mut self.species;
+        species.pool_count = species
+            .pool_count
+            .checked_add(1)
+            .ok_or(ErrorCode::MathError)?;
+
+        let species_key = species.key();
+        let species_id = species.species_id;
+        let species_name = species.common_name.clone();
+        let scientific_name = species.scientific_name.clone();
 
         let pool = &mut self.pool;
 
         pool.organization_pubkey = organization_pubkey;
+        pool.organization_authority = organization_authority;
+        pool.pending_organization_authority = Pubkey::default();
+        pool.authority_rotation_eta = 0;
+        pool.authority_rotation_by_admin = false;
         pool.organization_name = organization_name.clone();
         pool.organization_yield_bps = 20;
+        pool.payout_asset = PayoutAsset::Sol;
         pool.species_name = species_name.clone();
-        pool.species_id = bytes_to_string(&species_id);
+        pool.scientific_name = scientific_name.clone();
         pool.new_species_id = species_id;
         pool.pool_mint = self.pool_mint.key();
         pool.vault = self.pool_vault.key();
 
         pool.is_active = true;
         pool.is_crank_scheduled = false;
+        pool.crank_task_id = 0;
         pool.total_deposits = 0;
         pool.total_shares = 0;
         pool.last_streamed_vault_sol = 0;
         pool.last_stream_ts = 0;
+        pool.stream_count = 0;
+        pool.stream_cooldown = STREAM_INTERVAL;
+        pool.unreported_yield = 0;
+        pool.org_accrual_rate = 0;
+        pool.org_accrual_last_ts = 0;
+        pool.org_accrual_end_ts = 0;
+        pool.org_unvested = 0;
+        pool.withdraw_cap = 0;
+        pool.withdraw_window = DEFAULT_WITHDRAW_WINDOW;
+        pool.withdraw_window_start = 0;
+        pool.withdrawn_in_window = 0;
+        pool.withdraw_delay = 0;
+        pool.queued_withdraw_total = 0;
+        pool.queued_msol_total = 0;
+        pool.queued_withdraw_count = 0;
+        pool.field_report_count = 0;
+        pool.reported_entry_count = 0;
+        pool.escrow_mode = false;
+        pool.escrow_balance = 0;
+        pool.escrow_committed = 0;
+        pool.milestone_count = 0;
+        pool.next_milestone = 0;
+        pool.fallback_beneficiary = Pubkey::default();
+        pool.org_standing = OrganizationStanding::Active;
+        pool.suspended_at = 0;
+        pool.reassigned_to = Pubkey::default();
+        pool.held_balance = 0;
+        pool.peak_msol_price = 0;
+        pool.peak_sol_value = 0;
+        pool.unrecovered_loss = 0;
+        pool.last_observed_sol_value = 0;
+        pool.last_observed_ts = 0;
+        pool.donated_yield_per_share = 0;
+        pool.badge_thresholds = [0; BADGE_TIERS];
 
         pool.pool_bump = bumps.pool;
         pool.org_vault_bump = bumps.organization_vault;
         pool.pool_vault_bump = bumps.pool_vault;
         pool.pool_mint_bump = bumps.pool_mint;
+        pool.escrow_vault_bump = bumps.escrow_vault;
 
-        // Convert to strings for event (events can use String)
-        let species_id_str = bytes_to_string(&species_id);
-
-        emit!(PoolCreated {
-            pool: pool.key(),
-            organization_pubkey,
-            organization_name,
-            species_name,
-            species_id: species_id_str,
-            timestamp: Clock::get()?.unix_timestamp as u64,
-        });
+        emit_cpi_event(
+            &self.event_authority,
+            bumps.event_authority,
+            PoolCreated {
+                version: EVENT_SCHEMA_VERSION,
+                pool: pool.key(),
+                organization: organization_pubkey,
+                organization_name,
+                species: species_key,
+                species_id,
+                species_name,
+                scientific_name,
+                timestamp: Clock::get()?.unix_timestamp as u64,
+            },
+        )?;
 
         msg!("Pool created: {}", pool.key());
         msg!("Pool Vault: {}", self.pool_vault.key());
@@ -149,10 +325,59 @@ impl<'info> CreatePool<'info> {
 
         Ok(())
     }
-}
 
-// Helper function to convert fixed byte array to String
-fn bytes_to_string(bytes: &[u8]) -> String {
-    let len = bytes.iter().position(|&b| b == 0).unwrap_or(bytes.len());
-    String::from_utf8_lossy(&bytes[..len]).to_string()
+    /// Tops the escrow vault up to the rent-exempt minimum, tolerating
+    /// lamports sent to it ahead of time
+    fn fund_escrow_vault(&self) -> Result<()> {
+        let rent = Rent::get()?.minimum_balance(0);
+        let top_up = rent.saturating_sub(self.escrow_vault.lamports());
+        if top_up == 0 {
+            return Ok(());
+        }
+
+        transfer(
+            CpiContext::new(
+                self.system_program.to_account_info(),
+                Transfer {
+                    from: self.authority.to_account_info(),
+                    to: self.escrow_vault.to_account_info(),
+                },
+            ),
+            top_up,
+        )
+    }
+
+    fn list_pool(&mut self, listing: PoolListing, bumps: &CreatePoolBumps) -> Result<()> {
+        // Listings must be appended before the index counters advance
+        self.registry_page.append(
+            RegistryIndex::All,
+            self.pool_registry.key(),
+            RegistryPage::page_for(self.pool_registry.pool_count),
+            bumps.registry_page,
+            listing,
+        )?;
+        self.species_page.append(
+            RegistryIndex::Species,
+            self.species.key(),
+            RegistryPage::page_for(self.species.pool_count),
+            bumps.species_page,
+            listing,
+        )?;
+        self.organization_page.append(
+            RegistryIndex::Organization,
+            self.organization.key(),
+            RegistryPage::page_for(self.organization.pool_count),
+            bumps.organization_page,
+            listing,
+        )?;
+
+        let registry = &mut self.pool_registry;
+        registry.pool_count = registry
+            .pool_count
+            .checked_add(1)
+            .ok_or(ErrorCode::MathError)?;
+        registry.updated_at = Clock::get()?.unix_timestamp as u64;
+        registry.bump = bumps.pool_registry;
+        Ok(())
+    }
 }
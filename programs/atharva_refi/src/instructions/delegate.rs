@@ -1,9 +1,14 @@
 use anchor_lang::prelude::*;
 use ephemeral_rollups_sdk::{anchor::delegate, cpi::DelegateConfig};
 
-use crate::{constants::POOL_SEED, states::Pool};
+use crate::{
+    constants::{EVENT_SCHEMA_VERSION, POOL_SEED},
+    events::PoolDelegated,
+    states::Pool,
+};
 
 #[delegate]
+#[event_cpi]
 #[derive(Accounts)]
 pub struct DelegatePool<'info> {
     #[account(mut)]
@@ -23,8 +28,9 @@ pub struct DelegatePool<'info> {
     pub pool: Account<'info, Pool>,
 }
 pub fn delegate_process(ctx: Context<DelegatePool>) -> Result<()> {
+    let validator = ctx.remaining_accounts.first().map(|acc| acc.key());
     let delegate_config = DelegateConfig {
-        validator: ctx.remaining_accounts.first().map(|acc| acc.key()),
+        validator,
         ..Default::default()
     };
 
@@ -38,6 +44,14 @@ pub fn delegate_process(ctx: Context<DelegatePool>) -> Result<()> {
     ctx.accounts
         .delegate_pool(&ctx.accounts.payer, seeds, delegate_config)?;
 
+    emit_cpi!(PoolDelegated {
+        version: EVENT_SCHEMA_VERSION,
+        pool: ctx.accounts.pool.key(),
+        validator,
+        delegated_by: ctx.accounts.payer.key(),
+        timestamp: Clock::get()?.unix_timestamp as u64,
+    });
+
     msg!("Pool account delegated!");
 
     Ok(())
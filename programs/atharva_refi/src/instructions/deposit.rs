@@ -1,7 +1,11 @@
-use crate::constants::{POOL_MINT_SEED, POOL_SEED, POOL_VAULT_SEED};
+use crate::constants::{
+    EVENT_SCHEMA_VERSION, POOL_MINT_SEED, POOL_SEED, POOL_VAULT_SEED, PROTOCOL_STATS_SEED,
+    SUPPORTER_POSITION_SEED,
+};
 use crate::errors::ErrorCode;
 use crate::events::SupporterDeposited;
-use crate::states::Pool;
+use crate::states::{Pool, ProtocolStats, SupporterPosition};
+use crate::utilities::emit_cpi_event;
 use anchor_lang::prelude::*;
 use anchor_lang::system_program::{self, Transfer};
 use anchor_spl::associated_token::AssociatedToken;
@@ -9,6 +13,7 @@ use anchor_spl::token::{mint_to, Mint, MintTo, Token, TokenAccount};
 
 /// Deposits to pool vault and mints reciept tokens to supporter
 
+#[event_cpi]
 #[derive(Accounts)]
 pub struct Deposit<'info> {
     #[account(mut)]
@@ -58,6 +63,27 @@ pub struct Deposit<'info> {
     )]
     pub supporter_pool_token_account: Account<'info, TokenAccount>,
 
+    /// Tracks the supporter's shares and lifetime donated yield
+    #[account(
+        init_if_needed,
+        payer = supporter,
+        space = 8 + SupporterPosition::INIT_SPACE,
+        seeds = [
+            SUPPORTER_POSITION_SEED.as_bytes(),
+            pool.key().as_ref(),
+            supporter.key().as_ref(),
+        ],
+        bump,
+    )]
+    pub supporter_position: Account<'info, SupporterPosition>,
+
+    #[account(
+        mut,
+        seeds = [PROTOCOL_STATS_SEED.as_bytes()],
+        bump = protocol_stats.bump,
+    )]
+    pub protocol_stats: Account<'info, ProtocolStats>,
+
     pub token_program: Program<'info, Token>,
 
     pub associated_token_program: Program<'info, AssociatedToken>,
@@ -65,13 +91,35 @@ pub struct Deposit<'info> {
     pub system_program: Program<'info, System>,
 }
 impl<'info> Deposit<'info> {
-    pub fn process(&mut self, amount: u64) -> Result<()> {
+    pub fn process(&mut self, amount: u64, bumps: &DepositBumps) -> Result<()> {
         // Validation
         require!(amount > 0, ErrorCode::InvalidAmount);
         require!(self.pool.is_active, ErrorCode::PoolNotActive);
 
+        let current_time = Clock::get()?.unix_timestamp as u64;
+
+        // Crank runs can't write the stats from the rollup
+        self.protocol_stats
+            .flush_unreported_yield(&mut self.pool, current_time)?;
+
+        let held_shares = self.supporter_pool_token_account.amount;
         let pool = &mut self.pool;
 
+        // Settle donated yield before the share count changes
+        let position = &mut self.supporter_position;
+        if position.supporter == Pubkey::default() {
+            position.pool = pool.key();
+            position.supporter = self.supporter.key();
+            position.donated_yield_checkpoint = pool.donated_yield_per_share;
+            position.bump = bumps.supporter_position;
+        }
+        position.settle(pool.donated_yield_per_share, held_shares)?;
+        let is_new_supporter = position.shares == 0;
+        position.shares = position
+            .shares
+            .checked_add(amount)
+            .ok_or(ErrorCode::MathError)?;
+
         // Update state
         pool.total_deposits = pool
             .total_deposits
@@ -82,6 +130,19 @@ impl<'info> Deposit<'info> {
             .checked_add(amount)
             .ok_or(ErrorCode::MathError)?;
 
+        let stats = &mut self.protocol_stats;
+        stats.total_value_locked = stats
+            .total_value_locked
+            .checked_add(amount)
+            .ok_or(ErrorCode::MathError)?;
+        if is_new_supporter {
+            stats.supporter_count = stats
+                .supporter_count
+                .checked_add(1)
+                .ok_or(ErrorCode::MathError)?;
+        }
+        stats.updated_at = current_time;
+
         // Transfer from supporter to pool vault
         let transfer_cpi_accounts = Transfer {
             from: self.supporter.to_account_info(),
@@ -122,11 +183,19 @@ impl<'info> Deposit<'info> {
         msg!("Minted {} receipt tokens to supporter", amount);
 
         // Emit event
-        emit!(SupporterDeposited {
-            organization_pubkey: self.pool.organization_pubkey,
-            species_name: self.pool.species_name.clone(),
-            amount,
-        });
+        emit_cpi_event(
+            &self.event_authority,
+            bumps.event_authority,
+            SupporterDeposited {
+                version: EVENT_SCHEMA_VERSION,
+                pool: self.pool.key(),
+                supporter: self.supporter.key(),
+                amount,
+                // Receipt tokens are minted 1:1 with deposited lamports
+                shares_minted: amount,
+                timestamp: Clock::get()?.unix_timestamp as u64,
+            },
+        )?;
 
         msg!("Supporter Balance: {}", self.supporter.lamports());
         msg!("Pool Vault Balance: {}", self.pool_vault.lamports());
@@ -1,21 +1,85 @@
+pub mod add_milestone;
+pub mod apply_organization;
+pub mod attest_milestone;
+pub mod cancel_stream_schedule;
+pub mod claim_impact_badge;
 pub mod create_pool;
 pub mod delegate;
 pub mod deposit;
+pub mod organization_claimable;
 pub mod organization_withdraw;
+pub mod organization_withdraw_msol;
+pub mod publish_field_report;
+pub mod queue_organization_withdrawal;
+pub mod quote_pool;
+pub mod quote_position;
+pub mod reassign_to_fallback;
+pub mod reclaim_milestone;
+pub mod record_impact_metric;
+pub mod register_organization;
+pub mod register_species;
+pub mod reinstate_organization;
+pub mod release_excess_escrow;
+pub mod release_queued_withdrawal;
+pub mod review_organization;
+pub mod rotate_organization_authority;
 pub mod schedule_stream;
+pub mod set_attester;
+pub mod set_badge_thresholds;
+pub mod set_escrow_mode;
+pub mod set_payout_asset;
+pub mod set_stream_cooldown;
+pub mod set_withdrawal_controls;
+pub mod snapshot;
 pub mod stake;
 pub mod stream;
 pub mod supporter_withdraw;
+pub mod suspend_organization;
+pub mod sweep_held_funds;
+pub mod sync_pool_listing;
 pub mod undelegate;
 pub mod unstake;
+pub mod veto_queued_withdrawal;
 
+pub use add_milestone::*;
+pub use apply_organization::*;
+pub use attest_milestone::*;
+pub use cancel_stream_schedule::*;
+pub use claim_impact_badge::*;
 pub use create_pool::*;
 pub use delegate::*;
 pub use deposit::*;
+pub use organization_claimable::*;
 pub use organization_withdraw::*;
+pub use organization_withdraw_msol::*;
+pub use publish_field_report::*;
+pub use queue_organization_withdrawal::*;
+pub use quote_pool::*;
+pub use quote_position::*;
+pub use reassign_to_fallback::*;
+pub use reclaim_milestone::*;
+pub use record_impact_metric::*;
+pub use register_organization::*;
+pub use register_species::*;
+pub use reinstate_organization::*;
+pub use release_excess_escrow::*;
+pub use release_queued_withdrawal::*;
+pub use review_organization::*;
+pub use rotate_organization_authority::*;
 pub use schedule_stream::*;
+pub use set_attester::*;
+pub use set_badge_thresholds::*;
+pub use set_escrow_mode::*;
+pub use set_payout_asset::*;
+pub use set_stream_cooldown::*;
+pub use set_withdrawal_controls::*;
+pub use snapshot::*;
 pub use stake::*;
 pub use stream::*;
 pub use supporter_withdraw::*;
+pub use suspend_organization::*;
+pub use sweep_held_funds::*;
+pub use sync_pool_listing::*;
 pub use undelegate::*;
 pub use unstake::*;
+pub use veto_queued_withdrawal::*;
@@ -0,0 +1,43 @@
+use crate::constants::{ORG_VAULT_SEED, POOL_SEED};
+use crate::states::Pool;
+use anchor_lang::prelude::*;
+
+/// Read-only view of the SOL the organization can withdraw right now,
+/// including yield accrued since the last settlement
+///
+/// Meant to be simulated; the value comes back as return data
+
+#[derive(Accounts)]
+pub struct OrganizationClaimable<'info> {
+    #[account(
+        seeds = [
+            POOL_SEED.as_bytes(),
+            pool.organization_pubkey.as_ref(),
+            &pool.new_species_id,
+        ],
+        bump = pool.pool_bump,
+    )]
+    pub pool: Account<'info, Pool>,
+
+    #[account(
+        seeds = [
+            ORG_VAULT_SEED.as_bytes(),
+            pool.organization_pubkey.as_ref(),
+            &pool.new_species_id,
+        ],
+        bump = pool.org_vault_bump,
+    )]
+    pub org_vault: SystemAccount<'info>,
+}
+impl<'info> OrganizationClaimable<'info> {
+    pub fn process(&self) -> Result<u64> {
+        let pending = self
+            .pool
+            .pending_org_accrual(Clock::get()?.unix_timestamp as u64)?;
+        let unvested = self.pool.org_unvested.saturating_sub(pending);
+
+        Ok(self
+            .pool
+            .org_withdrawable(self.org_vault.lamports(), unvested))
+    }
+}
@@ -1,9 +1,24 @@
-use crate::constants::{ORG_VAULT_SEED, POOL_SEED};
+use crate::constants::{
+    EVENT_SCHEMA_VERSION, ORG_VAULT_SEED, POOL_SEED, PROTOCOL_STATS_SEED, SPENDING_LEDGER_SEED,
+};
 use crate::errors::ErrorCode;
-use crate::states::Pool;
+use crate::events::{OrganizationWithdrew, SpendingRecorded};
+use crate::states::{OrganizationStanding, Pool, ProtocolStats, SpendingCategory, SpendingLedger};
+use crate::utilities::emit_cpi_event;
 use anchor_lang::prelude::*;
 use anchor_lang::system_program::{self, Transfer};
 
+/// Withdraws SOL the organization has accrued from its vault
+///
+/// Streamed SOL accrues linearly over each stream period. Accrual is
+/// settled here, and only the accrued part of the vault can be withdrawn.
+/// Withdrawals count against the pool's rolling cap when one is set;
+/// larger amounts go through `queue_organization_withdrawal`.
+/// Every withdrawal is tagged with a spending category and memo hash
+/// and added to the pool's `SpendingLedger`. Blocked while the
+/// organization is suspended or reassigned
+
+#[event_cpi]
 #[derive(Accounts)]
 pub struct OrganizationWithdraw<'info> {
     #[account(mut)]
@@ -17,6 +32,8 @@ pub struct OrganizationWithdraw<'info> {
             &pool.new_species_id,
         ],
         bump = pool.pool_bump,
+        constraint = pool.org_standing == OrganizationStanding::Active
+            @ ErrorCode::OrganizationNotActive,
     )]
     pub pool: Account<'info, Pool>,
 
@@ -31,21 +48,52 @@ pub struct OrganizationWithdraw<'info> {
     )]
     pub org_vault: SystemAccount<'info>,
 
+    #[account(
+        init_if_needed,
+        payer = organization,
+        space = 8 + SpendingLedger::INIT_SPACE,
+        seeds = [SPENDING_LEDGER_SEED.as_bytes(), pool.key().as_ref()],
+        bump,
+    )]
+    pub spending_ledger: Account<'info, SpendingLedger>,
+
+    #[account(
+        mut,
+        seeds = [PROTOCOL_STATS_SEED.as_bytes()],
+        bump = protocol_stats.bump,
+    )]
+    pub protocol_stats: Account<'info, ProtocolStats>,
+
     pub system_program: Program<'info, System>,
 }
 impl<'info> OrganizationWithdraw<'info> {
-    pub fn process(&self, amount: u64) -> Result<()> {
+    pub fn process(
+        &mut self,
+        amount: u64,
+        category: SpendingCategory,
+        memo_hash: [u8; 32],
+        bumps: &OrganizationWithdrawBumps,
+    ) -> Result<()> {
         // Checks
         require!(amount > 0, ErrorCode::InvalidAmount);
         require!(
-            self.pool.total_deposits >= amount,
-            ErrorCode::InsufficientWithdrawFunds,
-        );
-        require!(
-            self.pool.organization_pubkey == self.organization.key(),
+            self.pool.organization_authority == self.organization.key(),
             ErrorCode::UnauthorizedOrganization
         );
 
+        let current_time = Clock::get()?.unix_timestamp as u64;
+        self.pool.settle_org_accrual(current_time)?;
+        // Crank runs can't write the stats from the rollup
+        self.protocol_stats
+            .flush_unreported_yield(&mut self.pool, current_time)?;
+
+        let claimable = self
+            .pool
+            .org_withdrawable(self.org_vault.lamports(), self.pool.org_unvested);
+        require!(claimable >= amount, ErrorCode::InsufficientWithdrawFunds);
+
+        self.pool.record_capped_withdrawal(amount, current_time)?;
+
         let pool = &self.pool;
 
         let seeds = &[
@@ -66,6 +114,49 @@ impl<'info> OrganizationWithdraw<'info> {
             signer_seeds,
         );
 
-        system_program::transfer(cpi_ctx, amount)
+        system_program::transfer(cpi_ctx, amount)?;
+
+        let ledger = &mut self.spending_ledger;
+        if ledger.pool == Pubkey::default() {
+            ledger.pool = pool.key();
+            ledger.bump = bumps.spending_ledger;
+        }
+        let entry = ledger.record(category, amount, memo_hash, current_time)?;
+
+        let stats = &mut self.protocol_stats;
+        stats.total_org_withdrawn = stats
+            .total_org_withdrawn
+            .checked_add(amount)
+            .ok_or(ErrorCode::MathError)?;
+        stats.updated_at = current_time;
+
+        emit_cpi_event(
+            &self.event_authority,
+            bumps.event_authority,
+            OrganizationWithdrew {
+                version: EVENT_SCHEMA_VERSION,
+                pool: pool.key(),
+                organization: self.organization.key(),
+                amount,
+                timestamp: current_time,
+            },
+        )?;
+        emit_cpi_event(
+            &self.event_authority,
+            bumps.event_authority,
+            SpendingRecorded {
+                version: EVENT_SCHEMA_VERSION,
+                pool: pool.key(),
+                organization: self.organization.key(),
+                category,
+                amount,
+                memo_hash,
+                entry,
+                category_total: ledger.category_totals[category as usize],
+                timestamp: current_time,
+            },
+        )?;
+
+        Ok(())
     }
 }
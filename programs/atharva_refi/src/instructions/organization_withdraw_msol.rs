@@ -0,0 +1,194 @@
+use crate::constants::{
+    EVENT_SCHEMA_VERSION, MARINADE_STATE, MSOL_MINT, ORG_VAULT_SEED, POOL_SEED,
+    PROTOCOL_STATS_SEED, SPENDING_LEDGER_SEED,
+};
+use crate::errors::ErrorCode;
+use crate::events::{OrganizationWithdrewMsol, SpendingRecorded};
+use crate::marinade::MarinadeRates;
+use crate::states::{OrganizationStanding, Pool, ProtocolStats, SpendingCategory, SpendingLedger};
+use crate::utilities::emit_cpi_event;
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token::{transfer, Mint, Token, TokenAccount, Transfer};
+
+/// Withdraws mSOL yield from the organization vault's mSOL ATA
+/// to the organization's own mSOL account
+///
+/// Withdrawals are valued in SOL and count against the pool's rolling
+/// cap like SOL withdrawals; larger amounts go through
+/// `queue_organization_withdrawal`, where the admin may veto them.
+/// mSOL reserved by queued withdrawals can't be withdrawn here.
+/// Tagged and added to the pool's `SpendingLedger` at its SOL value,
+/// like `organization_withdraw`. Blocked while the organization is suspended or reassigned
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct OrganizationWithdrawMsol<'info> {
+    #[account(mut)]
+    pub organization: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            POOL_SEED.as_bytes(),
+            pool.organization_pubkey.as_ref(),
+            &pool.new_species_id,
+        ],
+        bump = pool.pool_bump,
+        constraint = pool.org_standing == OrganizationStanding::Active
+            @ ErrorCode::OrganizationNotActive,
+    )]
+    pub pool: Account<'info, Pool>,
+
+    #[account(
+        seeds = [
+            ORG_VAULT_SEED.as_bytes(),
+            pool.organization_pubkey.as_ref(),
+            &pool.new_species_id,
+        ],
+        bump = pool.org_vault_bump,
+    )]
+    pub org_vault: SystemAccount<'info>,
+
+    #[account(address = MSOL_MINT)]
+    pub msol_mint: Account<'info, Mint>,
+
+    #[account(
+        mut,
+        associated_token::mint = msol_mint,
+        associated_token::authority = org_vault,
+    )]
+    pub org_vault_msol_account: Account<'info, TokenAccount>,
+
+    #[account(
+        init_if_needed,
+        payer = organization,
+        associated_token::mint = msol_mint,
+        associated_token::authority = organization,
+    )]
+    pub organization_msol_account: Account<'info, TokenAccount>,
+
+    #[account(
+        init_if_needed,
+        payer = organization,
+        space = 8 + SpendingLedger::INIT_SPACE,
+        seeds = [SPENDING_LEDGER_SEED.as_bytes(), pool.key().as_ref()],
+        bump,
+    )]
+    pub spending_ledger: Account<'info, SpendingLedger>,
+
+    /// CHECK: Read-only, pinned to the Marinade state address
+    #[account(address = MARINADE_STATE)]
+    pub marinade_state: AccountInfo<'info>,
+
+    #[account(
+        mut,
+        seeds = [PROTOCOL_STATS_SEED.as_bytes()],
+        bump = protocol_stats.bump,
+    )]
+    pub protocol_stats: Account<'info, ProtocolStats>,
+
+    pub token_program: Program<'info, Token>,
+
+    pub associated_token_program: Program<'info, AssociatedToken>,
+
+    pub system_program: Program<'info, System>,
+}
+impl<'info> OrganizationWithdrawMsol<'info> {
+    pub fn process(
+        &mut self,
+        amount: u64,
+        category: SpendingCategory,
+        memo_hash: [u8; 32],
+        bumps: &OrganizationWithdrawMsolBumps,
+    ) -> Result<()> {
+        // Checks
+        require!(amount > 0, ErrorCode::InvalidAmount);
+        require!(
+            self.pool.organization_authority == self.organization.key(),
+            ErrorCode::UnauthorizedOrganization
+        );
+        require!(
+            self.pool
+                .org_msol_withdrawable(self.org_vault_msol_account.amount)
+                >= amount,
+            ErrorCode::InsufficientWithdrawFunds
+        );
+
+        let current_time = Clock::get()?.unix_timestamp as u64;
+        let sol_value = MarinadeRates::load(&self.marinade_state)?.msol_to_sol(amount)?;
+        self.pool
+            .record_capped_withdrawal(sol_value, current_time)?;
+
+        let stats = &mut self.protocol_stats;
+        stats.total_org_withdrawn = stats
+            .total_org_withdrawn
+            .checked_add(sol_value)
+            .ok_or(ErrorCode::MathError)?;
+        stats.updated_at = current_time;
+
+        // Crank runs can't write the stats from the rollup
+        self.protocol_stats
+            .flush_unreported_yield(&mut self.pool, current_time)?;
+
+        let pool = &self.pool;
+
+        let seeds = &[
+            ORG_VAULT_SEED.as_bytes(),
+            pool.organization_pubkey.as_ref(),
+            &pool.new_species_id,
+            &[pool.org_vault_bump],
+        ];
+        let signer_seeds = &[&seeds[..]];
+
+        transfer(
+            CpiContext::new_with_signer(
+                self.token_program.to_account_info(),
+                Transfer {
+                    from: self.org_vault_msol_account.to_account_info(),
+                    to: self.organization_msol_account.to_account_info(),
+                    authority: self.org_vault.to_account_info(),
+                },
+                signer_seeds,
+            ),
+            amount,
+        )?;
+
+        let ledger = &mut self.spending_ledger;
+        if ledger.pool == Pubkey::default() {
+            ledger.pool = pool.key();
+            ledger.bump = bumps.spending_ledger;
+        }
+        let entry = ledger.record(category, sol_value, memo_hash, current_time)?;
+
+        emit_cpi_event(
+            &self.event_authority,
+            bumps.event_authority,
+            OrganizationWithdrewMsol {
+                version: EVENT_SCHEMA_VERSION,
+                pool: pool.key(),
+                organization: self.organization.key(),
+                msol_amount: amount,
+                sol_value,
+                timestamp: current_time,
+            },
+        )?;
+        emit_cpi_event(
+            &self.event_authority,
+            bumps.event_authority,
+            SpendingRecorded {
+                version: EVENT_SCHEMA_VERSION,
+                pool: pool.key(),
+                organization: self.organization.key(),
+                category,
+                amount: sol_value,
+                memo_hash,
+                entry,
+                category_total: ledger.category_totals[category as usize],
+                timestamp: current_time,
+            },
+        )?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,133 @@
+use crate::constants::{EVENT_SCHEMA_VERSION, FIELD_REPORT_SEED, POOL_SEED, SPENDING_LEDGER_SEED};
+use crate::errors::ErrorCode;
+use crate::events::FieldReportPublished;
+use crate::states::{FieldReport, Pool, PublishFieldReportArgs, SpendingLedger};
+use crate::utilities::emit_cpi_event;
+use anchor_lang::prelude::*;
+
+/// Anchors an organization's field report for a pool on-chain
+///
+/// The report itself lives off-chain at `uri`; its content hash makes
+/// any later edit detectable. Reports are numbered per pool and may
+/// reference the spending ledger entries they account for. Covered
+/// entries must already be recorded and start after those covered by
+/// earlier reports
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct PublishFieldReport<'info> {
+    #[account(
+        mut,
+        address = pool.organization_authority @ ErrorCode::UnauthorizedOrganization
+    )]
+    pub organization: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            POOL_SEED.as_bytes(),
+            pool.organization_pubkey.as_ref(),
+            &pool.new_species_id,
+        ],
+        bump = pool.pool_bump,
+    )]
+    pub pool: Account<'info, Pool>,
+
+    #[account(
+        init,
+        payer = organization,
+        space = 8 + FieldReport::INIT_SPACE,
+        seeds = [
+            FIELD_REPORT_SEED.as_bytes(),
+            pool.key().as_ref(),
+            &pool.field_report_count.to_le_bytes(),
+        ],
+        bump,
+    )]
+    pub field_report: Account<'info, FieldReport>,
+
+    /// Required when the report covers ledger entries
+    #[account(
+        seeds = [SPENDING_LEDGER_SEED.as_bytes(), pool.key().as_ref()],
+        bump = spending_ledger.bump,
+    )]
+    pub spending_ledger: Option<Account<'info, SpendingLedger>>,
+
+    pub system_program: Program<'info, System>,
+}
+impl<'info> PublishFieldReport<'info> {
+    pub fn process(
+        &mut self,
+        args: PublishFieldReportArgs,
+        bumps: &PublishFieldReportBumps,
+    ) -> Result<()> {
+        // Validation
+        require!(args.content_hash != [0; 32], ErrorCode::InvalidInput);
+        require!(
+            !args.uri.is_empty() && args.uri.len() <= 200,
+            ErrorCode::InvalidStringLength
+        );
+
+        let current_time = Clock::get()?.unix_timestamp as u64;
+        require!(
+            args.period_start < args.period_end && args.period_end <= current_time,
+            ErrorCode::InvalidReportPeriod
+        );
+        if let Some(range) = args.covered_entries {
+            let entry_count = self
+                .spending_ledger
+                .as_ref()
+                .map_or(0, |ledger| ledger.entry_count);
+            require!(
+                range.first_entry <= range.last_entry
+                    && range.first_entry >= self.pool.reported_entry_count
+                    && range.last_entry < entry_count,
+                ErrorCode::InvalidCoveredEntries
+            );
+            self.pool.reported_entry_count = range.last_entry + 1;
+        }
+
+        let field_report = &mut self.field_report;
+        field_report.pool = self.pool.key();
+        field_report.publisher = self.organization.key();
+        field_report.sequence = self.pool.field_report_count;
+        field_report.content_hash = args.content_hash;
+        field_report.uri = args.uri.clone();
+        field_report.period_start = args.period_start;
+        field_report.period_end = args.period_end;
+        field_report.covered_entries = args.covered_entries;
+        field_report.published_at = current_time;
+        field_report.bump = bumps.field_report;
+
+        self.pool.field_report_count = self
+            .pool
+            .field_report_count
+            .checked_add(1)
+            .ok_or(ErrorCode::MathError)?;
+
+        emit_cpi_event(
+            &self.event_authority,
+            bumps.event_authority,
+            FieldReportPublished {
+                version: EVENT_SCHEMA_VERSION,
+                pool: self.pool.key(),
+                field_report: field_report.key(),
+                publisher: field_report.publisher,
+                sequence: field_report.sequence,
+                content_hash: args.content_hash,
+                uri: args.uri,
+                period_start: args.period_start,
+                period_end: args.period_end,
+                timestamp: current_time,
+            },
+        )?;
+
+        msg!(
+            "Field report #{} published: {}",
+            field_report.sequence,
+            field_report.key()
+        );
+
+        Ok(())
+    }
+}
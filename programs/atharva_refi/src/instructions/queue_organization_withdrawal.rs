@@ -0,0 +1,176 @@
+use crate::constants::{
+    EVENT_SCHEMA_VERSION, MARINADE_STATE, MSOL_MINT, ORG_VAULT_SEED, POOL_SEED,
+    QUEUED_WITHDRAWAL_SEED,
+};
+use crate::errors::ErrorCode;
+use crate::events::WithdrawalQueued;
+use crate::marinade::MarinadeRates;
+use crate::states::{OrganizationStanding, PayoutAsset, Pool, QueuedWithdrawal, SpendingCategory};
+use crate::utilities::emit_cpi_event;
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::get_associated_token_address;
+use anchor_spl::token::TokenAccount;
+
+/// Queues an organization withdrawal for release after the pool's
+/// `withdraw_delay`
+///
+/// Queued amounts bypass the rolling cap, but are reserved in the
+/// organization vault immediately and the admin may veto them until
+/// release. Queueing and release both require an active organization
+///
+/// mSOL withdrawals are queued the same way against the organization
+/// vault's mSOL ATA, valued in SOL at queue time for the spending ledger
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct QueueOrganizationWithdrawal<'info> {
+    #[account(
+        mut,
+        address = pool.organization_authority @ ErrorCode::UnauthorizedOrganization
+    )]
+    pub organization: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            POOL_SEED.as_bytes(),
+            pool.organization_pubkey.as_ref(),
+            &pool.new_species_id,
+        ],
+        bump = pool.pool_bump,
+        constraint = pool.org_standing == OrganizationStanding::Active
+            @ ErrorCode::OrganizationNotActive,
+    )]
+    pub pool: Account<'info, Pool>,
+
+    #[account(
+        seeds = [
+            ORG_VAULT_SEED.as_bytes(),
+            pool.organization_pubkey.as_ref(),
+            &pool.new_species_id,
+        ],
+        bump = pool.org_vault_bump,
+    )]
+    pub org_vault: SystemAccount<'info>,
+
+    #[account(
+        init,
+        payer = organization,
+        space = 8 + QueuedWithdrawal::INIT_SPACE,
+        seeds = [
+            QUEUED_WITHDRAWAL_SEED.as_bytes(),
+            pool.key().as_ref(),
+            &pool.queued_withdraw_count.to_le_bytes(),
+        ],
+        bump,
+    )]
+    pub queued_withdrawal: Account<'info, QueuedWithdrawal>,
+
+    /// Organization vault's mSOL ATA, required when queueing mSOL
+    #[account(
+        address = get_associated_token_address(&org_vault.key(), &MSOL_MINT)
+            @ ErrorCode::InvalidMsolAccount,
+    )]
+    pub org_vault_msol_account: Option<Account<'info, TokenAccount>>,
+
+    /// CHECK: Read-only, pinned to the Marinade state address
+    #[account(address = MARINADE_STATE)]
+    pub marinade_state: AccountInfo<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+impl<'info> QueueOrganizationWithdrawal<'info> {
+    pub fn process(
+        &mut self,
+        amount: u64,
+        asset: PayoutAsset,
+        category: SpendingCategory,
+        memo_hash: [u8; 32],
+        bumps: &QueueOrganizationWithdrawalBumps,
+    ) -> Result<()> {
+        // Checks
+        require!(amount > 0, ErrorCode::InvalidAmount);
+        require!(
+            self.pool.withdraw_delay > 0,
+            ErrorCode::WithdrawalQueueDisabled
+        );
+
+        let current_time = Clock::get()?.unix_timestamp as u64;
+        self.pool.settle_org_accrual(current_time)?;
+
+        let sol_value = match asset {
+            PayoutAsset::Sol => {
+                let claimable = self
+                    .pool
+                    .org_withdrawable(self.org_vault.lamports(), self.pool.org_unvested);
+                require!(claimable >= amount, ErrorCode::InsufficientWithdrawFunds);
+                amount
+            }
+            PayoutAsset::Msol => {
+                let org_vault_msol_account = self
+                    .org_vault_msol_account
+                    .as_ref()
+                    .ok_or(ErrorCode::MissingOrganizationMsolAccount)?;
+                let claimable = self
+                    .pool
+                    .org_msol_withdrawable(org_vault_msol_account.amount);
+                require!(claimable >= amount, ErrorCode::InsufficientWithdrawFunds);
+                MarinadeRates::load(&self.marinade_state)?.msol_to_sol(amount)?
+            }
+        };
+
+        let release_ts = current_time
+            .checked_add(self.pool.withdraw_delay)
+            .ok_or(ErrorCode::MathError)?;
+
+        let queued_withdrawal = &mut self.queued_withdrawal;
+        queued_withdrawal.pool = self.pool.key();
+        queued_withdrawal.recipient = self.organization.key();
+        queued_withdrawal.sequence = self.pool.queued_withdraw_count;
+        queued_withdrawal.amount = amount;
+        queued_withdrawal.asset = asset;
+        queued_withdrawal.sol_value = sol_value;
+        queued_withdrawal.category = category;
+        queued_withdrawal.memo_hash = memo_hash;
+        queued_withdrawal.queued_at = current_time;
+        queued_withdrawal.release_ts = release_ts;
+        queued_withdrawal.bump = bumps.queued_withdrawal;
+
+        let pool = &mut self.pool;
+        match asset {
+            PayoutAsset::Sol => {
+                pool.queued_withdraw_total = pool
+                    .queued_withdraw_total
+                    .checked_add(amount)
+                    .ok_or(ErrorCode::MathError)?;
+            }
+            PayoutAsset::Msol => {
+                pool.queued_msol_total = pool
+                    .queued_msol_total
+                    .checked_add(amount)
+                    .ok_or(ErrorCode::MathError)?;
+            }
+        }
+        pool.queued_withdraw_count = pool
+            .queued_withdraw_count
+            .checked_add(1)
+            .ok_or(ErrorCode::MathError)?;
+
+        emit_cpi_event(
+            &self.event_authority,
+            bumps.event_authority,
+            WithdrawalQueued {
+                version: EVENT_SCHEMA_VERSION,
+                pool: pool.key(),
+                queued_withdrawal: queued_withdrawal.key(),
+                recipient: queued_withdrawal.recipient,
+                amount,
+                asset,
+                release_ts,
+                timestamp: current_time,
+            },
+        )?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,159 @@
+use crate::constants::{
+    LIQ_POOL_SOL_LEG, MARINADE_STATE, MIN_YIELD_AMOUNT, MSOL_MINT, MSOL_PRICE_PRECISION,
+    POOL_MINT_SEED, POOL_SEED, POOL_VAULT_SEED,
+};
+use crate::errors::ErrorCode;
+use crate::marinade::MarinadeRates;
+use crate::states::{DepositQuote, PendingOrgYield, Pool, PoolNav, WithdrawQuote};
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::get_associated_token_address;
+use anchor_spl::token::{Mint, TokenAccount};
+
+/// Read-only pool views: deposit and withdrawal quotes, NAV and the
+/// organization yield the next stream would pay
+///
+/// Meant to be simulated; results come back as Borsh-encoded return
+/// data, computed with the same math as `deposit`, `supporter_withdraw`
+/// and `stream`
+
+#[derive(Accounts)]
+pub struct QuotePool<'info> {
+    #[account(
+        seeds = [
+            POOL_SEED.as_bytes(),
+            pool.organization_pubkey.as_ref(),
+            &pool.new_species_id
+        ],
+        bump = pool.pool_bump,
+    )]
+    pub pool: Account<'info, Pool>,
+
+    #[account(
+        seeds = [
+            POOL_MINT_SEED.as_bytes(),
+            pool.organization_pubkey.as_ref(),
+            &pool.new_species_id
+        ],
+        bump = pool.pool_mint_bump,
+    )]
+    pub pool_mint: Account<'info, Mint>,
+
+    #[account(
+        seeds = [
+            POOL_VAULT_SEED.as_bytes(),
+            pool.organization_pubkey.as_ref(),
+            &pool.new_species_id
+        ],
+        bump = pool.pool_vault_bump,
+    )]
+    pub pool_vault: SystemAccount<'info>,
+
+    #[account(
+        address = get_associated_token_address(&pool.vault, &MSOL_MINT)
+            @ ErrorCode::InvalidTokenAccount,
+    )]
+    pub pool_msol_account: Account<'info, TokenAccount>,
+
+    /// CHECK: Read-only, pinned to the Marinade state address
+    #[account(address = MARINADE_STATE)]
+    pub marinade_state: AccountInfo<'info>,
+
+    /// Marinade liquidity pool SOL leg, sets the liquid-unstake fee
+    #[account(address = LIQ_POOL_SOL_LEG)]
+    pub liq_pool_sol_leg: SystemAccount<'info>,
+}
+impl<'info> QuotePool<'info> {
+    pub fn quote_deposit(&self, amount: u64) -> Result<DepositQuote> {
+        require!(amount > 0, ErrorCode::InvalidAmount);
+        require!(self.pool.is_active, ErrorCode::PoolNotActive);
+
+        // Receipt tokens are minted 1:1 with deposited lamports
+        let total_shares_after = self
+            .pool
+            .total_shares
+            .checked_add(amount)
+            .ok_or(ErrorCode::MathError)?;
+        let ownership_bps = (amount as u128)
+            .checked_mul(10_000)
+            .ok_or(ErrorCode::MathError)?
+            .checked_div(total_shares_after as u128)
+            .ok_or(ErrorCode::MathError)?;
+
+        Ok(DepositQuote {
+            shares: amount,
+            total_shares_after,
+            ownership_bps: u64::try_from(ownership_bps).map_err(|_| ErrorCode::MathError)?,
+        })
+    }
+
+    pub fn quote_withdraw(&self, share_amount: u64) -> Result<WithdrawQuote> {
+        require!(share_amount > 0, ErrorCode::InvalidAmount);
+        require!(self.pool.is_active, ErrorCode::PoolNotActive);
+
+        let msol_amount = Pool::msol_for_shares(
+            share_amount,
+            self.pool_mint.supply,
+            self.pool_msol_account.amount,
+        )?;
+        let rates = MarinadeRates::load(&self.marinade_state)?;
+        let unstake = rates.liquid_unstake(msol_amount, self.liq_pool_sol_leg.lamports())?;
+
+        Ok(WithdrawQuote {
+            msol_amount,
+            sol_value: rates.msol_to_sol(msol_amount)?,
+            fee_bps: unstake.fee_bps,
+            fee_msol: unstake.msol_fee,
+            sol_amount: unstake.sol_out,
+        })
+    }
+
+    pub fn pool_nav(&self) -> Result<PoolNav> {
+        let rates = MarinadeRates::load(&self.marinade_state)?;
+        let msol_balance = self.pool_msol_account.amount;
+        let msol_price = rates.msol_price()?;
+        let nav = rates.msol_to_sol(msol_balance)?;
+        let total_shares = self.pool_mint.supply;
+
+        let nav_per_share = if total_shares == 0 {
+            0
+        } else {
+            (nav as u128)
+                .checked_mul(MSOL_PRICE_PRECISION)
+                .ok_or(ErrorCode::MathError)?
+                .checked_div(total_shares as u128)
+                .ok_or(ErrorCode::MathError)?
+        };
+
+        Ok(PoolNav {
+            msol_balance,
+            msol_price,
+            nav,
+            unstaked_lamports: self.pool_vault.lamports(),
+            total_shares,
+            nav_per_share,
+        })
+    }
+
+    pub fn pending_org_yield(&self) -> Result<PendingOrgYield> {
+        let rates = MarinadeRates::load(&self.marinade_state)?;
+        let pool = &self.pool;
+
+        let nav = rates.msol_to_sol(self.pool_msol_account.amount)?;
+        let total_yield = nav.saturating_sub(pool.last_streamed_vault_sol);
+        let org_yield = pool.org_yield_share(total_yield)?;
+
+        // Mirrors the skip conditions in `stream`
+        let at_high_water_mark = rates.msol_price()? >= pool.peak_msol_price;
+        let streamable = at_high_water_mark && total_yield > MIN_YIELD_AMOUNT;
+
+        Ok(PendingOrgYield {
+            total_yield,
+            org_yield,
+            streamable,
+            next_public_stream_ts: pool
+                .last_stream_ts
+                .checked_add(pool.stream_cooldown)
+                .ok_or(ErrorCode::MathError)?,
+        })
+    }
+}
@@ -0,0 +1,89 @@
+use crate::constants::{
+    MARINADE_STATE, MSOL_MINT, POOL_MINT_SEED, POOL_SEED, SUPPORTER_POSITION_SEED,
+};
+use crate::errors::ErrorCode;
+use crate::marinade::MarinadeRates;
+use crate::states::{Pool, PositionValue, SupporterPosition};
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::get_associated_token_address;
+use anchor_spl::token::{Mint, TokenAccount};
+
+/// Read-only view of a supporter position's current value and
+/// lifetime donated yield
+///
+/// Meant to be simulated; the result comes back as return data
+
+#[derive(Accounts)]
+pub struct QuotePosition<'info> {
+    #[account(
+        seeds = [
+            POOL_SEED.as_bytes(),
+            pool.organization_pubkey.as_ref(),
+            &pool.new_species_id
+        ],
+        bump = pool.pool_bump,
+    )]
+    pub pool: Account<'info, Pool>,
+
+    #[account(
+        seeds = [
+            POOL_MINT_SEED.as_bytes(),
+            pool.organization_pubkey.as_ref(),
+            &pool.new_species_id
+        ],
+        bump = pool.pool_mint_bump,
+    )]
+    pub pool_mint: Account<'info, Mint>,
+
+    #[account(
+        address = get_associated_token_address(&supporter_position.supporter, &pool_mint.key())
+            @ ErrorCode::InvalidTokenAccount,
+    )]
+    pub supporter_pool_token_account: Account<'info, TokenAccount>,
+
+    #[account(
+        seeds = [
+            SUPPORTER_POSITION_SEED.as_bytes(),
+            pool.key().as_ref(),
+            supporter_position.supporter.as_ref(),
+        ],
+        bump = supporter_position.bump,
+    )]
+    pub supporter_position: Account<'info, SupporterPosition>,
+
+    #[account(
+        address = get_associated_token_address(&pool.vault, &MSOL_MINT)
+            @ ErrorCode::InvalidTokenAccount,
+    )]
+    pub pool_msol_account: Account<'info, TokenAccount>,
+
+    /// CHECK: Read-only, pinned to the Marinade state address
+    #[account(address = MARINADE_STATE)]
+    pub marinade_state: AccountInfo<'info>,
+}
+impl<'info> QuotePosition<'info> {
+    pub fn process(&self) -> Result<PositionValue> {
+        let shares = self.supporter_position.shares;
+
+        let msol_amount = if shares == 0 {
+            0
+        } else {
+            Pool::msol_for_shares(shares, self.pool_mint.supply, self.pool_msol_account.amount)?
+        };
+        let sol_value = MarinadeRates::load(&self.marinade_state)?.msol_to_sol(msol_amount)?;
+
+        // Settle a copy so unsettled donations are included
+        let mut position = (*self.supporter_position).clone();
+        position.settle(
+            self.pool.donated_yield_per_share,
+            self.supporter_pool_token_account.amount,
+        )?;
+
+        Ok(PositionValue {
+            shares,
+            msol_amount,
+            sol_value,
+            donated_yield: position.donated_yield,
+        })
+    }
+}
@@ -0,0 +1,216 @@
+use crate::constants::{
+    ADMIN_PUBKEY, ESCROW_VAULT_SEED, EVENT_SCHEMA_VERSION, ORGANIZATION_SEED, ORG_VAULT_SEED,
+    POOL_SEED, REGISTRY_PAGE_SEED, SUSPENSION_REVIEW_WINDOW,
+};
+use crate::errors::ErrorCode;
+use crate::events::PoolReassignedToFallback;
+use crate::states::{
+    Organization, OrganizationStanding, Pool, RegistryIndex, RegistryPage, VerificationStatus,
+};
+use crate::utilities::emit_cpi_event;
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{transfer, Transfer};
+
+/// Reassigns a suspended pool's held and future streams to a vetted
+/// fallback beneficiary once the review window has passed
+///
+/// The fallback must be a verified organization in the registry.
+/// Yield the organization had accrued stays in its vault, but the
+/// unvested remainder moves to the escrow vault as held funds. Held
+/// funds are paid out through `sweep_held_funds`. The pool's milestone
+/// `fallback_beneficiary` is left alone, and the pool's registry
+/// listings are marked reassigned
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ReassignToFallback<'info> {
+    #[account(
+        address = ADMIN_PUBKEY @ ErrorCode::AdminUnauthorized
+    )]
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            POOL_SEED.as_bytes(),
+            pool.organization_pubkey.as_ref(),
+            &pool.new_species_id
+        ],
+        bump = pool.pool_bump,
+        constraint = pool.org_standing == OrganizationStanding::Suspended
+            @ ErrorCode::OrganizationNotSuspended,
+    )]
+    pub pool: Account<'info, Pool>,
+
+    /// Global index page listing the pool
+    #[account(
+        mut,
+        seeds = [
+            REGISTRY_PAGE_SEED.as_bytes(),
+            registry_page.index_key.as_ref(),
+            &registry_page.page.to_le_bytes(),
+        ],
+        bump = registry_page.bump,
+        constraint = registry_page.index == RegistryIndex::All @ ErrorCode::PoolNotListed,
+    )]
+    pub registry_page: Box<Account<'info, RegistryPage>>,
+
+    /// Species index page listing the pool
+    #[account(
+        mut,
+        seeds = [
+            REGISTRY_PAGE_SEED.as_bytes(),
+            species_page.index_key.as_ref(),
+            &species_page.page.to_le_bytes(),
+        ],
+        bump = species_page.bump,
+        constraint = species_page.index == RegistryIndex::Species @ ErrorCode::PoolNotListed,
+    )]
+    pub species_page: Box<Account<'info, RegistryPage>>,
+
+    /// Organization index page listing the pool
+    #[account(
+        mut,
+        seeds = [
+            REGISTRY_PAGE_SEED.as_bytes(),
+            organization_page.index_key.as_ref(),
+            &organization_page.page.to_le_bytes(),
+        ],
+        bump = organization_page.bump,
+        constraint = organization_page.index == RegistryIndex::Organization
+            @ ErrorCode::PoolNotListed,
+    )]
+    pub organization_page: Box<Account<'info, RegistryPage>>,
+
+    #[account(
+        seeds = [
+            ORGANIZATION_SEED.as_bytes(),
+            fallback_organization.organization_pubkey.as_ref(),
+        ],
+        bump = fallback_organization.bump,
+        constraint = fallback_organization.status == VerificationStatus::Verified
+            @ ErrorCode::OrganizationNotVerified,
+        constraint = fallback_organization.organization_pubkey != pool.organization_pubkey
+            @ ErrorCode::InvalidInput,
+    )]
+    pub fallback_organization: Account<'info, Organization>,
+
+    #[account(
+        mut,
+        seeds = [
+            ORG_VAULT_SEED.as_bytes(),
+            pool.organization_pubkey.as_ref(),
+            &pool.new_species_id
+        ],
+        bump = pool.org_vault_bump,
+    )]
+    pub organization_vault: SystemAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            ESCROW_VAULT_SEED.as_bytes(),
+            pool.organization_pubkey.as_ref(),
+            &pool.new_species_id
+        ],
+        bump = pool.escrow_vault_bump,
+    )]
+    pub escrow_vault: SystemAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+impl<'info> ReassignToFallback<'info> {
+    pub fn process(&mut self, bumps: &ReassignToFallbackBumps) -> Result<()> {
+        let current_time = Clock::get()?.unix_timestamp as u64;
+
+        let review_ends_at = self
+            .pool
+            .suspended_at
+            .checked_add(SUSPENSION_REVIEW_WINDOW)
+            .ok_or(ErrorCode::MathError)?;
+        require!(
+            current_time >= review_ends_at,
+            ErrorCode::ReviewWindowNotElapsed
+        );
+
+        let unvested_held = self.hold_unvested(current_time)?;
+
+        self.pool.org_standing = OrganizationStanding::Reassigned;
+        self.pool.reassigned_to = self.fallback_organization.organization_pubkey;
+        self.update_listings()?;
+
+        let pool = &self.pool;
+
+        emit_cpi_event(
+            &self.event_authority,
+            bumps.event_authority,
+            PoolReassignedToFallback {
+                version: EVENT_SCHEMA_VERSION,
+                pool: pool.key(),
+                organization: pool.organization_pubkey,
+                fallback_beneficiary: pool.reassigned_to,
+                unvested_held,
+                timestamp: current_time,
+            },
+        )?;
+
+        msg!("Pool {} reassigned to {}", pool.key(), pool.reassigned_to);
+
+        Ok(())
+    }
+
+    /// Brings the pool's listing on every index up to its new standing
+    fn update_listings(&mut self) -> Result<()> {
+        let pool_key = self.pool.key();
+        let status = self.pool.listing_status();
+
+        self.registry_page.set_status(pool_key, status)?;
+        self.species_page.set_status(pool_key, status)?;
+        self.organization_page.set_status(pool_key, status)?;
+
+        Ok(())
+    }
+
+    /// Settles the accrual up to `now`, then moves what is still unvested
+    /// from the organization vault to the escrow vault's held balance
+    fn hold_unvested(&mut self, now: u64) -> Result<u64> {
+        self.pool.settle_org_accrual(now)?;
+
+        let amount = self.pool.org_unvested;
+        if amount == 0 {
+            return Ok(0);
+        }
+
+        let pool = &self.pool;
+        let seeds = &[
+            ORG_VAULT_SEED.as_bytes(),
+            pool.organization_pubkey.as_ref(),
+            &pool.new_species_id,
+            &[pool.org_vault_bump],
+        ];
+        let signer_seeds = &[&seeds[..]];
+
+        transfer(
+            CpiContext::new_with_signer(
+                self.system_program.to_account_info(),
+                Transfer {
+                    from: self.organization_vault.to_account_info(),
+                    to: self.escrow_vault.to_account_info(),
+                },
+                signer_seeds,
+            ),
+            amount,
+        )?;
+
+        let pool = &mut self.pool;
+        pool.org_unvested = 0;
+        pool.org_accrual_rate = 0;
+        pool.org_accrual_end_ts = now;
+        pool.held_balance = pool
+            .held_balance
+            .checked_add(amount)
+            .ok_or(ErrorCode::MathError)?;
+
+        Ok(amount)
+    }
+}
@@ -0,0 +1,133 @@
+use crate::constants::{ESCROW_VAULT_SEED, EVENT_SCHEMA_VERSION, MILESTONE_SEED, POOL_SEED};
+use crate::errors::ErrorCode;
+use crate::events::MilestoneReclaimed;
+use crate::states::{Milestone, MilestoneStatus, Pool};
+use crate::utilities::emit_cpi_event;
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{transfer, Transfer};
+
+/// Sends the tranche of a milestone that missed its deadline to the
+/// pool's fallback beneficiary. Permissionless once the deadline passes
+/// and every earlier milestone is resolved
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ReclaimMilestone<'info> {
+    #[account(
+        mut,
+        seeds = [
+            POOL_SEED.as_bytes(),
+            pool.organization_pubkey.as_ref(),
+            &pool.new_species_id
+        ],
+        bump = pool.pool_bump,
+    )]
+    pub pool: Account<'info, Pool>,
+
+    #[account(
+        mut,
+        seeds = [
+            MILESTONE_SEED.as_bytes(),
+            pool.key().as_ref(),
+            &[milestone.index],
+        ],
+        bump = milestone.bump,
+        has_one = pool,
+        constraint = milestone.index == pool.next_milestone
+            @ ErrorCode::MilestoneOutOfOrder,
+    )]
+    pub milestone: Account<'info, Milestone>,
+
+    #[account(
+        mut,
+        seeds = [
+            ESCROW_VAULT_SEED.as_bytes(),
+            pool.organization_pubkey.as_ref(),
+            &pool.new_species_id
+        ],
+        bump = pool.escrow_vault_bump,
+    )]
+    pub escrow_vault: SystemAccount<'info>,
+
+    #[account(
+        mut,
+        address = pool.fallback_beneficiary @ ErrorCode::FallbackBeneficiaryNotSet
+    )]
+    pub fallback_beneficiary: SystemAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+impl<'info> ReclaimMilestone<'info> {
+    pub fn process(&mut self, bumps: &ReclaimMilestoneBumps) -> Result<()> {
+        let current_time = Clock::get()?.unix_timestamp as u64;
+
+        // Validation
+        require!(
+            self.milestone.status == MilestoneStatus::Pending,
+            ErrorCode::MilestoneNotPending
+        );
+        require!(
+            current_time > self.milestone.deadline,
+            ErrorCode::MilestoneDeadlineNotReached
+        );
+
+        // Earlier milestones are resolved, so all escrow backs this one first;
+        // reclaim what is there even if the tranche was never fully funded
+        let amount = self.milestone.amount.min(self.pool.escrow_balance);
+
+        if amount > 0 {
+            let pool = &self.pool;
+            let seeds = &[
+                ESCROW_VAULT_SEED.as_bytes(),
+                pool.organization_pubkey.as_ref(),
+                &pool.new_species_id,
+                &[pool.escrow_vault_bump],
+            ];
+            let signer_seeds = &[&seeds[..]];
+
+            transfer(
+                CpiContext::new_with_signer(
+                    self.system_program.to_account_info(),
+                    Transfer {
+                        from: self.escrow_vault.to_account_info(),
+                        to: self.fallback_beneficiary.to_account_info(),
+                    },
+                    signer_seeds,
+                ),
+                amount,
+            )?;
+
+            self.pool.escrow_balance -= amount;
+        }
+
+        self.pool.escrow_committed = self
+            .pool
+            .escrow_committed
+            .saturating_sub(self.milestone.amount);
+        self.pool.next_milestone = self
+            .pool
+            .next_milestone
+            .checked_add(1)
+            .ok_or(ErrorCode::MathError)?;
+
+        let milestone = &mut self.milestone;
+        milestone.status = MilestoneStatus::Reclaimed;
+        milestone.resolved_at = current_time;
+
+        emit_cpi_event(
+            &self.event_authority,
+            bumps.event_authority,
+            MilestoneReclaimed {
+                version: EVENT_SCHEMA_VERSION,
+                pool: self.pool.key(),
+                milestone: milestone.key(),
+                index: milestone.index,
+                amount,
+                fallback_beneficiary: self.fallback_beneficiary.key(),
+                timestamp: current_time,
+            },
+        )?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,95 @@
+use crate::constants::{ATTESTER_SEED, EVENT_SCHEMA_VERSION, IMPACT_METRICS_SEED, POOL_SEED};
+use crate::errors::ErrorCode;
+use crate::events::ImpactMetricRecorded;
+use crate::states::{Attester, ImpactMetrics, MetricEntry, MetricKind, Pool};
+use crate::utilities::emit_cpi_event;
+use anchor_lang::prelude::*;
+
+/// Records an attested impact metric (population, habitat, patrols)
+/// for a pool's species
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct RecordImpactMetric<'info> {
+    #[account(mut)]
+    pub attester: Signer<'info>,
+
+    #[account(
+        seeds = [ATTESTER_SEED.as_bytes(), attester.key().as_ref()],
+        bump = attester_account.bump,
+        constraint = attester_account.is_active @ ErrorCode::AttesterNotActive,
+    )]
+    pub attester_account: Account<'info, Attester>,
+
+    #[account(
+        seeds = [
+            POOL_SEED.as_bytes(),
+            pool.organization_pubkey.as_ref(),
+            &pool.new_species_id,
+        ],
+        bump = pool.pool_bump,
+    )]
+    pub pool: Account<'info, Pool>,
+
+    #[account(
+        init_if_needed,
+        payer = attester,
+        space = 8 + ImpactMetrics::INIT_SPACE,
+        seeds = [IMPACT_METRICS_SEED.as_bytes(), pool.key().as_ref()],
+        bump,
+    )]
+    pub impact_metrics: Box<Account<'info, ImpactMetrics>>,
+
+    pub system_program: Program<'info, System>,
+}
+impl<'info> RecordImpactMetric<'info> {
+    pub fn process(
+        &mut self,
+        kind: MetricKind,
+        value: u64,
+        survey_date: u64,
+        source_hash: [u8; 32],
+        bumps: &RecordImpactMetricBumps,
+    ) -> Result<()> {
+        let current_time = Clock::get()?.unix_timestamp as u64;
+
+        // Validation
+        require!(
+            survey_date > 0 && survey_date <= current_time,
+            ErrorCode::InvalidSurveyDate
+        );
+        require!(source_hash != [0; 32], ErrorCode::InvalidInput);
+
+        let impact_metrics = &mut self.impact_metrics;
+        if impact_metrics.pool == Pubkey::default() {
+            impact_metrics.pool = self.pool.key();
+            impact_metrics.bump = bumps.impact_metrics;
+        }
+
+        impact_metrics.record(MetricEntry {
+            kind,
+            value,
+            survey_date,
+            source_hash,
+            attester: self.attester.key(),
+            recorded_at: current_time,
+        })?;
+
+        emit_cpi_event(
+            &self.event_authority,
+            bumps.event_authority,
+            ImpactMetricRecorded {
+                version: EVENT_SCHEMA_VERSION,
+                pool: self.pool.key(),
+                attester: self.attester.key(),
+                kind,
+                value,
+                survey_date,
+                source_hash,
+                timestamp: current_time,
+            },
+        )?;
+
+        Ok(())
+    }
+}
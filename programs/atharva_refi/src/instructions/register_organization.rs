@@ -0,0 +1,86 @@
+use crate::constants::{ADMIN_PUBKEY, EVENT_SCHEMA_VERSION, ORGANIZATION_SEED};
+use crate::errors::ErrorCode;
+use crate::events::OrganizationRegistered;
+use crate::states::{Organization, VerificationStatus};
+use crate::utilities::emit_cpi_event;
+use anchor_lang::prelude::*;
+
+/// Registers and verifies a conservation organization
+///
+/// Pools can only be created for organizations verified here, and take
+/// their organization name from this account. No species are approved
+/// for a directly registered organization, so the admin creates its pools
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(organization_pubkey: Pubkey)]
+pub struct RegisterOrganization<'info> {
+    #[account(
+        mut,
+        address = ADMIN_PUBKEY @ ErrorCode::AdminUnauthorized
+    )]
+    pub admin: Signer<'info>,
+
+    #[account(
+        init,
+        payer = admin,
+        space = 8 + Organization::INIT_SPACE,
+        seeds = [ORGANIZATION_SEED.as_bytes(), organization_pubkey.as_ref()],
+        bump,
+    )]
+    pub organization: Account<'info, Organization>,
+
+    pub system_program: Program<'info, System>,
+}
+impl<'info> RegisterOrganization<'info> {
+    pub fn process(
+        &mut self,
+        organization_pubkey: Pubkey,
+        name: String,
+        metadata_uri: String,
+        bumps: &RegisterOrganizationBumps,
+    ) -> Result<()> {
+        // Validation
+        require!(
+            !name.is_empty() && name.len() <= 50,
+            ErrorCode::InvalidStringLength
+        );
+        require!(metadata_uri.len() <= 200, ErrorCode::InvalidStringLength);
+
+        let current_time = Clock::get()?.unix_timestamp as u64;
+        let organization = &mut self.organization;
+
+        organization.organization_pubkey = organization_pubkey;
+        organization.authority = organization_pubkey;
+        organization.name = name.clone();
+        organization.metadata_uri = metadata_uri.clone();
+        organization.document_hash = [0; 32];
+        organization.species_ids = Vec::new();
+        organization.status = VerificationStatus::Verified;
+        organization.verified_at = current_time;
+        organization.verified_by = self.admin.key();
+        organization.rejection_reason = 0;
+        organization.reviewed_at = current_time;
+        organization.pool_count = 0;
+        organization.created_at = current_time;
+        organization.bump = bumps.organization;
+
+        emit_cpi_event(
+            &self.event_authority,
+            bumps.event_authority,
+            OrganizationRegistered {
+                version: EVENT_SCHEMA_VERSION,
+                organization: organization.key(),
+                organization_pubkey,
+                name,
+                metadata_uri,
+                verified_by: self.admin.key(),
+                timestamp: current_time,
+            },
+        )?;
+
+        msg!("Organization registered: {}", organization.key());
+
+        Ok(())
+    }
+}
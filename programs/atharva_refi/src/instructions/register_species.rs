@@ -0,0 +1,97 @@
+use crate::constants::{ADMIN_PUBKEY, EVENT_SCHEMA_VERSION, SPECIES_SEED};
+use crate::errors::ErrorCode;
+use crate::events::SpeciesRegistered;
+use crate::states::{IucnCategory, Species};
+use crate::utilities::emit_cpi_event;
+use anchor_lang::prelude::*;
+
+/// Registers a species in the canonical species registry
+///
+/// The species ID is the SHA-256 hash of the normalized, lowercased
+/// scientific name (see `Species::id_for`), so the same species always maps
+/// to the same PDA and cannot be registered twice under a variant spelling
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(scientific_name: String)]
+pub struct RegisterSpecies<'info> {
+    #[account(
+        mut,
+        address = ADMIN_PUBKEY @ ErrorCode::AdminUnauthorized
+    )]
+    pub admin: Signer<'info>,
+
+    #[account(
+        init,
+        payer = admin,
+        space = 8 + Species::INIT_SPACE,
+        seeds = [
+            SPECIES_SEED.as_bytes(),
+            &Species::id_for(&scientific_name),
+        ],
+        bump,
+    )]
+    pub species: Account<'info, Species>,
+
+    pub system_program: Program<'info, System>,
+}
+impl<'info> RegisterSpecies<'info> {
+    pub fn process(
+        &mut self,
+        scientific_name: String,
+        common_name: String,
+        iucn_category: IucnCategory,
+        region: String,
+        metadata_uri: String,
+        bumps: &RegisterSpeciesBumps,
+    ) -> Result<()> {
+        let scientific_name = Species::normalize_name(&scientific_name);
+
+        // Validation
+        require!(
+            !scientific_name.is_empty() && scientific_name.len() <= 50,
+            ErrorCode::InvalidStringLength
+        );
+        require!(
+            !common_name.is_empty() && common_name.len() <= 50,
+            ErrorCode::InvalidStringLength
+        );
+        require!(region.len() <= 50, ErrorCode::InvalidStringLength);
+        require!(metadata_uri.len() <= 200, ErrorCode::InvalidStringLength);
+
+        let species_id = Species::id_for(&scientific_name);
+        let current_time = Clock::get()?.unix_timestamp as u64;
+        let species = &mut self.species;
+
+        species.species_id = species_id;
+        species.scientific_name = scientific_name.clone();
+        species.common_name = common_name.clone();
+        species.iucn_category = iucn_category;
+        species.region = region.clone();
+        species.metadata_uri = metadata_uri;
+        species.pool_count = 0;
+        species.registered_by = self.admin.key();
+        species.created_at = current_time;
+        species.bump = bumps.species;
+
+        emit_cpi_event(
+            &self.event_authority,
+            bumps.event_authority,
+            SpeciesRegistered {
+                version: EVENT_SCHEMA_VERSION,
+                species: species.key(),
+                species_id,
+                scientific_name,
+                common_name,
+                iucn_category,
+                region,
+                registered_by: self.admin.key(),
+                timestamp: current_time,
+            },
+        )?;
+
+        msg!("Species registered: {}", species.key());
+
+        Ok(())
+    }
+}
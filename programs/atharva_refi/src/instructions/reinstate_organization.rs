@@ -0,0 +1,175 @@
+use crate::constants::{
+    ADMIN_PUBKEY, ESCROW_VAULT_SEED, EVENT_SCHEMA_VERSION, ORG_VAULT_SEED, POOL_SEED,
+    REGISTRY_PAGE_SEED, SUSPENSION_REVIEW_WINDOW,
+};
+use crate::errors::ErrorCode;
+use crate::events::OrganizationReinstated;
+use crate::states::{OrganizationStanding, Pool, RegistryIndex, RegistryPage};
+use crate::utilities::emit_cpi_event;
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{transfer, Transfer};
+
+/// Reinstates a suspended organization once its review window has
+/// passed, releasing the streams held during the suspension to its vault
+/// and relisting the pool as active
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ReinstateOrganization<'info> {
+    #[account(
+        address = ADMIN_PUBKEY @ ErrorCode::AdminUnauthorized
+    )]
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            POOL_SEED.as_bytes(),
+            pool.organization_pubkey.as_ref(),
+            &pool.new_species_id
+        ],
+        bump = pool.pool_bump,
+        constraint = pool.org_standing == OrganizationStanding::Suspended
+            @ ErrorCode::OrganizationNotSuspended,
+    )]
+    pub pool: Account<'info, Pool>,
+
+    /// Global index page listing the pool
+    #[account(
+        mut,
+        seeds = [
+            REGISTRY_PAGE_SEED.as_bytes(),
+            registry_page.index_key.as_ref(),
+            &registry_page.page.to_le_bytes(),
+        ],
+        bump = registry_page.bump,
+        constraint = registry_page.index == RegistryIndex::All @ ErrorCode::PoolNotListed,
+    )]
+    pub registry_page: Box<Account<'info, RegistryPage>>,
+
+    /// Species index page listing the pool
+    #[account(
+        mut,
+        seeds = [
+            REGISTRY_PAGE_SEED.as_bytes(),
+            species_page.index_key.as_ref(),
+            &species_page.page.to_le_bytes(),
+        ],
+        bump = species_page.bump,
+        constraint = species_page.index == RegistryIndex::Species @ ErrorCode::PoolNotListed,
+    )]
+    pub species_page: Box<Account<'info, RegistryPage>>,
+
+    /// Organization index page listing the pool
+    #[account(
+        mut,
+        seeds = [
+            REGISTRY_PAGE_SEED.as_bytes(),
+            organization_page.index_key.as_ref(),
+            &organization_page.page.to_le_bytes(),
+        ],
+        bump = organization_page.bump,
+        constraint = organization_page.index == RegistryIndex::Organization
+            @ ErrorCode::PoolNotListed,
+    )]
+    pub organization_page: Box<Account<'info, RegistryPage>>,
+
+    #[account(
+        mut,
+        seeds = [
+            ESCROW_VAULT_SEED.as_bytes(),
+            pool.organization_pubkey.as_ref(),
+            &pool.new_species_id
+        ],
+        bump = pool.escrow_vault_bump,
+    )]
+    pub escrow_vault: SystemAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            ORG_VAULT_SEED.as_bytes(),
+            pool.organization_pubkey.as_ref(),
+            &pool.new_species_id
+        ],
+        bump = pool.org_vault_bump,
+    )]
+    pub organization_vault: SystemAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+impl<'info> ReinstateOrganization<'info> {
+    pub fn process(&mut self, bumps: &ReinstateOrganizationBumps) -> Result<()> {
+        let current_time = Clock::get()?.unix_timestamp as u64;
+
+        let review_ends_at = self
+            .pool
+            .suspended_at
+            .checked_add(SUSPENSION_REVIEW_WINDOW)
+            .ok_or(ErrorCode::MathError)?;
+        require!(
+            current_time >= review_ends_at,
+            ErrorCode::ReviewWindowNotElapsed
+        );
+
+        let released_amount = self.pool.held_balance;
+
+        if released_amount > 0 {
+            let pool = &self.pool;
+            let seeds = &[
+                ESCROW_VAULT_SEED.as_bytes(),
+                pool.organization_pubkey.as_ref(),
+                &pool.new_species_id,
+                &[pool.escrow_vault_bump],
+            ];
+            let signer_seeds = &[&seeds[..]];
+
+            transfer(
+                CpiContext::new_with_signer(
+                    self.system_program.to_account_info(),
+                    Transfer {
+                        from: self.escrow_vault.to_account_info(),
+                        to: self.organization_vault.to_account_info(),
+                    },
+                    signer_seeds,
+                ),
+                released_amount,
+            )?;
+        }
+
+        let pool = &mut self.pool;
+        pool.org_standing = OrganizationStanding::Active;
+        pool.suspended_at = 0;
+        pool.held_balance = 0;
+        self.update_listings()?;
+
+        let pool = &self.pool;
+        emit_cpi_event(
+            &self.event_authority,
+            bumps.event_authority,
+            OrganizationReinstated {
+                version: EVENT_SCHEMA_VERSION,
+                pool: pool.key(),
+                organization: pool.organization_pubkey,
+                released_amount,
+                timestamp: current_time,
+            },
+        )?;
+
+        msg!("Organization reinstated for pool {}", pool.key());
+
+        Ok(())
+    }
+
+    /// Brings the pool's listing on every index up to its new standing
+    fn update_listings(&mut self) -> Result<()> {
+        let pool_key = self.pool.key();
+        let status = self.pool.listing_status();
+
+        self.registry_page.set_status(pool_key, status)?;
+        self.species_page.set_status(pool_key, status)?;
+        self.organization_page.set_status(pool_key, status)?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,100 @@
+use crate::constants::{ESCROW_VAULT_SEED, EVENT_SCHEMA_VERSION, ORG_VAULT_SEED, POOL_SEED};
+use crate::errors::ErrorCode;
+use crate::events::ExcessEscrowReleased;
+use crate::states::{OrganizationStanding, Pool};
+use crate::utilities::emit_cpi_event;
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{transfer, Transfer};
+
+/// Pays escrow not committed to pending milestones to the organization
+/// vault once escrow mode is off
+///
+/// Permissionless, like `sweep_held_funds`. Pending milestones keep
+/// their tranches escrowed until attested or reclaimed
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ReleaseExcessEscrow<'info> {
+    #[account(
+        mut,
+        seeds = [
+            POOL_SEED.as_bytes(),
+            pool.organization_pubkey.as_ref(),
+            &pool.new_species_id
+        ],
+        bump = pool.pool_bump,
+        constraint = !pool.escrow_mode @ ErrorCode::EscrowModeEnabled,
+        constraint = pool.org_standing == OrganizationStanding::Active
+            @ ErrorCode::OrganizationNotActive,
+    )]
+    pub pool: Account<'info, Pool>,
+
+    #[account(
+        mut,
+        seeds = [
+            ESCROW_VAULT_SEED.as_bytes(),
+            pool.organization_pubkey.as_ref(),
+            &pool.new_species_id
+        ],
+        bump = pool.escrow_vault_bump,
+    )]
+    pub escrow_vault: SystemAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            ORG_VAULT_SEED.as_bytes(),
+            pool.organization_pubkey.as_ref(),
+            &pool.new_species_id
+        ],
+        bump = pool.org_vault_bump,
+    )]
+    pub organization_vault: SystemAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+impl<'info> ReleaseExcessEscrow<'info> {
+    pub fn process(&mut self, bumps: &ReleaseExcessEscrowBumps) -> Result<()> {
+        let amount = self
+            .pool
+            .escrow_balance
+            .saturating_sub(self.pool.escrow_committed);
+        require!(amount > 0, ErrorCode::InvalidAmount);
+
+        let pool = &self.pool;
+        let seeds = &[
+            ESCROW_VAULT_SEED.as_bytes(),
+            pool.organization_pubkey.as_ref(),
+            &pool.new_species_id,
+            &[pool.escrow_vault_bump],
+        ];
+        let signer_seeds = &[&seeds[..]];
+
+        transfer(
+            CpiContext::new_with_signer(
+                self.system_program.to_account_info(),
+                Transfer {
+                    from: self.escrow_vault.to_account_info(),
+                    to: self.organization_vault.to_account_info(),
+                },
+                signer_seeds,
+            ),
+            amount,
+        )?;
+
+        self.pool.escrow_balance -= amount;
+
+        emit_cpi_event(
+            &self.event_authority,
+            bumps.event_authority,
+            ExcessEscrowReleased {
+                version: EVENT_SCHEMA_VERSION,
+                pool: self.pool.key(),
+                amount,
+                timestamp: Clock::get()?.unix_timestamp as u64,
+            },
+        )?;
+
+        Ok(())
+    }
+}
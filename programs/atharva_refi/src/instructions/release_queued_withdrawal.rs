@@ -0,0 +1,243 @@
+use crate::constants::{
+    EVENT_SCHEMA_VERSION, MSOL_MINT, ORG_VAULT_SEED, POOL_SEED, PROTOCOL_STATS_SEED,
+    QUEUED_WITHDRAWAL_SEED, SPENDING_LEDGER_SEED,
+};
+use crate::errors::ErrorCode;
+use crate::events::{QueuedWithdrawalReleased, SpendingRecorded};
+use crate::states::{
+    OrganizationStanding, PayoutAsset, Pool, ProtocolStats, QueuedWithdrawal, SpendingLedger,
+};
+use crate::utilities::emit_cpi_event;
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{self, Transfer};
+use anchor_spl::associated_token::get_associated_token_address;
+use anchor_spl::token::{self as token, Token, TokenAccount};
+
+/// Pays out a queued organization withdrawal once its delay has passed
+/// and adds it to the pool's `SpendingLedger`
+///
+/// Queued mSOL is paid from the organization vault's mSOL ATA into the
+/// recipient's mSOL ATA, so both must be passed for mSOL withdrawals
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ReleaseQueuedWithdrawal<'info> {
+    #[account(mut)]
+    pub recipient: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            POOL_SEED.as_bytes(),
+            pool.organization_pubkey.as_ref(),
+            &pool.new_species_id,
+        ],
+        bump = pool.pool_bump,
+        constraint = pool.org_standing == OrganizationStanding::Active
+            @ ErrorCode::OrganizationNotActive,
+    )]
+    pub pool: Account<'info, Pool>,
+
+    #[account(
+        mut,
+        seeds = [
+            ORG_VAULT_SEED.as_bytes(),
+            pool.organization_pubkey.as_ref(),
+            &pool.new_species_id,
+        ],
+        bump = pool.org_vault_bump,
+    )]
+    pub org_vault: SystemAccount<'info>,
+
+    #[account(
+        mut,
+        close = recipient,
+        seeds = [
+            QUEUED_WITHDRAWAL_SEED.as_bytes(),
+            pool.key().as_ref(),
+            &queued_withdrawal.sequence.to_le_bytes(),
+        ],
+        bump = queued_withdrawal.bump,
+        has_one = pool,
+        has_one = recipient @ ErrorCode::UnauthorizedOrganization,
+    )]
+    pub queued_withdrawal: Account<'info, QueuedWithdrawal>,
+
+    #[account(
+        init_if_needed,
+        payer = recipient,
+        space = 8 + SpendingLedger::INIT_SPACE,
+        seeds = [SPENDING_LEDGER_SEED.as_bytes(), pool.key().as_ref()],
+        bump,
+    )]
+    pub spending_ledger: Account<'info, SpendingLedger>,
+
+    #[account(
+        mut,
+        seeds = [PROTOCOL_STATS_SEED.as_bytes()],
+        bump = protocol_stats.bump,
+    )]
+    pub protocol_stats: Account<'info, ProtocolStats>,
+
+    #[account(
+        mut,
+        address = get_associated_token_address(&org_vault.key(), &MSOL_MINT)
+            @ ErrorCode::InvalidMsolAccount,
+    )]
+    pub org_vault_msol_account: Option<Account<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        address = get_associated_token_address(&recipient.key(), &MSOL_MINT)
+            @ ErrorCode::InvalidMsolAccount,
+    )]
+    pub recipient_msol_account: Option<Account<'info, TokenAccount>>,
+
+    pub token_program: Program<'info, Token>,
+
+    pub system_program: Program<'info, System>,
+}
+impl<'info> ReleaseQueuedWithdrawal<'info> {
+    pub fn process(&mut self, bumps: &ReleaseQueuedWithdrawalBumps) -> Result<()> {
+        let current_time = Clock::get()?.unix_timestamp as u64;
+        let amount = self.queued_withdrawal.amount;
+        let asset = self.queued_withdrawal.asset;
+        let sol_value = self.queued_withdrawal.sol_value;
+
+        require!(
+            current_time >= self.queued_withdrawal.release_ts,
+            ErrorCode::WithdrawalStillLocked
+        );
+
+        // Crank runs can't write the stats from the rollup
+        self.protocol_stats
+            .flush_unreported_yield(&mut self.pool, current_time)?;
+
+        match asset {
+            PayoutAsset::Sol => {
+                self.pool.queued_withdraw_total = self
+                    .pool
+                    .queued_withdraw_total
+                    .checked_sub(amount)
+                    .ok_or(ErrorCode::MathError)?;
+                self.pay_sol(amount)?;
+            }
+            PayoutAsset::Msol => {
+                self.pool.queued_msol_total = self
+                    .pool
+                    .queued_msol_total
+                    .checked_sub(amount)
+                    .ok_or(ErrorCode::MathError)?;
+                self.pay_msol(amount)?;
+            }
+        }
+
+        let pool = &self.pool;
+
+        emit_cpi_event(
+            &self.event_authority,
+            bumps.event_authority,
+            QueuedWithdrawalReleased {
+                version: EVENT_SCHEMA_VERSION,
+                pool: pool.key(),
+                queued_withdrawal: self.queued_withdrawal.key(),
+                recipient: self.recipient.key(),
+                amount,
+                asset,
+                timestamp: current_time,
+            },
+        )?;
+
+        let category = self.queued_withdrawal.category;
+        let memo_hash = self.queued_withdrawal.memo_hash;
+        let ledger = &mut self.spending_ledger;
+        if ledger.pool == Pubkey::default() {
+            ledger.pool = pool.key();
+            ledger.bump = bumps.spending_ledger;
+        }
+        let entry = ledger.record(category, sol_value, memo_hash, current_time)?;
+
+        let stats = &mut self.protocol_stats;
+        stats.total_org_withdrawn = stats
+            .total_org_withdrawn
+            .checked_add(sol_value)
+            .ok_or(ErrorCode::MathError)?;
+        stats.updated_at = current_time;
+
+        emit_cpi_event(
+            &self.event_authority,
+            bumps.event_authority,
+            SpendingRecorded {
+                version: EVENT_SCHEMA_VERSION,
+                pool: pool.key(),
+                organization: self.recipient.key(),
+                category,
+                amount: sol_value,
+                memo_hash,
+                entry,
+                category_total: ledger.category_totals[category as usize],
+                timestamp: current_time,
+            },
+        )?;
+
+        Ok(())
+    }
+
+    fn pay_sol(&self, amount: u64) -> Result<()> {
+        let pool = &self.pool;
+
+        let seeds = &[
+            ORG_VAULT_SEED.as_bytes(),
+            pool.organization_pubkey.as_ref(),
+            &pool.new_species_id,
+            &[pool.org_vault_bump],
+        ];
+        let signer_seeds = &[&seeds[..]];
+
+        system_program::transfer(
+            CpiContext::new_with_signer(
+                self.system_program.to_account_info(),
+                Transfer {
+                    from: self.org_vault.to_account_info(),
+                    to: self.recipient.to_account_info(),
+                },
+                signer_seeds,
+            ),
+            amount,
+        )
+    }
+
+    fn pay_msol(&self, amount: u64) -> Result<()> {
+        let org_vault_msol_account = self
+            .org_vault_msol_account
+            .as_ref()
+            .ok_or(ErrorCode::MissingOrganizationMsolAccount)?;
+        let recipient_msol_account = self
+            .recipient_msol_account
+            .as_ref()
+            .ok_or(ErrorCode::MissingOrganizationMsolAccount)?;
+
+        let pool = &self.pool;
+
+        let seeds = &[
+            ORG_VAULT_SEED.as_bytes(),
+            pool.organization_pubkey.as_ref(),
+            &pool.new_species_id,
+            &[pool.org_vault_bump],
+        ];
+        let signer_seeds = &[&seeds[..]];
+
+        token::transfer(
+            CpiContext::new_with_signer(
+                self.token_program.to_account_info(),
+                token::Transfer {
+                    from: org_vault_msol_account.to_account_info(),
+                    to: recipient_msol_account.to_account_info(),
+                    authority: self.org_vault.to_account_info(),
+                },
+                signer_seeds,
+            ),
+            amount,
+        )
+    }
+}
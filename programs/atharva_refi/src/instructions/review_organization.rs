@@ -0,0 +1,91 @@
+use crate::constants::{ADMIN_PUBKEY, EVENT_SCHEMA_VERSION, ORGANIZATION_SEED};
+use crate::errors::ErrorCode;
+use crate::events::{OrganizationApproved, OrganizationRejected};
+use crate::states::{Organization, VerificationStatus};
+use crate::utilities::emit_cpi_event;
+use anchor_lang::prelude::*;
+
+/// Admin review of a pending organization application
+///
+/// Approval verifies the organization, which can then create pools
+/// for the species it applied for. Rejection records a reason code, and the
+/// organization may apply again
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ReviewOrganization<'info> {
+    #[account(
+        address = ADMIN_PUBKEY @ ErrorCode::AdminUnauthorized
+    )]
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            ORGANIZATION_SEED.as_bytes(),
+            organization.organization_pubkey.as_ref(),
+        ],
+        bump = organization.bump,
+        constraint = organization.status == VerificationStatus::Pending
+            @ ErrorCode::ApplicationNotPending,
+    )]
+    pub organization: Account<'info, Organization>,
+}
+impl<'info> ReviewOrganization<'info> {
+    pub fn approve(&mut self, bumps: &ReviewOrganizationBumps) -> Result<()> {
+        let current_time = Clock::get()?.unix_timestamp as u64;
+        let organization = &mut self.organization;
+
+        organization.status = VerificationStatus::Verified;
+        organization.verified_at = current_time;
+        organization.verified_by = self.admin.key();
+        organization.reviewed_at = current_time;
+
+        emit_cpi_event(
+            &self.event_authority,
+            bumps.event_authority,
+            OrganizationApproved {
+                version: EVENT_SCHEMA_VERSION,
+                organization: organization.key(),
+                organization_pubkey: organization.organization_pubkey,
+                verified_by: self.admin.key(),
+                timestamp: current_time,
+            },
+        )?;
+
+        msg!("Organization approved: {}", organization.key());
+
+        Ok(())
+    }
+
+    pub fn reject(&mut self, reason_code: u16, bumps: &ReviewOrganizationBumps) -> Result<()> {
+        require!(reason_code > 0, ErrorCode::InvalidRejectionReason);
+
+        let current_time = Clock::get()?.unix_timestamp as u64;
+        let organization = &mut self.organization;
+
+        organization.status = VerificationStatus::Rejected;
+        organization.rejection_reason = reason_code;
+        organization.reviewed_at = current_time;
+
+        emit_cpi_event(
+            &self.event_authority,
+            bumps.event_authority,
+            OrganizationRejected {
+                version: EVENT_SCHEMA_VERSION,
+                organization: organization.key(),
+                organization_pubkey: organization.organization_pubkey,
+                reason_code,
+                timestamp: current_time,
+            },
+        )?;
+
+        msg!(
+            "Organization rejected: {} (reason {})",
+            organization.key(),
+            reason_code
+        );
+
+        Ok(())
+    }
+}
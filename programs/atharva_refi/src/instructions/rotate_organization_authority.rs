@@ -0,0 +1,242 @@
+use crate::constants::{
+    ADMIN_PUBKEY, AUTHORITY_ROTATION_DELAY, EVENT_SCHEMA_VERSION, MIN_RECOVERY_DELAY, POOL_SEED,
+};
+use crate::errors::ErrorCode;
+use crate::events::{
+    OrganizationAuthorityRotated, OrganizationAuthorityRotationApproved,
+    OrganizationAuthorityRotationCancelled, OrganizationAuthorityRotationProposed,
+};
+use crate::states::Pool;
+use crate::utilities::emit_cpi_event;
+use anchor_lang::prelude::*;
+
+/// Two-step, timelocked rotation of a pool's organization authority
+///
+/// The authority is separate from `organization_pubkey`, which seeds
+/// the pool's PDAs and never changes. The current authority proposes
+/// a new key, or the admin does for recovery of a lost key. Either
+/// may cancel while pending, and the new key accepts once
+/// `AUTHORITY_ROTATION_DELAY` seconds have passed
+///
+/// An admin recovery can only be cancelled or replaced by the admin,
+/// so a compromised key cannot block its own rotation
+///
+/// Rotation is per pool: the organization's registry `authority`, used
+/// only to create pools, is left alone
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ProposeOrganizationAuthority<'info> {
+    #[account(
+        constraint = authority.key() == ADMIN_PUBKEY
+            || authority.key() == pool.organization_authority
+            @ ErrorCode::PoolConfigUnauthorized,
+    )]
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            POOL_SEED.as_bytes(),
+            pool.organization_pubkey.as_ref(),
+            &pool.new_species_id
+        ],
+        bump = pool.pool_bump,
+    )]
+    pub pool: Account<'info, Pool>,
+}
+impl<'info> ProposeOrganizationAuthority<'info> {
+    pub fn propose(
+        &mut self,
+        new_authority: Pubkey,
+        bumps: &ProposeOrganizationAuthorityBumps,
+    ) -> Result<()> {
+        // Validation
+        require!(
+            new_authority != Pubkey::default() && new_authority != self.pool.organization_authority,
+            ErrorCode::InvalidNewAuthority
+        );
+
+        self.check_admin_rotation()?;
+
+        let current_time = Clock::get()?.unix_timestamp as u64;
+        let eta = current_time
+            .checked_add(AUTHORITY_ROTATION_DELAY)
+            .ok_or(ErrorCode::MathError)?;
+
+        let by_admin = self.authority.key() == ADMIN_PUBKEY;
+        let pool = &mut self.pool;
+        pool.pending_organization_authority = new_authority;
+        pool.authority_rotation_eta = eta;
+        pool.authority_rotation_by_admin = by_admin;
+
+        emit_cpi_event(
+            &self.event_authority,
+            bumps.event_authority,
+            OrganizationAuthorityRotationProposed {
+                version: EVENT_SCHEMA_VERSION,
+                pool: pool.key(),
+                current_authority: pool.organization_authority,
+                pending_authority: new_authority,
+                proposed_by: self.authority.key(),
+                eta,
+                timestamp: current_time,
+            },
+        )?;
+
+        Ok(())
+    }
+
+    pub fn cancel(&mut self, bumps: &ProposeOrganizationAuthorityBumps) -> Result<()> {
+        require!(
+            self.pool.pending_organization_authority != Pubkey::default(),
+            ErrorCode::NoPendingAuthorityRotation
+        );
+        self.check_admin_rotation()?;
+
+        let pool = &mut self.pool;
+        let pending_authority = pool.pending_organization_authority;
+        pool.pending_organization_authority = Pubkey::default();
+        pool.authority_rotation_eta = 0;
+        pool.authority_rotation_by_admin = false;
+
+        emit_cpi_event(
+            &self.event_authority,
+            bumps.event_authority,
+            OrganizationAuthorityRotationCancelled {
+                version: EVENT_SCHEMA_VERSION,
+                pool: pool.key(),
+                pending_authority,
+                cancelled_by: self.authority.key(),
+                timestamp: Clock::get()?.unix_timestamp as u64,
+            },
+        )?;
+
+        Ok(())
+    }
+
+    /// A pending admin recovery is only the admin's to change
+    fn check_admin_rotation(&self) -> Result<()> {
+        require!(
+            !self.pool.authority_rotation_by_admin || self.authority.key() == ADMIN_PUBKEY,
+            ErrorCode::AdminRotationPending
+        );
+
+        Ok(())
+    }
+}
+
+/// Admin approval of a pending rotation, e.g. an urgent recovery
+///
+/// Brings the acceptance time forward to `delay` seconds from now, never
+/// below `MIN_RECOVERY_DELAY`, so the organization always has a window
+/// to react. Never pushes an existing ETA further out
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ApproveOrganizationAuthority<'info> {
+    #[account(
+        address = ADMIN_PUBKEY @ ErrorCode::AdminUnauthorized
+    )]
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            POOL_SEED.as_bytes(),
+            pool.organization_pubkey.as_ref(),
+            &pool.new_species_id
+        ],
+        bump = pool.pool_bump,
+        constraint = pool.pending_organization_authority != Pubkey::default()
+            @ ErrorCode::NoPendingAuthorityRotation,
+    )]
+    pub pool: Account<'info, Pool>,
+}
+impl<'info> ApproveOrganizationAuthority<'info> {
+    pub fn process(&mut self, delay: u64, bumps: &ApproveOrganizationAuthorityBumps) -> Result<()> {
+        require!(
+            delay >= MIN_RECOVERY_DELAY,
+            ErrorCode::RecoveryDelayTooShort
+        );
+
+        let current_time = Clock::get()?.unix_timestamp as u64;
+        let eta = current_time
+            .checked_add(delay)
+            .ok_or(ErrorCode::MathError)?;
+
+        let pool = &mut self.pool;
+        pool.authority_rotation_eta = pool.authority_rotation_eta.min(eta);
+
+        emit_cpi_event(
+            &self.event_authority,
+            bumps.event_authority,
+            OrganizationAuthorityRotationApproved {
+                version: EVENT_SCHEMA_VERSION,
+                pool: pool.key(),
+                pending_authority: pool.pending_organization_authority,
+                eta: pool.authority_rotation_eta,
+                timestamp: current_time,
+            },
+        )?;
+
+        Ok(())
+    }
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct AcceptOrganizationAuthority<'info> {
+    pub new_authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            POOL_SEED.as_bytes(),
+            pool.organization_pubkey.as_ref(),
+            &pool.new_species_id
+        ],
+        bump = pool.pool_bump,
+        constraint = pool.pending_organization_authority != Pubkey::default()
+            @ ErrorCode::NoPendingAuthorityRotation,
+        constraint = pool.pending_organization_authority == new_authority.key()
+            @ ErrorCode::PendingAuthorityMismatch,
+    )]
+    pub pool: Account<'info, Pool>,
+}
+impl<'info> AcceptOrganizationAuthority<'info> {
+    pub fn process(&mut self, bumps: &AcceptOrganizationAuthorityBumps) -> Result<()> {
+        let current_time = Clock::get()?.unix_timestamp as u64;
+
+        let pool = &mut self.pool;
+        require!(
+            current_time >= pool.authority_rotation_eta,
+            ErrorCode::AuthorityRotationTimelocked
+        );
+
+        let previous_authority = pool.organization_authority;
+        pool.organization_authority = self.new_authority.key();
+        pool.pending_organization_authority = Pubkey::default();
+        pool.authority_rotation_eta = 0;
+        pool.authority_rotation_by_admin = false;
+
+        emit_cpi_event(
+            &self.event_authority,
+            bumps.event_authority,
+            OrganizationAuthorityRotated {
+                version: EVENT_SCHEMA_VERSION,
+                pool: pool.key(),
+                previous_authority,
+                new_authority: pool.organization_authority,
+                timestamp: current_time,
+            },
+        )?;
+
+        msg!(
+            "Organization authority rotated to {}",
+            pool.organization_authority
+        );
+
+        Ok(())
+    }
+}
@@ -1,18 +1,20 @@
 use anchor_lang::prelude::*;
 
-use anchor_lang::solana_program::{
-    instruction::{AccountMeta, Instruction},
-    program::invoke_signed,
-};
+use anchor_lang::solana_program::instruction::Instruction;
 use anchor_spl::token::{Mint, Token, TokenAccount};
 use ephemeral_rollups_sdk::consts::MAGIC_PROGRAM_ID;
-use magicblock_magic_program_api::{args::ScheduleTaskArgs, instruction::MagicBlockInstruction};
+use magicblock_magic_program_api::args::ScheduleTaskArgs;
 
 use crate::constants::{
-    MARINADE_PROGRAM_ID, ORG_VAULT_SEED, POOL_SEED, POOL_VAULT_SEED, STREAM_INTERVAL_MS,
+    ADMIN_PUBKEY, ESCROW_VAULT_SEED, EVENT_SCHEMA_VERSION, LIQ_POOL_MSOL_LEG, LIQ_POOL_SOL_LEG,
+    MARINADE_PROGRAM_ID, MARINADE_STATE, MSOL_MINT, ORG_VAULT_SEED, POOL_SEED, POOL_VAULT_SEED,
+    STREAM_INTERVAL_MS, TREASURY_MSOL_ACCOUNT,
 };
 use crate::errors::ErrorCode;
-use crate::states::{Pool, ScheduleStreamArgs};
+use crate::events::{StreamScheduleCancelled, StreamScheduled};
+use crate::magicblock::{magicblock_cancel_task, magicblock_schedule_task, TaskAccounts};
+use crate::states::{PayoutAsset, Pool, ScheduleStreamArgs};
+use crate::utilities::emit_cpi_event;
 
 /// Schedules automated yield streaming via MagicBlock Cranks
 /// Crank calls Stream instruction every 2 days to distribute yields
@@ -21,7 +23,15 @@ use crate::states::{Pool, ScheduleStreamArgs};
 /// - Calculate accumulated yield from Marinade staking
 /// - Distribute organization's percentage to their vault
 /// - Keep remainder in pool for supporters
+///
+/// The same accounts back `reschedule_stream`, which cancels the
+/// pool's current task and schedules a replacement atomically
+///
+/// Only the admin or the organization authority may schedule, since crank
+/// runs bypass the public cooldown. Marinade accounts are pinned because
+/// they are baked into every scheduled stream instruction.
 
+#[event_cpi]
 #[derive(Accounts)]
 pub struct ScheduleStream<'info> {
     #[account(mut)]
@@ -35,7 +45,6 @@ pub struct ScheduleStream<'info> {
         ],
         bump = pool.pool_bump,
         constraint = pool.is_active @ ErrorCode::PoolNotActive,
-        constraint = !pool.is_crank_scheduled @ ErrorCode::CrankAlreadyScheduled,
     )]
     pub pool: Account<'info, Pool>,
 
@@ -61,23 +70,22 @@ pub struct ScheduleStream<'info> {
     )]
     pub organization_vault: SystemAccount<'info>,
 
-    /// CHECK: Validate by Marinade program
-    #[account(mut)]
+    /// CHECK: Pinned to the Marinade state address
+    #[account(mut, address = MARINADE_STATE)]
     pub marinade_state: AccountInfo<'info>,
 
-    #[account(mut)]
+    #[account(mut, address = MSOL_MINT)]
     pub msol_mint: Account<'info, Mint>,
 
-    /// CHECK: Validated by Marinade program
-    #[account(mut)]
+    /// CHECK: Pinned to Marinade's liquidity pool SOL leg
+    #[account(mut, address = LIQ_POOL_SOL_LEG)]
     pub liq_pool_sol_leg: AccountInfo<'info>,
 
-    /// CHECK: Validated by Marinade program
-    #[account(mut)]
+    #[account(mut, address = LIQ_POOL_MSOL_LEG)]
     pub liq_pool_msol_leg: Account<'info, TokenAccount>,
 
-    /// CHECK: Validated by Marinade program. Holds mSOl backing SOL
-    #[account(mut)]
+    /// Holds mSOL backing SOL
+    #[account(mut, address = TREASURY_MSOL_ACCOUNT)]
     pub treasury_msol_account: Account<'info, TokenAccount>,
 
     pub system_program: Program<'info, System>,
@@ -91,12 +99,77 @@ pub struct ScheduleStream<'info> {
     /// CHECK: used for MagicBlock program CPI
     #[account(address = MAGIC_PROGRAM_ID)]
     pub magic_program: AccountInfo<'info>,
-
-    /// CHECK: used for Atharva ReFi CPI
-    pub program: AccountInfo<'info>,
 }
 impl<'info> ScheduleStream<'info> {
-    pub fn process(&mut self, args: ScheduleStreamArgs) -> Result<()> {
+    pub fn process(&mut self, args: ScheduleStreamArgs, bumps: &ScheduleStreamBumps) -> Result<()> {
+        // Validation
+        self.check_authority()?;
+        require!(
+            !self.pool.is_crank_scheduled,
+            ErrorCode::CrankAlreadyScheduled
+        );
+
+        self.schedule(args, bumps)
+    }
+
+    /// Cancels the pool's current crank task and schedules a new one
+    pub fn reschedule(
+        &mut self,
+        args: ScheduleStreamArgs,
+        bumps: &ScheduleStreamBumps,
+    ) -> Result<()> {
+        // Validation
+        self.check_authority()?;
+        require!(self.pool.is_crank_scheduled, ErrorCode::CrankNotScheduled);
+
+        let previous_task_id = self.pool.crank_task_id;
+
+        let seeds = &[
+            POOL_VAULT_SEED.as_bytes(),
+            self.pool.organization_pubkey.as_ref(),
+            &self.pool.new_species_id,
+            &[self.pool.pool_vault_bump],
+        ];
+        let signer_seeds = &[&seeds[..]];
+
+        magicblock_cancel_task(
+            previous_task_id,
+            TaskAccounts {
+                magic_program: self.magic_program.to_account_info(),
+                task_authority: self.pool_vault.to_account_info(),
+                task_context: self.pool.to_account_info(),
+            },
+            signer_seeds,
+        )?;
+
+        emit_cpi_event(
+            &self.event_authority,
+            bumps.event_authority,
+            StreamScheduleCancelled {
+                version: EVENT_SCHEMA_VERSION,
+                pool: self.pool.key(),
+                task_id: previous_task_id,
+                cancelled_by: self.authority.key(),
+                timestamp: Clock::get()?.unix_timestamp as u64,
+            },
+        )?;
+
+        msg!("Crank cancelled: task_id={}", previous_task_id);
+
+        self.schedule(args, bumps)
+    }
+
+    fn check_authority(&self) -> Result<()> {
+        require!(
+            self.authority.key() == ADMIN_PUBKEY
+                || self.authority.key() == self.pool.organization_authority,
+            ErrorCode::UnauthorizedCrankAuthority
+        );
+
+        Ok(())
+    }
+
+    fn schedule(&mut self, args: ScheduleStreamArgs, bumps: &ScheduleStreamBumps) -> Result<()> {
         // Validation
         require!(
             args.execution_interval_millis >= STREAM_INTERVAL_MS,
@@ -105,31 +178,14 @@ impl<'info> ScheduleStream<'info> {
         require!(args.iterations > 0, ErrorCode::InvalidIterations);
 
         // Update pool state
+        let current_time = Clock::get()?.unix_timestamp as u64;
         self.pool.is_crank_scheduled = true;
-        self.pool.last_stream_ts = Clock::get()?.unix_timestamp as u64;
+        self.pool.crank_task_id = args.task_id;
+        self.pool.last_stream_ts = current_time;
 
         // Build stream instruction for cranking
         let stream_ix = self.build_stream_ix()?;
 
-        // Serialize MagicBlock Task Args
-        let ix_data = bincode::serialize(&MagicBlockInstruction::ScheduleTask(ScheduleTaskArgs {
-            task_id: args.task_id,
-            execution_interval_millis: args.execution_interval_millis,
-            iterations: args.iterations,
-            instructions: vec![stream_ix],
-        }))
-        .map_err(|_| ErrorCode::SerializationError)?;
-
-        // Create the schedule instruction for CPI
-        let schedule_ix = Instruction::new_with_bytes(
-            self.magic_program.key(),
-            &ix_data,
-            vec![
-                AccountMeta::new(self.pool_vault.key(), true),
-                AccountMeta::new(self.pool.key(), false),
-            ],
-        );
-
         let seeds = &[
             POOL_VAULT_SEED.as_bytes(),
             self.pool.organization_pubkey.as_ref(),
@@ -138,15 +194,35 @@ impl<'info> ScheduleStream<'info> {
         ];
         let signer_seeds = &[&seeds[..]];
 
-        invoke_signed(
-            &schedule_ix,
-            &[
-                self.pool_vault.to_account_info(),
-                self.pool.to_account_info(),
-            ],
+        magicblock_schedule_task(
+            ScheduleTaskArgs {
+                task_id: args.task_id,
+                execution_interval_millis: args.execution_interval_millis,
+                iterations: args.iterations,
+                instructions: vec![stream_ix],
+            },
+            TaskAccounts {
+                magic_program: self.magic_program.to_account_info(),
+                task_authority: self.pool_vault.to_account_info(),
+                task_context: self.pool.to_account_info(),
+            },
             signer_seeds,
         )?;
 
+        emit_cpi_event(
+            &self.event_authority,
+            bumps.event_authority,
+            StreamScheduled {
+                version: EVENT_SCHEMA_VERSION,
+                pool: self.pool.key(),
+                task_id: args.task_id,
+                execution_interval_millis: args.execution_interval_millis,
+                iterations: args.iterations,
+                scheduled_by: self.authority.key(),
+                timestamp: current_time,
+            },
+        )?;
+
         msg!(
             "Crank scheduled: task_id={}, interval={}ms, iterations={}",
             args.task_id,
@@ -163,22 +239,56 @@ impl<'info> ScheduleStream<'info> {
             &self.msol_mint.key(),
         );
 
+        let mut accounts = crate::accounts::Stream {
+            pool: self.pool.key(),
+            organization_vault: self.organization_vault.key(),
+            escrow_vault: Pubkey::create_program_address(
+                &[
+                    ESCROW_VAULT_SEED.as_bytes(),
+                    self.pool.organization_pubkey.as_ref(),
+                    &self.pool.new_species_id,
+                    &[self.pool.escrow_vault_bump],
+                ],
+                &crate::ID,
+            )
+            .map_err(|_| ErrorCode::InvalidInput)?,
+            marinade_state: self.marinade_state.key(),
+            msol_mint: self.msol_mint.key(),
+            liq_pool_sol_leg: self.liq_pool_sol_leg.key(),
+            liq_pool_msol_leg: self.liq_pool_msol_leg.key(),
+            treasury_msol_account: self.treasury_msol_account.key(),
+            pool_msol_account,
+            pool_vault: self.pool_vault.key(),
+            system_program: self.system_program.key(),
+            token_program: self.token_program.key(),
+            marinade_program: self.marinade_program.key(),
+            // The rollup can't create accounts, so crank runs are not recorded
+            payer: None,
+            stream_record: None,
+            organization_msol_account: (self.pool.payout_asset == PayoutAsset::Msol).then(|| {
+                anchor_spl::associated_token::get_associated_token_address(
+                    &self.organization_vault.key(),
+                    &self.msol_mint.key(),
+                )
+            }),
+            // Nor write protocol stats, so crank yield is parked on the pool
+            protocol_stats: None,
+            pool_history: None,
+            event_authority: self.event_authority.key(),
+            program: self.program.key(),
+        }
+        .to_account_metas(None);
+
+        // The crank signs as the pool vault, which lets it bypass the public cooldown
+        for meta in accounts.iter_mut() {
+            if meta.pubkey == self.pool_vault.key() {
+                meta.is_signer = true;
+            }
+        }
+
         Ok(Instruction {
             program_id: crate::ID,
-            accounts: vec![
-                AccountMeta::new(self.pool.key(), false),
-                AccountMeta::new(self.pool_vault.key(), true),
-                AccountMeta::new(pool_msol_account, false),
-                AccountMeta::new(self.organization_vault.key(), false),
-                AccountMeta::new(self.marinade_state.key(), false),
-                AccountMeta::new(self.msol_mint.key(), false),
-                AccountMeta::new(self.liq_pool_sol_leg.key(), false),
-                AccountMeta::new(self.liq_pool_msol_leg.key(), false),
-                AccountMeta::new(self.treasury_msol_account.key(), false),
-                AccountMeta::new_readonly(self.marinade_program.key(), false),
-                AccountMeta::new_readonly(self.token_program.key(), false),
-                AccountMeta::new_readonly(self.system_program.key(), false),
-            ],
+            accounts,
             data: anchor_lang::InstructionData::data(&crate::instruction::Stream {}),
         })
     }
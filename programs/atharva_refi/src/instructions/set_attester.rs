@@ -0,0 +1,62 @@
+use crate::constants::{ADMIN_PUBKEY, ATTESTER_SEED, EVENT_SCHEMA_VERSION};
+use crate::errors::ErrorCode;
+use crate::events::AttesterUpdated;
+use crate::states::Attester;
+use crate::utilities::emit_cpi_event;
+use anchor_lang::prelude::*;
+
+/// Registers, reactivates or revokes an attester
+///
+/// Attesters report impact metrics for pools
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(attester: Pubkey)]
+pub struct SetAttester<'info> {
+    #[account(
+        mut,
+        address = ADMIN_PUBKEY @ ErrorCode::AdminUnauthorized
+    )]
+    pub admin: Signer<'info>,
+
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = 8 + Attester::INIT_SPACE,
+        seeds = [ATTESTER_SEED.as_bytes(), attester.as_ref()],
+        bump,
+    )]
+    pub attester_account: Account<'info, Attester>,
+
+    pub system_program: Program<'info, System>,
+}
+impl<'info> SetAttester<'info> {
+    pub fn process(
+        &mut self,
+        attester: Pubkey,
+        is_active: bool,
+        bumps: &SetAttesterBumps,
+    ) -> Result<()> {
+        let current_time = Clock::get()?.unix_timestamp as u64;
+
+        let attester_account = &mut self.attester_account;
+        attester_account.attester = attester;
+        attester_account.is_active = is_active;
+        attester_account.registered_by = self.admin.key();
+        attester_account.updated_at = current_time;
+        attester_account.bump = bumps.attester_account;
+
+        emit_cpi_event(
+            &self.event_authority,
+            bumps.event_authority,
+            AttesterUpdated {
+                version: EVENT_SCHEMA_VERSION,
+                attester,
+                is_active,
+                timestamp: current_time,
+            },
+        )?;
+
+        Ok(())
+    }
+}
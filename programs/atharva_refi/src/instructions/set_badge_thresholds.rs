@@ -0,0 +1,58 @@
+use crate::constants::{ADMIN_PUBKEY, BADGE_TIERS, EVENT_SCHEMA_VERSION, POOL_SEED};
+use crate::errors::ErrorCode;
+use crate::events::BadgeThresholdsUpdated;
+use crate::states::Pool;
+use crate::utilities::emit_cpi_event;
+use anchor_lang::prelude::*;
+
+/// Configures the lifetime donated yield (lamports) a supporter
+/// must reach to claim each impact badge tier of a pool
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct SetBadgeThresholds<'info> {
+    #[account(
+        address = ADMIN_PUBKEY @ ErrorCode::AdminUnauthorized
+    )]
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            POOL_SEED.as_bytes(),
+            pool.organization_pubkey.as_ref(),
+            &pool.new_species_id
+        ],
+        bump = pool.pool_bump,
+    )]
+    pub pool: Account<'info, Pool>,
+}
+impl<'info> SetBadgeThresholds<'info> {
+    pub fn process(
+        &mut self,
+        thresholds: [u64; BADGE_TIERS],
+        bumps: &SetBadgeThresholdsBumps,
+    ) -> Result<()> {
+        // Validation
+        require!(thresholds[0] > 0, ErrorCode::InvalidBadgeThresholds);
+        require!(
+            thresholds.windows(2).all(|pair| pair[0] < pair[1]),
+            ErrorCode::InvalidBadgeThresholds
+        );
+
+        self.pool.badge_thresholds = thresholds;
+
+        emit_cpi_event(
+            &self.event_authority,
+            bumps.event_authority,
+            BadgeThresholdsUpdated {
+                version: EVENT_SCHEMA_VERSION,
+                pool: self.pool.key(),
+                thresholds,
+                timestamp: Clock::get()?.unix_timestamp as u64,
+            },
+        )?;
+
+        Ok(())
+    }
+}
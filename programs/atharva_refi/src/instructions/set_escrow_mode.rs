@@ -0,0 +1,73 @@
+use crate::constants::{ADMIN_PUBKEY, EVENT_SCHEMA_VERSION, POOL_SEED};
+use crate::errors::ErrorCode;
+use crate::events::EscrowModeUpdated;
+use crate::states::{PayoutAsset, Pool};
+use crate::utilities::emit_cpi_event;
+use anchor_lang::prelude::*;
+
+/// Turns milestone escrow on or off for a pool
+///
+/// While on, SOL streams pay into the pool's escrow vault and only
+/// leave it through `attest_milestone` or `reclaim_milestone`. Once off,
+/// escrow beyond the pending milestones goes back to the organization
+/// through `release_excess_escrow`
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct SetEscrowMode<'info> {
+    #[account(
+        address = ADMIN_PUBKEY @ ErrorCode::AdminUnauthorized
+    )]
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            POOL_SEED.as_bytes(),
+            pool.organization_pubkey.as_ref(),
+            &pool.new_species_id
+        ],
+        bump = pool.pool_bump,
+    )]
+    pub pool: Account<'info, Pool>,
+}
+impl<'info> SetEscrowMode<'info> {
+    pub fn process(
+        &mut self,
+        escrow_mode: bool,
+        fallback_beneficiary: Pubkey,
+        bumps: &SetEscrowModeBumps,
+    ) -> Result<()> {
+        let pool = &mut self.pool;
+
+        if escrow_mode {
+            require!(
+                pool.payout_asset == PayoutAsset::Sol,
+                ErrorCode::EscrowRequiresSolPayout
+            );
+            require!(
+                fallback_beneficiary != Pubkey::default(),
+                ErrorCode::FallbackBeneficiaryNotSet
+            );
+        }
+
+        pool.escrow_mode = escrow_mode;
+        if fallback_beneficiary != Pubkey::default() {
+            pool.fallback_beneficiary = fallback_beneficiary;
+        }
+
+        emit_cpi_event(
+            &self.event_authority,
+            bumps.event_authority,
+            EscrowModeUpdated {
+                version: EVENT_SCHEMA_VERSION,
+                pool: pool.key(),
+                escrow_mode,
+                fallback_beneficiary: pool.fallback_beneficiary,
+                timestamp: Clock::get()?.unix_timestamp as u64,
+            },
+        )?;
+
+        Ok(())
+    }
+}
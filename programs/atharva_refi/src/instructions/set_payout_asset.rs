@@ -0,0 +1,92 @@
+use crate::constants::{ADMIN_PUBKEY, EVENT_SCHEMA_VERSION, MSOL_MINT, ORG_VAULT_SEED, POOL_SEED};
+use crate::errors::ErrorCode;
+use crate::events::PayoutAssetUpdated;
+use crate::states::{PayoutAsset, Pool};
+use crate::utilities::emit_cpi_event;
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token::{Mint, Token, TokenAccount};
+
+/// Chooses whether the organization's yield is paid out in SOL or mSOL
+///
+/// The organization vault's mSOL ATA is created here so streams
+/// never need a payer. The crank bakes its accounts in at schedule
+/// time, so the setting can only change while no crank is scheduled
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct SetPayoutAsset<'info> {
+    #[account(
+        mut,
+        constraint = authority.key() == ADMIN_PUBKEY
+            || authority.key() == pool.organization_authority
+            @ ErrorCode::PoolConfigUnauthorized,
+    )]
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            POOL_SEED.as_bytes(),
+            pool.organization_pubkey.as_ref(),
+            &pool.new_species_id
+        ],
+        bump = pool.pool_bump,
+        constraint = !pool.is_crank_scheduled @ ErrorCode::CrankAlreadyScheduled,
+    )]
+    pub pool: Account<'info, Pool>,
+
+    #[account(
+        seeds = [
+            ORG_VAULT_SEED.as_bytes(),
+            pool.organization_pubkey.as_ref(),
+            &pool.new_species_id
+        ],
+        bump = pool.org_vault_bump,
+    )]
+    pub organization_vault: SystemAccount<'info>,
+
+    #[account(address = MSOL_MINT)]
+    pub msol_mint: Account<'info, Mint>,
+
+    #[account(
+        init_if_needed,
+        payer = authority,
+        associated_token::mint = msol_mint,
+        associated_token::authority = organization_vault,
+    )]
+    pub organization_msol_account: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+
+    pub associated_token_program: Program<'info, AssociatedToken>,
+
+    pub system_program: Program<'info, System>,
+}
+impl<'info> SetPayoutAsset<'info> {
+    pub fn process(
+        &mut self,
+        payout_asset: PayoutAsset,
+        bumps: &SetPayoutAssetBumps,
+    ) -> Result<()> {
+        require!(
+            payout_asset == PayoutAsset::Sol || !self.pool.escrow_mode,
+            ErrorCode::EscrowRequiresSolPayout
+        );
+
+        self.pool.payout_asset = payout_asset;
+
+        emit_cpi_event(
+            &self.event_authority,
+            bumps.event_authority,
+            PayoutAssetUpdated {
+                version: EVENT_SCHEMA_VERSION,
+                pool: self.pool.key(),
+                payout_asset,
+                timestamp: Clock::get()?.unix_timestamp as u64,
+            },
+        )?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,53 @@
+use crate::constants::{ADMIN_PUBKEY, EVENT_SCHEMA_VERSION, MIN_STREAM_COOLDOWN, POOL_SEED};
+use crate::errors::ErrorCode;
+use crate::events::StreamCooldownUpdated;
+use crate::states::Pool;
+use crate::utilities::emit_cpi_event;
+use anchor_lang::prelude::*;
+
+/// Configures how long (in seconds) after the last stream
+/// anyone other than the crank may call `stream` on a pool
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct SetStreamCooldown<'info> {
+    #[account(
+        address = ADMIN_PUBKEY @ ErrorCode::AdminUnauthorized
+    )]
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            POOL_SEED.as_bytes(),
+            pool.organization_pubkey.as_ref(),
+            &pool.new_species_id
+        ],
+        bump = pool.pool_bump,
+    )]
+    pub pool: Account<'info, Pool>,
+}
+impl<'info> SetStreamCooldown<'info> {
+    pub fn process(&mut self, stream_cooldown: u64, bumps: &SetStreamCooldownBumps) -> Result<()> {
+        // Validation
+        require!(
+            stream_cooldown >= MIN_STREAM_COOLDOWN,
+            ErrorCode::CooldownTooShort
+        );
+
+        self.pool.stream_cooldown = stream_cooldown;
+
+        emit_cpi_event(
+            &self.event_authority,
+            bumps.event_authority,
+            StreamCooldownUpdated {
+                version: EVENT_SCHEMA_VERSION,
+                pool: self.pool.key(),
+                stream_cooldown,
+                timestamp: Clock::get()?.unix_timestamp as u64,
+            },
+        )?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,89 @@
+use crate::constants::{ADMIN_PUBKEY, EVENT_SCHEMA_VERSION, POOL_SEED};
+use crate::errors::ErrorCode;
+use crate::events::WithdrawalControlsUpdated;
+use crate::states::Pool;
+use crate::utilities::emit_cpi_event;
+use anchor_lang::prelude::*;
+
+/// Configures the organization's withdrawal cap, rolling window and
+/// queue delay for a pool
+///
+/// The organization may only tighten its controls, so a compromised
+/// key cannot lift them before draining the vault. The admin may set
+/// anything, including a zero cap (no cap) or zero delay (no queue),
+/// and is the only one whose window change resets the window counters
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct SetWithdrawalControls<'info> {
+    #[account(
+        constraint = authority.key() == ADMIN_PUBKEY
+            || authority.key() == pool.organization_authority
+            @ ErrorCode::PoolConfigUnauthorized,
+    )]
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            POOL_SEED.as_bytes(),
+            pool.organization_pubkey.as_ref(),
+            &pool.new_species_id
+        ],
+        bump = pool.pool_bump,
+    )]
+    pub pool: Account<'info, Pool>,
+}
+impl<'info> SetWithdrawalControls<'info> {
+    pub fn process(
+        &mut self,
+        withdraw_cap: u64,
+        withdraw_window: u64,
+        withdraw_delay: u64,
+        bumps: &SetWithdrawalControlsBumps,
+    ) -> Result<()> {
+        // Validation
+        require!(withdraw_window > 0, ErrorCode::InvalidInput);
+
+        let pool = &mut self.pool;
+        let is_admin = self.authority.key() == ADMIN_PUBKEY;
+
+        if !is_admin {
+            let tightens_cap =
+                withdraw_cap > 0 && (pool.withdraw_cap == 0 || withdraw_cap <= pool.withdraw_cap);
+            require!(
+                tightens_cap
+                    && withdraw_window >= pool.withdraw_window
+                    && withdraw_delay >= pool.withdraw_delay,
+                ErrorCode::WithdrawalControlsLoosened
+            );
+        }
+
+        // Only the admin may restart the window. A longer window keeps what
+        // was already withdrawn in it, so resizing can't refresh the cap
+        if is_admin && withdraw_window != pool.withdraw_window {
+            pool.withdraw_window_start = 0;
+            pool.withdrawn_in_window = 0;
+        }
+
+        pool.withdraw_cap = withdraw_cap;
+        pool.withdraw_window = withdraw_window;
+        pool.withdraw_delay = withdraw_delay;
+
+        emit_cpi_event(
+            &self.event_authority,
+            bumps.event_authority,
+            WithdrawalControlsUpdated {
+                version: EVENT_SCHEMA_VERSION,
+                pool: pool.key(),
+                withdraw_cap,
+                withdraw_window,
+                withdraw_delay,
+                updated_by: self.authority.key(),
+                timestamp: Clock::get()?.unix_timestamp as u64,
+            },
+        )?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,106 @@
+use crate::constants::{
+    EVENT_SCHEMA_VERSION, MARINADE_STATE, MIN_SNAPSHOT_INTERVAL, MSOL_MINT, POOL_HISTORY_SEED,
+    POOL_SEED,
+};
+use crate::errors::ErrorCode;
+use crate::events::PoolSnapshotRecorded;
+use crate::marinade::MarinadeRates;
+use crate::states::{Pool, PoolHistory, PoolSnapshot};
+use crate::utilities::emit_cpi_event;
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::get_associated_token_address;
+use anchor_spl::token::TokenAccount;
+
+/// Records the pool's NAV, mSOL price and share count in its history
+///
+/// Permissionless and read-only against Marinade, so keepers can build
+/// APY history between streams. Limited to one snapshot per
+/// `MIN_SNAPSHOT_INTERVAL`; the caller pays for the history account
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct Snapshot<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(
+        seeds = [
+            POOL_SEED.as_bytes(),
+            pool.organization_pubkey.as_ref(),
+            &pool.new_species_id
+        ],
+        bump = pool.pool_bump,
+    )]
+    pub pool: Account<'info, Pool>,
+
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 8 + PoolHistory::INIT_SPACE,
+        seeds = [POOL_HISTORY_SEED.as_bytes(), pool.key().as_ref()],
+        bump,
+    )]
+    pub pool_history: Box<Account<'info, PoolHistory>>,
+
+    /// CHECK: Read-only, pinned to the Marinade state address
+    #[account(address = MARINADE_STATE)]
+    pub marinade_state: AccountInfo<'info>,
+
+    #[account(
+        address = get_associated_token_address(&pool.vault, &MSOL_MINT)
+            @ ErrorCode::InvalidTokenAccount,
+    )]
+    pub pool_msol_account: Account<'info, TokenAccount>,
+
+    pub system_program: Program<'info, System>,
+}
+impl<'info> Snapshot<'info> {
+    pub fn process(&mut self, bumps: &SnapshotBumps) -> Result<()> {
+        let current_time = Clock::get()?.unix_timestamp as u64;
+
+        let history = &mut self.pool_history;
+        if history.pool == Pubkey::default() {
+            history.pool = self.pool.key();
+            history.bump = bumps.pool_history;
+        }
+
+        if let Some(latest) = history.latest() {
+            let next_snapshot_ts = latest
+                .timestamp
+                .checked_add(MIN_SNAPSHOT_INTERVAL)
+                .ok_or(ErrorCode::MathError)?;
+            require!(
+                current_time >= next_snapshot_ts,
+                ErrorCode::SnapshotTooFrequent
+            );
+        }
+
+        let rates = MarinadeRates::load(&self.marinade_state)?;
+        let msol_price = rates.msol_price()?;
+        let nav = rates.msol_to_sol(self.pool_msol_account.amount)?;
+
+        let history = &mut self.pool_history;
+        history.push(PoolSnapshot {
+            timestamp: current_time,
+            nav,
+            msol_price,
+            total_shares: self.pool.total_shares,
+        })?;
+
+        emit_cpi_event(
+            &self.event_authority,
+            bumps.event_authority,
+            PoolSnapshotRecorded {
+                version: EVENT_SCHEMA_VERSION,
+                pool: self.pool.key(),
+                nav,
+                msol_price,
+                total_shares: self.pool.total_shares,
+                trailing_apy_bps: history.trailing_apy_bps().unwrap_or_default(),
+                timestamp: current_time,
+            },
+        )?;
+
+        Ok(())
+    }
+}
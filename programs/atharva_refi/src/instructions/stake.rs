@@ -1,6 +1,9 @@
-use crate::constants::{POOL_SEED, POOL_VAULT_SEED};
+use crate::constants::{EVENT_SCHEMA_VERSION, POOL_SEED, POOL_VAULT_SEED};
+use crate::errors::ErrorCode;
+use crate::events::PoolStaked;
 use crate::marinade::{marinade_liquid_stake, LiquidStakeAccounts};
 use crate::states::Pool;
+use crate::utilities::emit_cpi_event;
 use anchor_lang::prelude::*;
 use anchor_spl::associated_token::AssociatedToken;
 use anchor_spl::token::{Mint, Token, TokenAccount};
@@ -9,6 +12,7 @@ use anchor_spl::token::{Mint, Token, TokenAccount};
 /// Pool vault immediately stakes on marinade and creates
 /// the pool_msol_account to hold mSOL
 
+#[event_cpi]
 #[derive(Accounts)]
 pub struct Stake<'info> {
     // #[account(mut)]
@@ -89,9 +93,11 @@ pub struct Stake<'info> {
 impl<'info> Stake<'info> {
     /// Stake SOL with Marinade to receive mSOL
     /// Manually constructs the Marinade deposit instruction
-    pub fn process(&self, amount: u64) -> Result<()> {
+    pub fn process(&mut self, amount: u64, bumps: &StakeBumps) -> Result<()> {
         msg!("Staking SOL on Marinade...");
 
+        let msol_before = self.pool_msol_account.amount;
+
         let pool = &self.pool;
 
         let seeds = &[
@@ -121,6 +127,25 @@ impl<'info> Stake<'info> {
             Some(signer_seeds),
         )?;
 
+        self.pool_msol_account.reload()?;
+        let msol_amount = self
+            .pool_msol_account
+            .amount
+            .checked_sub(msol_before)
+            .ok_or(ErrorCode::MathError)?;
+
+        emit_cpi_event(
+            &self.event_authority,
+            bumps.event_authority,
+            PoolStaked {
+                version: EVENT_SCHEMA_VERSION,
+                pool: self.pool.key(),
+                sol_amount: amount,
+                msol_amount,
+                timestamp: Clock::get()?.unix_timestamp as u64,
+            },
+        )?;
+
         msg!("Successfully staked {} lamports for mSOL", amount);
 
         Ok(())
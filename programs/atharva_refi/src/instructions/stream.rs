@@ -1,19 +1,71 @@
-use crate::constants::{MARINADE_PROGRAM_ID, MIN_YIELD_AMOUNT, ORG_VAULT_SEED, ORG_YIELD_BPS};
+use crate::constants::{
+    ESCROW_VAULT_SEED, EVENT_SCHEMA_VERSION, MARINADE_PROGRAM_ID, MARINADE_STATE,
+    MIN_SNAPSHOT_INTERVAL, MIN_YIELD_AMOUNT, MSOL_MINT, MSOL_PRICE_PRECISION, ORG_VAULT_SEED,
+    POOL_HISTORY_SEED, PROTOCOL_STATS_SEED, STREAM_RECORD_SEED, YIELD_PER_SHARE_PRECISION,
+};
 use crate::errors::ErrorCode;
-use crate::events::YieldStreamed;
-use crate::marinade::{marinade_liquid_unstake, LiquidUnstakeAccounts};
+use crate::events::{
+    LossRecorded, LossRecovered, OrganizationAccrualStarted, PoolSnapshotRecorded,
+    StreamSkipReason, StreamSkipped, YieldHeld, YieldStreamed,
+};
+use crate::marinade::{marinade_liquid_unstake, LiquidUnstakeAccounts, MarinadeRates};
+use crate::utilities::emit_cpi_event;
 use crate::{
     constants::{POOL_SEED, POOL_VAULT_SEED},
-    states::Pool,
+    states::{PayoutAsset, Pool, PoolHistory, PoolSnapshot, ProtocolStats, StreamRecord},
 };
 use anchor_lang::prelude::*;
-use anchor_lang::system_program::{transfer, Transfer};
-use anchor_spl::token::{Mint, Token, TokenAccount};
+use anchor_lang::system_program::{
+    allocate, assign, create_account, transfer, Allocate, Assign, CreateAccount, Transfer,
+};
+use anchor_spl::associated_token::get_associated_token_address;
+use anchor_spl::token::{
+    transfer as token_transfer, Mint, Token, TokenAccount, Transfer as TokenTransfer,
+};
 
+/// Streams the organization's share of accrued yield to its vault
+///
+/// Access:
+/// - The scheduled MagicBlock crank signs as the pool vault PDA and
+///   may stream at its configured interval
+/// - Anyone else may stream once `stream_cooldown` seconds have
+///   passed since `last_stream_ts`
+///
+/// Yield at or below `MIN_YIELD_AMOUNT` is not paid out. The call still
+/// succeeds, records what it observed and carries the yield over to the
+/// next stream, so scheduled cranks don't fail on small pools.
+///
+/// Losses (slashing, Marinade incidents) are tracked against a high-water
+/// mark on the mSOL price. While the price is below the mark the loss is
+/// recorded and nothing streams, so recovery is never paid out as new yield.
+///
+/// Pools paying out in mSOL move the org's share straight into the
+/// organization vault's mSOL ATA instead of liquid-unstaking it.
+///
+/// SOL payouts land in the organization vault but accrue to the
+/// organization linearly over the next `stream_cooldown` seconds, along
+/// with anything left unvested from the previous period. Pools in escrow
+/// mode pay into the escrow vault instead, released per milestone.
+///
+/// While the organization is suspended or reassigned, its share is always
+/// unstaked to SOL and held in the escrow vault pending admin review.
+///
+/// Every payout writes a `StreamRecord` PDA seeded by pool and sequence
+/// number, with rent paid by the caller. Public callers must pass both;
+/// crank runs execute inside the ephemeral rollup, which cannot create
+/// accounts, so they skip the record. The sequence only advances when a
+/// record is written, so recorded streams stay gapless.
+///
+/// The rollup can't write `ProtocolStats` either. Crank runs park the
+/// org's share in `unreported_yield` until the next public stream,
+/// deposit or withdrawal flushes it.
+///
+/// Public callers may also pass the pool's `PoolHistory` (created by the
+/// first `snapshot`) to record what every stream observed, paid out or not.
+
+#[event_cpi]
 #[derive(Accounts)]
 pub struct Stream<'info> {
-    // #[account(mut)]
-    // pub auth: Signer<'info>,
     #[account(
         mut,
         seeds = [
@@ -36,11 +88,24 @@ pub struct Stream<'info> {
     )]
     pub organization_vault: SystemAccount<'info>,
 
-    /// CHECK: Verified by Marinade program
-    #[account(mut)]
+    #[account(
+        mut,
+        seeds = [
+            ESCROW_VAULT_SEED.as_bytes(),
+            pool.organization_pubkey.as_ref(),
+            &pool.new_species_id
+        ],
+        bump = pool.escrow_vault_bump,
+    )]
+    pub escrow_vault: SystemAccount<'info>,
+
+    /// Pinned, since the mSOL payout and loss tracking read it without
+    /// any Marinade CPI to validate it
+    /// CHECK: Pinned to the Marinade state address
+    #[account(mut, address = MARINADE_STATE)]
     pub marinade_state: AccountInfo<'info>,
 
-    #[account(mut)]
+    #[account(mut, address = MSOL_MINT)]
     pub msol_mint: Account<'info, Mint>,
 
     /// Vault to receive SOL from unstake
@@ -59,8 +124,11 @@ pub struct Stream<'info> {
 
     /// mSOL token account to burn from
     /// Equivalent of `get_msol_from`
-    /// CHECK: Verified by Marinade program
-    #[account(mut)]
+    #[account(
+        mut,
+        address = get_associated_token_address(&pool.vault, &MSOL_MINT)
+            @ ErrorCode::InvalidTokenAccount,
+    )]
     pub pool_msol_account: Account<'info, TokenAccount>,
 
     /// Signs burn CPI and receives SOL from unstake
@@ -85,54 +153,309 @@ pub struct Stream<'info> {
     /// CHECK: Marinade program
     #[account(address = MARINADE_PROGRAM_ID)]
     pub marinade_program: AccountInfo<'info>,
+
+    /// Pays the stream record's rent, required alongside it
+    #[account(mut)]
+    pub payer: Option<Signer<'info>>,
+
+    /// History record for this stream, created in `process`
+    /// CHECK: Uninitialized PDA for the pool's next sequence number
+    #[account(
+        mut,
+        seeds = [
+            STREAM_RECORD_SEED.as_bytes(),
+            pool.key().as_ref(),
+            &pool.stream_count.to_le_bytes(),
+        ],
+        bump,
+    )]
+    pub stream_record: Option<UncheckedAccount<'info>>,
+
+    /// Organization vault's mSOL ATA, required when the pool pays out in mSOL
+    #[account(
+        mut,
+        associated_token::mint = msol_mint,
+        associated_token::authority = organization_vault,
+    )]
+    pub organization_msol_account: Option<Box<Account<'info, TokenAccount>>>,
+
+    #[account(
+        mut,
+        seeds = [PROTOCOL_STATS_SEED.as_bytes()],
+        bump = protocol_stats.bump,
+    )]
+    pub protocol_stats: Option<Account<'info, ProtocolStats>>,
+
+    #[account(
+        mut,
+        seeds = [POOL_HISTORY_SEED.as_bytes(), pool.key().as_ref()],
+        bump = pool_history.bump,
+    )]
+    pub pool_history: Option<Box<Account<'info, PoolHistory>>>,
 }
 impl<'info> Stream<'info> {
-    pub fn process(&mut self) -> Result<()> {
+    pub fn process(&mut self, bumps: &StreamBumps) -> Result<()> {
         let current_time = Clock::get()?.unix_timestamp as u64;
 
+        self.check_access(current_time)?;
+        require!(
+            self.pool_vault.is_signer || (self.stream_record.is_some() && self.payer.is_some()),
+            ErrorCode::StreamRecordRequired
+        );
+
         // Calculate yield
         let current_sol_value = self.compute_pool_sol_value()?;
         let total_yield = self.compute_yield(current_sol_value)?;
 
-        require!(total_yield > MIN_YIELD_AMOUNT, ErrorCode::YieldTooSmall);
+        self.pool.last_observed_sol_value = current_sol_value;
+        self.pool.last_observed_ts = current_time;
+        self.record_snapshot(current_sol_value, current_time, bumps)?;
+
+        if !self.check_high_water_mark(current_sol_value, current_time, bumps)? {
+            return self.skip_stream(
+                current_sol_value,
+                total_yield,
+                current_time,
+                StreamSkipReason::BelowHighWaterMark,
+                bumps,
+            );
+        }
 
-        let org_yield_sol = self.calculate_org_yield(total_yield)?;
-        let msol_to_unstake = self.sol_to_msol(org_yield_sol)?;
+        if total_yield <= MIN_YIELD_AMOUNT {
+            return self.skip_stream(
+                current_sol_value,
+                total_yield,
+                current_time,
+                StreamSkipReason::YieldBelowMinimum,
+                bumps,
+            );
+        }
+
+        let org_yield_sol = self.pool.org_yield_share(total_yield)?;
+        let org_msol = self.sol_to_msol(org_yield_sol)?;
 
         msg!(
             "Streaming {} mSOL (≈{} SOL, 20% of {} total yield)",
-            msol_to_unstake,
+            org_msol,
             org_yield_sol,
             total_yield
         );
 
-        // Get pool vault balance before unstaking
-        let vault_balance_before = self.pool_vault.lamports();
+        let payout_asset = if self.pool.holds_org_yield() {
+            PayoutAsset::Sol
+        } else {
+            self.pool.payout_asset
+        };
+
+        let (org_amount, lamports_received, msol_amount) = match payout_asset {
+            PayoutAsset::Sol => {
+                let received = self.pay_org_in_sol(org_msol)?;
+                self.account_org_sol(received, current_time, bumps)?;
+                (received, received, 0)
+            }
+            PayoutAsset::Msol => {
+                self.pay_org_in_msol(org_msol)?;
+                (org_yield_sol, 0, org_msol)
+            }
+        };
+
+        self.update_checkpoint(current_sol_value, org_amount)?;
+        self.report_yield(org_yield_sol, current_time)?;
+        self.write_stream_record(StreamRecord {
+            pool: self.pool.key(),
+            organization: self.pool.organization_pubkey,
+            sequence: self.pool.stream_count,
+            // High-water mark was just raised to the current price
+            msol_price: self.pool.peak_msol_price,
+            pool_sol_value: current_sol_value,
+            total_yield,
+            org_amount: org_yield_sol,
+            lamports_received,
+            msol_amount,
+            timestamp: current_time,
+            bump: bumps.stream_record.unwrap_or_default(),
+        })?;
+
+        emit_cpi_event(
+            &self.event_authority,
+            bumps.event_authority,
+            YieldStreamed {
+                version: EVENT_SCHEMA_VERSION,
+                pool: self.pool.key(),
+                organization: self.pool.organization_pubkey,
+                total_yield,
+                org_amount,
+                msol_amount,
+                pool_amount: total_yield.saturating_sub(org_amount),
+                timestamp: current_time,
+            },
+        )?;
 
-        // Execute yield distribution
-        self.unstake_msol(msol_to_unstake)?;
+        Ok(())
+    }
 
-        // Get pool vault balance after unstaking to see actual SOL received
-        let vault_balance_after = self.pool_vault.lamports();
-        let actual_sol_received = vault_balance_after.saturating_sub(vault_balance_before);
+    fn check_access(&self, current_time: u64) -> Result<()> {
+        // Only the crank can produce a pool vault signature (via invoke_signed)
+        if self.pool_vault.is_signer {
+            require!(self.pool.is_crank_scheduled, ErrorCode::UnauthorizedStream);
+            return Ok(());
+        }
 
+        let next_stream_ts = self
+            .pool
+            .last_stream_ts
+            .checked_add(self.pool.stream_cooldown)
+            .ok_or(ErrorCode::MathError)?;
+        require!(
+            current_time >= next_stream_ts,
+            ErrorCode::SettlementTooFrequent
+        );
+
+        Ok(())
+    }
+
+    /// Returns false while the mSOL price is below the pool's high-water mark
+    fn check_high_water_mark(
+        &mut self,
+        current_value: u64,
+        current_time: u64,
+        bumps: &StreamBumps,
+    ) -> Result<bool> {
+        let current_price = self.compute_msol_price()?;
+        let peak_price = self.pool.peak_msol_price;
+
+        if current_price < peak_price {
+            let shortfall = (self.pool_msol_account.amount as u128)
+                .checked_mul(peak_price - current_price)
+                .ok_or(ErrorCode::MathError)?
+                .checked_div(MSOL_PRICE_PRECISION)
+                .ok_or(ErrorCode::MathError)?;
+            let shortfall = u64::try_from(shortfall).map_err(|_| ErrorCode::MathError)?;
+
+            // Only new or deepening losses are recorded
+            if shortfall > self.pool.unrecovered_loss {
+                msg!("Loss recorded: {} lamports below peak", shortfall);
+
+                emit_cpi_event(
+                    &self.event_authority,
+                    bumps.event_authority,
+                    LossRecorded {
+                        version: EVENT_SCHEMA_VERSION,
+                        pool: self.pool.key(),
+                        peak_msol_price: peak_price,
+                        current_msol_price: current_price,
+                        peak_sol_value: self.pool.peak_sol_value,
+                        current_sol_value: current_value,
+                        shortfall,
+                        timestamp: current_time,
+                    },
+                )?;
+            }
+
+            self.pool.unrecovered_loss = shortfall;
+            return Ok(false);
+        }
+
+        if self.pool.unrecovered_loss > 0 {
+            msg!("Loss recovered, pool back at its high-water mark");
+
+            emit_cpi_event(
+                &self.event_authority,
+                bumps.event_authority,
+                LossRecovered {
+                    version: EVENT_SCHEMA_VERSION,
+                    pool: self.pool.key(),
+                    peak_msol_price: peak_price,
+                    current_sol_value: current_value,
+                    timestamp: current_time,
+                },
+            )?;
+
+            self.pool.unrecovered_loss = 0;
+        }
+
+        self.pool.peak_msol_price = current_price;
+        self.pool.peak_sol_value = current_value;
+
+        Ok(true)
+    }
+
+    fn skip_stream(
+        &self,
+        current_value: u64,
+        pending_yield: u64,
+        current_time: u64,
+        reason: StreamSkipReason,
+        bumps: &StreamBumps,
+    ) -> Result<()> {
         msg!(
-            "Actually received {} SOL from unstaking",
-            actual_sol_received
+            "Stream skipped: {} lamports pending, minimum is {}",
+            pending_yield,
+            MIN_YIELD_AMOUNT
         );
 
-        // Transfer the ACTUAL amount received, not the calculated amount
-        self.transfer_to_org(actual_sol_received)?;
-        self.update_checkpoint(current_sol_value, actual_sol_received)?;
+        emit_cpi_event(
+            &self.event_authority,
+            bumps.event_authority,
+            StreamSkipped {
+                version: EVENT_SCHEMA_VERSION,
+                pool: self.pool.key(),
+                organization: self.pool.organization_pubkey,
+                observed_sol_value: current_value,
+                pending_yield,
+                min_yield: MIN_YIELD_AMOUNT,
+                reason,
+                timestamp: current_time,
+            },
+        )?;
 
-        emit!(YieldStreamed {
-            pool: self.pool.key(),
-            organization: self.pool.organization_pubkey,
-            total_yield,
-            org_amount: actual_sol_received,
-            pool_amount: total_yield.saturating_sub(actual_sol_received),
-            timestamp: current_time,
-        });
+        Ok(())
+    }
+
+    /// Appends what this stream observed to the pool history, if passed
+    ///
+    /// Skipped within `MIN_SNAPSHOT_INTERVAL` of the latest snapshot, so
+    /// frequent streams cannot flush the history's older entries
+    fn record_snapshot(
+        &mut self,
+        current_value: u64,
+        current_time: u64,
+        bumps: &StreamBumps,
+    ) -> Result<()> {
+        let msol_price = self.compute_msol_price()?;
+
+        if let Some(history) = self.pool_history.as_mut() {
+            if let Some(latest) = history.latest() {
+                let next_snapshot_ts = latest
+                    .timestamp
+                    .checked_add(MIN_SNAPSHOT_INTERVAL)
+                    .ok_or(ErrorCode::MathError)?;
+                if current_time < next_snapshot_ts {
+                    return Ok(());
+                }
+            }
+
+            history.push(PoolSnapshot {
+                timestamp: current_time,
+                nav: current_value,
+                msol_price,
+                total_shares: self.pool.total_shares,
+            })?;
+
+            emit_cpi_event(
+                &self.event_authority,
+                bumps.event_authority,
+                PoolSnapshotRecorded {
+                    version: EVENT_SCHEMA_VERSION,
+                    pool: self.pool.key(),
+                    nav: current_value,
+                    msol_price,
+                    total_shares: self.pool.total_shares,
+                    trailing_apy_bps: history.trailing_apy_bps().unwrap_or_default(),
+                    timestamp: current_time,
+                },
+            )?;
+        }
 
         Ok(())
     }
@@ -143,45 +466,19 @@ impl<'info> Stream<'info> {
             return Ok(0);
         }
 
-        let data = self.marinade_state.try_borrow_data()?;
-        let msol_supply = u64::from_le_bytes(data[368..376].try_into().unwrap());
-        let total_virtual_staked = u64::from_le_bytes(data[376..384].try_into().unwrap());
-
-        let sol_value = (msol_balance as u128)
-            .checked_mul(total_virtual_staked as u128)
-            .ok_or(ErrorCode::MathError)?
-            .checked_div(msol_supply as u128)
-            .ok_or(ErrorCode::MathError)?;
+        MarinadeRates::load(&self.marinade_state)?.msol_to_sol(msol_balance)
+    }
 
-        u64::try_from(sol_value).map_err(|_| ErrorCode::MathError.into())
+    fn compute_msol_price(&self) -> Result<u128> {
+        MarinadeRates::load(&self.marinade_state)?.msol_price()
     }
 
     fn compute_yield(&self, current_value: u64) -> Result<u64> {
         Ok(current_value.saturating_sub(self.pool.last_streamed_vault_sol))
     }
 
-    fn calculate_org_yield(&self, total_yield: u64) -> Result<u64> {
-        let org_amount = (total_yield as u128)
-            .checked_mul(ORG_YIELD_BPS)
-            .ok_or(ErrorCode::MathError)?
-            .checked_div(10_000)
-            .ok_or(ErrorCode::MathError)?;
-
-        u64::try_from(org_amount).map_err(|_| ErrorCode::MathError.into())
-    }
-
     fn sol_to_msol(&self, sol_amount: u64) -> Result<u64> {
-        let data = self.marinade_state.try_borrow_data()?;
-        let msol_supply = u64::from_le_bytes(data[368..376].try_into().unwrap());
-        let total_virtual_staked = u64::from_le_bytes(data[376..384].try_into().unwrap());
-
-        let msol_amount = (sol_amount as u128)
-            .checked_mul(msol_supply as u128)
-            .ok_or(ErrorCode::MathError)?
-            .checked_div(total_virtual_staked as u128)
-            .ok_or(ErrorCode::MathError)?;
-
-        u64::try_from(msol_amount).map_err(|_| ErrorCode::MathError.into())
+        MarinadeRates::load(&self.marinade_state)?.sol_to_msol(sol_amount)
     }
 
     fn unstake_msol(&self, msol_amount: u64) -> Result<()> {
@@ -215,6 +512,131 @@ impl<'info> Stream<'info> {
         Ok(())
     }
 
+    /// Liquid-unstakes the org's mSOL and returns the lamports paid out
+    fn pay_org_in_sol(&self, msol_amount: u64) -> Result<u64> {
+        // Get pool vault balance before unstaking
+        let vault_balance_before = self.pool_vault.lamports();
+
+        // Execute yield distribution
+        self.unstake_msol(msol_amount)?;
+
+        // Get pool vault balance after unstaking to see actual SOL received
+        let vault_balance_after = self.pool_vault.lamports();
+        let actual_sol_received = vault_balance_after.saturating_sub(vault_balance_before);
+
+        msg!(
+            "Actually received {} SOL from unstaking",
+            actual_sol_received
+        );
+
+        // Transfer the ACTUAL amount received, not the calculated amount
+        self.transfer_to_org(actual_sol_received)?;
+
+        Ok(actual_sol_received)
+    }
+
+    fn pay_org_in_msol(&self, msol_amount: u64) -> Result<()> {
+        let organization_msol_account = self
+            .organization_msol_account
+            .as_ref()
+            .ok_or(ErrorCode::MissingOrganizationMsolAccount)?;
+
+        let seeds = &[
+            POOL_VAULT_SEED.as_bytes(),
+            self.pool.organization_pubkey.as_ref(),
+            &self.pool.new_species_id,
+            &[self.pool.pool_vault_bump],
+        ];
+        let signer_seeds = &[&seeds[..]];
+
+        token_transfer(
+            CpiContext::new_with_signer(
+                self.token_program.to_account_info(),
+                TokenTransfer {
+                    from: self.pool_msol_account.to_account_info(),
+                    to: organization_msol_account.to_account_info(),
+                    authority: self.pool_vault.to_account_info(),
+                },
+                signer_seeds,
+            ),
+            msol_amount,
+        )?;
+
+        msg!("Transferred {} mSOL to organization vault", msol_amount);
+
+        Ok(())
+    }
+
+    /// Books SOL paid out by `pay_org_in_sol` against its destination
+    fn account_org_sol(
+        &mut self,
+        amount: u64,
+        current_time: u64,
+        bumps: &StreamBumps,
+    ) -> Result<()> {
+        if self.pool.holds_org_yield() {
+            self.pool.held_balance = self
+                .pool
+                .held_balance
+                .checked_add(amount)
+                .ok_or(ErrorCode::MathError)?;
+
+            emit_cpi_event(
+                &self.event_authority,
+                bumps.event_authority,
+                YieldHeld {
+                    version: EVENT_SCHEMA_VERSION,
+                    pool: self.pool.key(),
+                    organization: self.pool.organization_pubkey,
+                    standing: self.pool.org_standing,
+                    amount,
+                    held_balance: self.pool.held_balance,
+                    timestamp: current_time,
+                },
+            )?;
+
+            return Ok(());
+        }
+
+        if self.pool.escrow_mode {
+            self.pool.escrow_balance = self
+                .pool
+                .escrow_balance
+                .checked_add(amount)
+                .ok_or(ErrorCode::MathError)?;
+            return Ok(());
+        }
+
+        self.start_org_accrual(amount, current_time, bumps)
+    }
+
+    fn start_org_accrual(
+        &mut self,
+        amount: u64,
+        current_time: u64,
+        bumps: &StreamBumps,
+    ) -> Result<()> {
+        let period = self.pool.stream_cooldown;
+        self.pool.start_org_accrual(amount, current_time, period)?;
+
+        emit_cpi_event(
+            &self.event_authority,
+            bumps.event_authority,
+            OrganizationAccrualStarted {
+                version: EVENT_SCHEMA_VERSION,
+                pool: self.pool.key(),
+                organization: self.pool.organization_pubkey,
+                unvested: self.pool.org_unvested,
+                rate: self.pool.org_accrual_rate,
+                period_end: self.pool.org_accrual_end_ts,
+                timestamp: current_time,
+            },
+        )?;
+
+        Ok(())
+    }
+
+    /// Pays the organization vault, or the escrow vault in escrow mode
     fn transfer_to_org(&self, amount: u64) -> Result<()> {
         let seeds = &[
             POOL_VAULT_SEED.as_bytes(),
@@ -229,7 +651,11 @@ impl<'info> Stream<'info> {
                 self.system_program.to_account_info(),
                 Transfer {
                     from: self.pool_vault.to_account_info(),
-                    to: self.organization_vault.to_account_info(),
+                    to: if self.pool.pays_into_escrow() {
+                        self.escrow_vault.to_account_info()
+                    } else {
+                        self.organization_vault.to_account_info()
+                    },
                 },
                 signer_seeds,
             ),
@@ -237,11 +663,138 @@ impl<'info> Stream<'info> {
         )
     }
 
+    /// Adds the org's share, plus anything parked by crank runs, to the
+    /// protocol totals when the stats account is passed
+    fn report_yield(&mut self, org_yield: u64, current_time: u64) -> Result<()> {
+        let unreported = self
+            .pool
+            .unreported_yield
+            .checked_add(org_yield)
+            .ok_or(ErrorCode::MathError)?;
+
+        match self.protocol_stats.as_mut() {
+            Some(stats) => {
+                stats.total_yield_streamed = stats
+                    .total_yield_streamed
+                    .checked_add(unreported)
+                    .ok_or(ErrorCode::MathError)?;
+                stats.updated_at = current_time;
+                self.pool.unreported_yield = 0;
+            }
+            None => self.pool.unreported_yield = unreported,
+        }
+
+        Ok(())
+    }
+
+    fn write_stream_record(&mut self, record: StreamRecord) -> Result<()> {
+        let (Some(stream_record), Some(payer)) = (&self.stream_record, &self.payer) else {
+            return Ok(());
+        };
+
+        let pool_key = self.pool.key();
+        let sequence_bytes = record.sequence.to_le_bytes();
+        let record_seeds: &[&[u8]] = &[
+            STREAM_RECORD_SEED.as_bytes(),
+            pool_key.as_ref(),
+            &sequence_bytes,
+            &[record.bump],
+        ];
+        let signer_seeds = &[record_seeds];
+
+        let space = 8 + StreamRecord::INIT_SPACE;
+        let rent = Rent::get()?.minimum_balance(space);
+        let current_lamports = stream_record.lamports();
+
+        // Tolerate lamports sent to the PDA ahead of time
+        if current_lamports == 0 {
+            create_account(
+                CpiContext::new_with_signer(
+                    self.system_program.to_account_info(),
+                    CreateAccount {
+                        from: payer.to_account_info(),
+                        to: stream_record.to_account_info(),
+                    },
+                    signer_seeds,
+                ),
+                rent,
+                space as u64,
+                &crate::ID,
+            )?;
+        } else {
+            let top_up = rent.saturating_sub(current_lamports);
+            if top_up > 0 {
+                transfer(
+                    CpiContext::new(
+                        self.system_program.to_account_info(),
+                        Transfer {
+                            from: payer.to_account_info(),
+                            to: stream_record.to_account_info(),
+                        },
+                    ),
+                    top_up,
+                )?;
+            }
+            allocate(
+                CpiContext::new_with_signer(
+                    self.system_program.to_account_info(),
+                    Allocate {
+                        account_to_allocate: stream_record.to_account_info(),
+                    },
+                    signer_seeds,
+                ),
+                space as u64,
+            )?;
+            assign(
+                CpiContext::new_with_signer(
+                    self.system_program.to_account_info(),
+                    Assign {
+                        account_to_assign: stream_record.to_account_info(),
+                    },
+                    signer_seeds,
+                ),
+                &crate::ID,
+            )?;
+        }
+
+        let mut data = stream_record.try_borrow_mut_data()?;
+        record.try_serialize(&mut &mut data[..])?;
+
+        msg!(
+            "Stream record #{}: {}",
+            record.sequence,
+            stream_record.key()
+        );
+
+        // Only written records take a sequence number
+        self.pool.stream_count = self
+            .pool
+            .stream_count
+            .checked_add(1)
+            .ok_or(ErrorCode::MathError)?;
+
+        Ok(())
+    }
+
     fn update_checkpoint(&mut self, current_value: u64, org_cut: u64) -> Result<()> {
         self.pool.last_streamed_vault_sol = current_value
             .checked_sub(org_cut)
             .ok_or(ErrorCode::MathError)?;
         self.pool.last_stream_ts = Clock::get()?.unix_timestamp as u64;
+
+        // Attribute the org cut to current shareholders for impact tracking
+        if self.pool.total_shares > 0 {
+            let per_share = (org_cut as u128)
+                .checked_mul(YIELD_PER_SHARE_PRECISION)
+                .ok_or(ErrorCode::MathError)?
+                .checked_div(self.pool.total_shares as u128)
+                .ok_or(ErrorCode::MathError)?;
+            self.pool.donated_yield_per_share = self
+                .pool
+                .donated_yield_per_share
+                .checked_add(per_share)
+                .ok_or(ErrorCode::MathError)?;
+        }
         Ok(())
     }
 }
@@ -2,14 +2,18 @@ use anchor_lang::prelude::*;
 use anchor_lang::system_program::{transfer, Transfer};
 use anchor_spl::token::{burn, Burn, Mint, Token, TokenAccount};
 
-use crate::constants::MARINADE_PROGRAM_ID;
-use crate::marinade::marinade_liquid_unstake;
+use crate::constants::{LIQ_POOL_SOL_LEG, MARINADE_PROGRAM_ID, MARINADE_STATE};
+use crate::marinade::{marinade_liquid_unstake, LiquidUnstakeQuote, MarinadeRates};
 use crate::{
-    constants::{POOL_MINT_SEED, POOL_SEED, POOL_VAULT_SEED},
+    constants::{
+        EVENT_SCHEMA_VERSION, POOL_MINT_SEED, POOL_SEED, POOL_VAULT_SEED, PROTOCOL_STATS_SEED,
+        SUPPORTER_POSITION_SEED,
+    },
     errors::ErrorCode,
     events::SupporterWithdrew,
     marinade::LiquidUnstakeAccounts,
-    states::Pool,
+    states::{Pool, ProtocolStats, SupporterPosition},
+    utilities::emit_cpi_event,
 };
 
 /// Allows a supporter to exit the pool by burning their share tokens.
@@ -19,7 +23,12 @@ use crate::{
 /// organization via a separate automated Crank, any mSOL currently in the
 /// pool vault represents the original principal plus the 80% "Supporter Share"
 /// of accumulated yield.
+///
+/// The mSOL leaves through Marinade's liquid unstake, which charges a fee
+/// that rises as the liquidity pool's SOL leg drains. `min_sol_out` bounds
+/// the SOL the supporter accepts after that fee.
 
+#[event_cpi]
 #[derive(Accounts)]
 pub struct SupporterWithdraw<'info> {
     #[account(mut)]
@@ -57,17 +66,39 @@ pub struct SupporterWithdraw<'info> {
     )]
     pub supporter_pool_token_account: Account<'info, TokenAccount>,
 
+    /// Tracks the supporter's shares and lifetime donated yield
+    /// Created here for receipt holders that predate position tracking
+    #[account(
+        init_if_needed,
+        payer = supporter,
+        space = 8 + SupporterPosition::INIT_SPACE,
+        seeds = [
+            SUPPORTER_POSITION_SEED.as_bytes(),
+            pool.key().as_ref(),
+            supporter.key().as_ref(),
+        ],
+        bump,
+    )]
+    pub supporter_position: Account<'info, SupporterPosition>,
+
+    #[account(
+        mut,
+        seeds = [PROTOCOL_STATS_SEED.as_bytes()],
+        bump = protocol_stats.bump,
+    )]
+    pub protocol_stats: Account<'info, ProtocolStats>,
+
     /// Marinade state account
-    /// CHECK: Verified by Marinade program
-    #[account(mut)]
+    /// CHECK: Pinned to Marinade's state, rates are read from it
+    #[account(mut, address = MARINADE_STATE)]
     pub marinade_state: AccountInfo<'info>,
 
     #[account(mut)]
     pub msol_mint: Account<'info, Mint>,
 
-    /// Vault to receive SOL from unstake
-    /// CHECK: PDA derived and validated by Marinade
-    #[account(mut)]
+    /// Liquidity pool SOL leg, its balance sets the unstake fee
+    /// CHECK: Pinned to Marinade's SOL leg
+    #[account(mut, address = LIQ_POOL_SOL_LEG)]
     pub liq_pool_sol_leg: AccountInfo<'info>,
 
     /// CHECK: Owned and validated by Marinade
@@ -110,7 +141,12 @@ pub struct SupporterWithdraw<'info> {
 }
 
 impl<'info> SupporterWithdraw<'info> {
-    pub fn process(&mut self, share_amount: u64) -> Result<()> {
+    pub fn process(
+        &mut self,
+        share_amount: u64,
+        min_sol_out: u64,
+        bumps: &SupporterWithdrawBumps,
+    ) -> Result<()> {
         // Validation
         require!(share_amount > 0, ErrorCode::InvalidAmount);
         require!(
@@ -119,49 +155,63 @@ impl<'info> SupporterWithdraw<'info> {
         );
 
         // Calculate how much of the pool's mSOL this share amount represents
-        let (msol_to_unstake, sol_estimated) = self.calculate_withdrawal_amounts(share_amount)?;
+        let (msol_to_unstake, unstake) = self.calculate_withdrawal_amounts(share_amount)?;
+
+        // Fail before touching Marinade if the fee alone breaks the bound
+        require!(unstake.sol_out >= min_sol_out, ErrorCode::SlippageExceeded);
 
         // Update State
-        self.update_pool_state(share_amount, sol_estimated)?;
+        self.update_pool_state(share_amount)?;
+        self.update_position(share_amount, bumps)?;
 
         // Unstake mSOL to the Vault
         // Note: Marinade liquid_unstake takes msol_amount, not sol_amount
+        let vault_lamports_before = self.pool_vault.lamports();
         self.unstake_msol(msol_to_unstake)?;
+        let sol_received = self
+            .pool_vault
+            .lamports()
+            .checked_sub(vault_lamports_before)
+            .ok_or(ErrorCode::MathError)?;
+        require!(sol_received >= min_sol_out, ErrorCode::SlippageExceeded);
 
         // Transfer the resulting SOL to Supporter
-        self.transfer_sol_to_supporter(sol_estimated)?;
+        self.transfer_sol_to_supporter(sol_received)?;
 
         // Burn the Share Tokens
         self.burn_share_tokens(share_amount)?;
 
-        emit!(SupporterWithdrew {
-            supporter: self.supporter.key(),
-            pool: self.pool.key(),
-            share_amount,
-            msol_amount: msol_to_unstake,
-            sol_amount: sol_estimated,
-            timestamp: Clock::get()?.unix_timestamp as u64,
-        });
+        emit_cpi_event(
+            &self.event_authority,
+            bumps.event_authority,
+            SupporterWithdrew {
+                version: EVENT_SCHEMA_VERSION,
+                supporter: self.supporter.key(),
+                pool: self.pool.key(),
+                share_amount,
+                msol_amount: msol_to_unstake,
+                sol_amount: sol_received,
+                unstake_fee_bps: unstake.fee_bps,
+                timestamp: Clock::get()?.unix_timestamp as u64,
+            },
+        )?;
 
         Ok(())
     }
 
-    fn calculate_withdrawal_amounts(&self, share_amount: u64) -> Result<(u64, u64)> {
-        let total_shares = self.pool_mint.supply;
-        let total_msol = self.pool_msol_account.amount;
+    fn calculate_withdrawal_amounts(&self, share_amount: u64) -> Result<(u64, LiquidUnstakeQuote)> {
+        let msol_to_unstake = Pool::msol_for_shares(
+            share_amount,
+            self.pool_mint.supply,
+            self.pool_msol_account.amount,
+        )?;
 
-        require!(total_shares > 0, ErrorCode::PoolEmpty);
+        let rates = MarinadeRates::load(&self.marinade_state)?;
+        require!(rates.msol_supply > 0, ErrorCode::MathError);
 
-        let msol_to_unstake = (share_amount as u128)
-            .checked_mul(total_msol as u128)
-            .ok_or(ErrorCode::MathError)?
-            .checked_div(total_shares as u128)
-            .ok_or(ErrorCode::MathError)?;
+        let unstake = rates.liquid_unstake(msol_to_unstake, self.liq_pool_sol_leg.lamports())?;
 
-        let msol_u64 = u64::try_from(msol_to_unstake).map_err(|_| ErrorCode::MathError)?;
-        let sol_estimated = self.msol_to_sol(msol_u64)?;
-
-        Ok((msol_u64, sol_estimated))
+        Ok((msol_to_unstake, unstake))
     }
 
     fn unstake_msol(&self, msol_amount: u64) -> Result<()> {
@@ -193,7 +243,7 @@ impl<'info> SupporterWithdraw<'info> {
         )
     }
 
-    fn transfer_sol_to_supporter(&self, _amount: u64) -> Result<()> {
+    fn transfer_sol_to_supporter(&self, amount: u64) -> Result<()> {
         let pool = &self.pool;
         let seeds = &[
             POOL_VAULT_SEED.as_bytes(),
@@ -203,10 +253,6 @@ impl<'info> SupporterWithdraw<'info> {
         ];
         let signer_seeds = &[&seeds[..]];
 
-        // FIX: Transfer the actual SOL currently in the vault
-        // (which is what just arrived from Marinade)
-        let amount_to_transfer = self.pool_vault.lamports();
-
         transfer(
             CpiContext::new_with_signer(
                 self.system_program.to_account_info(),
@@ -216,7 +262,7 @@ impl<'info> SupporterWithdraw<'info> {
                 },
                 signer_seeds,
             ),
-            amount_to_transfer,
+            amount,
         )
     }
 
@@ -234,38 +280,69 @@ impl<'info> SupporterWithdraw<'info> {
         )
     }
 
-    fn msol_to_sol(&self, msol_amount: u64) -> Result<u64> {
-        let data = self.marinade_state.try_borrow_data()?;
-
-        // Marinade State Field Offsets (Standard for MarBms... program):
-        // msol_supply is at byte 368
-        // total_virtual_staked_lamports is at byte 376
+    fn update_position(
+        &mut self,
+        shares_burned: u64,
+        bumps: &SupporterWithdrawBumps,
+    ) -> Result<()> {
+        let position = &mut self.supporter_position;
+        if position.supporter == Pubkey::default() {
+            position.pool = self.pool.key();
+            position.supporter = self.supporter.key();
+            position.donated_yield_checkpoint = self.pool.donated_yield_per_share;
+            position.bump = bumps.supporter_position;
+        }
+        // Not reloaded since the burn, so this is the balance that earned the yield
+        let held_shares = self.supporter_pool_token_account.amount;
+        position.settle(self.pool.donated_yield_per_share, held_shares)?;
+
+        let had_shares = position.shares > 0;
+        position.shares = position.shares.saturating_sub(shares_burned);
+
+        if had_shares && position.shares == 0 {
+            self.protocol_stats.supporter_count = self
+                .protocol_stats
+                .supporter_count
+                .checked_sub(1)
+                .ok_or(ErrorCode::MathError)?;
+        }
+        Ok(())
+    }
 
-        let msol_supply = u64::from_le_bytes(
-            data[368..376]
-                .try_into()
-                .map_err(|_| ErrorCode::MathError)?,
-        );
-        let total_virtual_staked_lamports = u64::from_le_bytes(
-            data[376..384]
-                .try_into()
-                .map_err(|_| ErrorCode::MathError)?,
-        );
+    fn update_pool_state(&mut self, shares_burned: u64) -> Result<()> {
+        // Principal leaves in proportion to the shares burned; the yield
+        // paid on top was never part of the deposit totals
+        require!(self.pool.total_shares > 0, ErrorCode::PoolEmpty);
+        let principal = (self.pool.total_deposits as u128)
+            .checked_mul(shares_burned as u128)
+            .ok_or(ErrorCode::MathError)?
+            .checked_div(self.pool.total_shares as u128)
+            .ok_or(ErrorCode::MathError)?;
+        let principal = u64::try_from(principal).map_err(|_| ErrorCode::MathError)?;
 
-        require!(msol_supply > 0, ErrorCode::MathError);
+        self.pool.total_deposits = self
+            .pool
+            .total_deposits
+            .checked_sub(principal)
+            .ok_or(ErrorCode::MathError)?;
+        self.pool.total_shares = self
+            .pool
+            .total_shares
+            .checked_sub(shares_burned)
+            .ok_or(ErrorCode::MathError)?;
 
-        let sol_value = (msol_amount as u128)
-            .checked_mul(total_virtual_staked_lamports as u128)
-            .ok_or(ErrorCode::MathError)?
-            .checked_div(msol_supply as u128)
+        let current_time = Clock::get()?.unix_timestamp as u64;
+        let stats = &mut self.protocol_stats;
+        stats.total_value_locked = stats
+            .total_value_locked
+            .checked_sub(principal)
             .ok_or(ErrorCode::MathError)?;
+        stats.updated_at = current_time;
 
-        u64::try_from(sol_value).map_err(|_| ErrorCode::MathError.into())
-    }
+        // Crank runs can't write the stats from the rollup
+        self.protocol_stats
+            .flush_unreported_yield(&mut self.pool, current_time)?;
 
-    fn update_pool_state(&mut self, shares_burned: u64, sol_withdrawn: u64) -> Result<()> {
-        self.pool.total_deposits = self.pool.total_deposits.saturating_sub(sol_withdrawn);
-        self.pool.total_shares = self.pool.total_shares.saturating_sub(shares_burned);
         Ok(())
     }
 }
@@ -0,0 +1,118 @@
+use crate::constants::{
+    ADMIN_PUBKEY, EVENT_SCHEMA_VERSION, POOL_SEED, REGISTRY_PAGE_SEED, SUSPENSION_REVIEW_WINDOW,
+};
+use crate::errors::ErrorCode;
+use crate::events::OrganizationSuspended;
+use crate::states::{OrganizationStanding, Pool, RegistryIndex, RegistryPage};
+use crate::utilities::emit_cpi_event;
+use anchor_lang::prelude::*;
+
+/// Suspends a pool's organization, e.g. after it loses accreditation
+///
+/// Streams keep running but the organization's share is held in the
+/// escrow vault. After `SUSPENSION_REVIEW_WINDOW` the admin either
+/// reinstates the organization or reassigns the pool to a fallback.
+/// The pool's registry listings are marked suspended in the same step
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct SuspendOrganization<'info> {
+    #[account(
+        address = ADMIN_PUBKEY @ ErrorCode::AdminUnauthorized
+    )]
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            POOL_SEED.as_bytes(),
+            pool.organization_pubkey.as_ref(),
+            &pool.new_species_id
+        ],
+        bump = pool.pool_bump,
+        constraint = pool.org_standing == OrganizationStanding::Active
+            @ ErrorCode::OrganizationNotActive,
+    )]
+    pub pool: Account<'info, Pool>,
+
+    /// Global index page listing the pool
+    #[account(
+        mut,
+        seeds = [
+            REGISTRY_PAGE_SEED.as_bytes(),
+            registry_page.index_key.as_ref(),
+            &registry_page.page.to_le_bytes(),
+        ],
+        bump = registry_page.bump,
+        constraint = registry_page.index == RegistryIndex::All @ ErrorCode::PoolNotListed,
+    )]
+    pub registry_page: Box<Account<'info, RegistryPage>>,
+
+    /// Species index page listing the pool
+    #[account(
+        mut,
+        seeds = [
+            REGISTRY_PAGE_SEED.as_bytes(),
+            species_page.index_key.as_ref(),
+            &species_page.page.to_le_bytes(),
+        ],
+        bump = species_page.bump,
+        constraint = species_page.index == RegistryIndex::Species @ ErrorCode::PoolNotListed,
+    )]
+    pub species_page: Box<Account<'info, RegistryPage>>,
+
+    /// Organization index page listing the pool
+    #[account(
+        mut,
+        seeds = [
+            REGISTRY_PAGE_SEED.as_bytes(),
+            organization_page.index_key.as_ref(),
+            &organization_page.page.to_le_bytes(),
+        ],
+        bump = organization_page.bump,
+        constraint = organization_page.index == RegistryIndex::Organization
+            @ ErrorCode::PoolNotListed,
+    )]
+    pub organization_page: Box<Account<'info, RegistryPage>>,
+}
+impl<'info> SuspendOrganization<'info> {
+    pub fn process(&mut self, bumps: &SuspendOrganizationBumps) -> Result<()> {
+        let current_time = Clock::get()?.unix_timestamp as u64;
+
+        self.pool.org_standing = OrganizationStanding::Suspended;
+        self.pool.suspended_at = current_time;
+        self.update_listings()?;
+
+        let pool = &self.pool;
+
+        emit_cpi_event(
+            &self.event_authority,
+            bumps.event_authority,
+            OrganizationSuspended {
+                version: EVENT_SCHEMA_VERSION,
+                pool: pool.key(),
+                organization: pool.organization_pubkey,
+                review_ends_at: current_time
+                    .checked_add(SUSPENSION_REVIEW_WINDOW)
+                    .ok_or(ErrorCode::MathError)?,
+                timestamp: current_time,
+            },
+        )?;
+
+        msg!("Organization suspended for pool {}", pool.key());
+
+        Ok(())
+    }
+
+    /// Brings the pool's listing on every index up to its new standing
+    fn update_listings(&mut self) -> Result<()> {
+        let pool_key = self.pool.key();
+        let status = self.pool.listing_status();
+
+        self.registry_page.set_status(pool_key, status)?;
+        self.species_page.set_status(pool_key, status)?;
+        self.organization_page.set_status(pool_key, status)?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,91 @@
+use crate::constants::{ESCROW_VAULT_SEED, EVENT_SCHEMA_VERSION, POOL_SEED};
+use crate::errors::ErrorCode;
+use crate::events::HeldFundsSwept;
+use crate::states::{OrganizationStanding, Pool};
+use crate::utilities::emit_cpi_event;
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{transfer, Transfer};
+
+/// Pays streams held for a reassigned pool to its fallback beneficiary
+///
+/// Permissionless, so held funds keep flowing to the fallback without
+/// admin involvement after every stream
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct SweepHeldFunds<'info> {
+    #[account(
+        mut,
+        seeds = [
+            POOL_SEED.as_bytes(),
+            pool.organization_pubkey.as_ref(),
+            &pool.new_species_id
+        ],
+        bump = pool.pool_bump,
+        constraint = pool.org_standing == OrganizationStanding::Reassigned
+            @ ErrorCode::PoolNotReassigned,
+    )]
+    pub pool: Account<'info, Pool>,
+
+    #[account(
+        mut,
+        seeds = [
+            ESCROW_VAULT_SEED.as_bytes(),
+            pool.organization_pubkey.as_ref(),
+            &pool.new_species_id
+        ],
+        bump = pool.escrow_vault_bump,
+    )]
+    pub escrow_vault: SystemAccount<'info>,
+
+    #[account(
+        mut,
+        address = pool.reassigned_to @ ErrorCode::FallbackBeneficiaryNotSet
+    )]
+    pub fallback_beneficiary: SystemAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+impl<'info> SweepHeldFunds<'info> {
+    pub fn process(&mut self, bumps: &SweepHeldFundsBumps) -> Result<()> {
+        let amount = self.pool.held_balance;
+        require!(amount > 0, ErrorCode::InvalidAmount);
+
+        let pool = &self.pool;
+        let seeds = &[
+            ESCROW_VAULT_SEED.as_bytes(),
+            pool.organization_pubkey.as_ref(),
+            &pool.new_species_id,
+            &[pool.escrow_vault_bump],
+        ];
+        let signer_seeds = &[&seeds[..]];
+
+        transfer(
+            CpiContext::new_with_signer(
+                self.system_program.to_account_info(),
+                Transfer {
+                    from: self.escrow_vault.to_account_info(),
+                    to: self.fallback_beneficiary.to_account_info(),
+                },
+                signer_seeds,
+            ),
+            amount,
+        )?;
+
+        self.pool.held_balance = 0;
+
+        emit_cpi_event(
+            &self.event_authority,
+            bumps.event_authority,
+            HeldFundsSwept {
+                version: EVENT_SCHEMA_VERSION,
+                pool: self.pool.key(),
+                fallback_beneficiary: self.fallback_beneficiary.key(),
+                amount,
+                timestamp: Clock::get()?.unix_timestamp as u64,
+            },
+        )?;
+
+        Ok(())
+    }
+}
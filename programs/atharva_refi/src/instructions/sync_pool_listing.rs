@@ -0,0 +1,58 @@
+use crate::constants::{EVENT_SCHEMA_VERSION, POOL_SEED, REGISTRY_PAGE_SEED};
+use crate::events::PoolListingSynced;
+use crate::states::{Pool, RegistryPage};
+use crate::utilities::emit_cpi_event;
+use anchor_lang::prelude::*;
+
+/// Refreshes a pool's status on one of its registry pages
+///
+/// Permissionless: the status is read from the pool itself, so anyone
+/// may bring a page up to date after a suspension, reinstatement or
+/// reassignment
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct SyncPoolListing<'info> {
+    #[account(
+        seeds = [
+            POOL_SEED.as_bytes(),
+            pool.organization_pubkey.as_ref(),
+            &pool.new_species_id
+        ],
+        bump = pool.pool_bump,
+    )]
+    pub pool: Account<'info, Pool>,
+
+    #[account(
+        mut,
+        seeds = [
+            REGISTRY_PAGE_SEED.as_bytes(),
+            registry_page.index_key.as_ref(),
+            &registry_page.page.to_le_bytes(),
+        ],
+        bump = registry_page.bump,
+    )]
+    pub registry_page: Account<'info, RegistryPage>,
+}
+impl<'info> SyncPoolListing<'info> {
+    pub fn process(&mut self, bumps: &SyncPoolListingBumps) -> Result<()> {
+        let pool_key = self.pool.key();
+        let status = self.pool.listing_status();
+
+        self.registry_page.set_status(pool_key, status)?;
+
+        emit_cpi_event(
+            &self.event_authority,
+            bumps.event_authority,
+            PoolListingSynced {
+                version: EVENT_SCHEMA_VERSION,
+                pool: pool_key,
+                registry_page: self.registry_page.key(),
+                status,
+                timestamp: Clock::get()?.unix_timestamp as u64,
+            },
+        )?;
+
+        Ok(())
+    }
+}
@@ -1,9 +1,14 @@
 use anchor_lang::prelude::*;
 use ephemeral_rollups_sdk::{anchor::commit, ephem::commit_and_undelegate_accounts};
 
-use crate::{constants::POOL_SEED, states::Pool};
+use crate::{
+    constants::{EVENT_SCHEMA_VERSION, POOL_SEED},
+    events::PoolUndelegated,
+    states::Pool,
+};
 
 #[commit]
+#[event_cpi]
 #[derive(Accounts)]
 pub struct UndelegatePool<'info> {
     #[account(mut)]
@@ -27,5 +32,13 @@ pub fn undelegate_process(ctx: Context<UndelegatePool>) -> Result<()> {
         &ctx.accounts.magic_context,
         &ctx.accounts.magic_program,
     )?;
+
+    emit_cpi!(PoolUndelegated {
+        version: EVENT_SCHEMA_VERSION,
+        pool: ctx.accounts.pool.key(),
+        undelegated_by: ctx.accounts.payer.key(),
+        timestamp: Clock::get()?.unix_timestamp as u64,
+    });
+
     Ok(())
 }
@@ -1,11 +1,15 @@
 use crate::{
-    constants::{MARINADE_PROGRAM_ID, POOL_SEED, POOL_VAULT_SEED},
+    constants::{EVENT_SCHEMA_VERSION, MARINADE_PROGRAM_ID, POOL_SEED, POOL_VAULT_SEED},
+    errors::ErrorCode,
+    events::PoolUnstaked,
     marinade::{marinade_liquid_unstake, LiquidUnstakeAccounts},
     states::Pool,
+    utilities::emit_cpi_event,
 };
 use anchor_lang::prelude::*;
 use anchor_spl::token::{Mint, Token};
 
+#[event_cpi]
 #[derive(Accounts)]
 pub struct Unstake<'info> {
     #[account(
@@ -73,9 +77,11 @@ pub struct Unstake<'info> {
 impl<'info> Unstake<'info> {
     /// Unstake mSOL to receive SOL
     /// Manually constructs the Marinade liquid_unstake CPI
-    pub fn process(&self, msol_amount: u64) -> Result<()> {
+    pub fn process(&self, msol_amount: u64, bumps: &UnstakeBumps) -> Result<()> {
         msg!("Unstaking {} mSOL from Marinade...", msol_amount);
 
+        let vault_lamports_before = self.pool_vault.lamports();
+
         let pool = &self.pool;
 
         let seeds = &[
@@ -103,6 +109,24 @@ impl<'info> Unstake<'info> {
             Some(signer_seeds),
         )?;
 
+        let sol_amount = self
+            .pool_vault
+            .lamports()
+            .checked_sub(vault_lamports_before)
+            .ok_or(ErrorCode::MathError)?;
+
+        emit_cpi_event(
+            &self.event_authority,
+            bumps.event_authority,
+            PoolUnstaked {
+                version: EVENT_SCHEMA_VERSION,
+                pool: self.pool.key(),
+                msol_amount,
+                sol_amount,
+                timestamp: Clock::get()?.unix_timestamp as u64,
+            },
+        )?;
+
         msg!("Successfully unstaked {} mSOL for SOL", msol_amount);
 
         Ok(())
@@ -0,0 +1,94 @@
+use crate::constants::{ADMIN_PUBKEY, EVENT_SCHEMA_VERSION, POOL_SEED, QUEUED_WITHDRAWAL_SEED};
+use crate::errors::ErrorCode;
+use crate::events::QueuedWithdrawalVetoed;
+use crate::states::{PayoutAsset, Pool, QueuedWithdrawal};
+use crate::utilities::emit_cpi_event;
+use anchor_lang::prelude::*;
+
+/// Admin veto of a queued organization withdrawal during its delay
+/// window. The funds stay in the organization vault
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct VetoQueuedWithdrawal<'info> {
+    #[account(
+        address = ADMIN_PUBKEY @ ErrorCode::AdminUnauthorized
+    )]
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            POOL_SEED.as_bytes(),
+            pool.organization_pubkey.as_ref(),
+            &pool.new_species_id,
+        ],
+        bump = pool.pool_bump,
+    )]
+    pub pool: Account<'info, Pool>,
+
+    /// Refunded the queued withdrawal's rent
+    #[account(mut)]
+    pub recipient: SystemAccount<'info>,
+
+    #[account(
+        mut,
+        close = recipient,
+        seeds = [
+            QUEUED_WITHDRAWAL_SEED.as_bytes(),
+            pool.key().as_ref(),
+            &queued_withdrawal.sequence.to_le_bytes(),
+        ],
+        bump = queued_withdrawal.bump,
+        has_one = pool,
+        has_one = recipient,
+    )]
+    pub queued_withdrawal: Account<'info, QueuedWithdrawal>,
+}
+impl<'info> VetoQueuedWithdrawal<'info> {
+    pub fn process(&mut self, bumps: &VetoQueuedWithdrawalBumps) -> Result<()> {
+        let current_time = Clock::get()?.unix_timestamp as u64;
+        let amount = self.queued_withdrawal.amount;
+        let asset = self.queued_withdrawal.asset;
+
+        require!(
+            current_time < self.queued_withdrawal.release_ts,
+            ErrorCode::VetoWindowClosed
+        );
+
+        let pool = &mut self.pool;
+        match asset {
+            PayoutAsset::Sol => {
+                pool.queued_withdraw_total = pool
+                    .queued_withdraw_total
+                    .checked_sub(amount)
+                    .ok_or(ErrorCode::MathError)?;
+            }
+            PayoutAsset::Msol => {
+                pool.queued_msol_total = pool
+                    .queued_msol_total
+                    .checked_sub(amount)
+                    .ok_or(ErrorCode::MathError)?;
+            }
+        }
+
+        emit_cpi_event(
+            &self.event_authority,
+            bumps.event_authority,
+            QueuedWithdrawalVetoed {
+                version: EVENT_SCHEMA_VERSION,
+                pool: self.pool.key(),
+                queued_withdrawal: self.queued_withdrawal.key(),
+                recipient: self.recipient.key(),
+                amount,
+                asset,
+                vetoed_by: self.admin.key(),
+                timestamp: current_time,
+            },
+        )?;
+
+        msg!("Queued withdrawal vetoed: {}", self.queued_withdrawal.key());
+
+        Ok(())
+    }
+}
@@ -2,16 +2,21 @@
 use anchor_lang::prelude::*;
 use ephemeral_rollups_sdk::anchor::ephemeral;
 
-mod constants;
-mod errors;
-mod events;
+pub mod constants;
+pub mod errors;
+pub mod events;
 mod instructions;
+mod magicblock;
 mod marinade;
-mod states;
+pub mod states;
 mod utilities;
 
+use constants::BADGE_TIERS;
 use instructions::*;
-use states::ScheduleStreamArgs;
+use states::{
+    DepositQuote, IucnCategory, MetricKind, PayoutAsset, PendingOrgYield, PoolNav, PositionValue,
+    PublishFieldReportArgs, ScheduleStreamArgs, SpendingCategory, WithdrawQuote,
+};
 
 declare_id!("5MQdy7SUtMR5qQqryuizd7WXKE18RRn7sNS4uX64ih96");
 
@@ -21,41 +26,145 @@ pub mod atharva_refi {
 
     use super::*;
 
-    pub fn create_pool(
-        ctx: Context<CreatePool>,
-        organization_name: String,
+    pub fn register_organization(
+        ctx: Context<RegisterOrganization>,
         organization_pubkey: Pubkey,
-        species_name: String,
-        species_id: [u8; 32],
+        name: String,
+        metadata_uri: String,
+    ) -> Result<()> {
+        ctx.accounts
+            .process(organization_pubkey, name, metadata_uri, &ctx.bumps)
+    }
+    pub fn apply_organization(
+        ctx: Context<ApplyOrganization>,
+        name: String,
+        metadata_uri: String,
+        document_hash: [u8; 32],
+        species_ids: Vec<[u8; 32]>,
+    ) -> Result<()> {
+        ctx.accounts
+            .process(name, metadata_uri, document_hash, species_ids, &ctx.bumps)
+    }
+    pub fn approve_organization(ctx: Context<ReviewOrganization>) -> Result<()> {
+        ctx.accounts.approve(&ctx.bumps)
+    }
+    pub fn reject_organization(ctx: Context<ReviewOrganization>, reason_code: u16) -> Result<()> {
+        ctx.accounts.reject(reason_code, &ctx.bumps)
+    }
+    pub fn register_species(
+        ctx: Context<RegisterSpecies>,
+        scientific_name: String,
+        common_name: String,
+        iucn_category: IucnCategory,
+        region: String,
+        metadata_uri: String,
     ) -> Result<()> {
         ctx.accounts.process(
-            organization_name,
-            organization_pubkey,
-            species_name,
-            species_id,
+            scientific_name,
+            common_name,
+            iucn_category,
+            region,
+            metadata_uri,
             &ctx.bumps,
         )
     }
+    pub fn create_pool(ctx: Context<CreatePool>) -> Result<()> {
+        ctx.accounts.process(&ctx.bumps)
+    }
+    pub fn sync_pool_listing(ctx: Context<SyncPoolListing>) -> Result<()> {
+        ctx.accounts.process(&ctx.bumps)
+    }
     pub fn deposit(ctx: Context<Deposit>, amount: u64) -> Result<()> {
-        ctx.accounts.process(amount)
+        ctx.accounts.process(amount, &ctx.bumps)
     }
     pub fn stake(ctx: Context<Stake>, amount: u64) -> Result<()> {
-        ctx.accounts.process(amount)
+        ctx.accounts.process(amount, &ctx.bumps)
     }
     pub fn stream(ctx: Context<Stream>) -> Result<()> {
-        ctx.accounts.process()
+        ctx.accounts.process(&ctx.bumps)
+    }
+    pub fn snapshot(ctx: Context<Snapshot>) -> Result<()> {
+        ctx.accounts.process(&ctx.bumps)
     }
     pub fn schedule_streams(ctx: Context<ScheduleStream>, args: ScheduleStreamArgs) -> Result<()> {
-        ctx.accounts.process(args)
+        ctx.accounts.process(args, &ctx.bumps)
+    }
+    pub fn cancel_stream_schedule(ctx: Context<CancelStreamSchedule>) -> Result<()> {
+        ctx.accounts.process(&ctx.bumps)
+    }
+    pub fn reschedule_stream(ctx: Context<ScheduleStream>, args: ScheduleStreamArgs) -> Result<()> {
+        ctx.accounts.reschedule(args, &ctx.bumps)
     }
     pub fn unstake(ctx: Context<Unstake>, amount: u64) -> Result<()> {
-        ctx.accounts.process(amount)
+        ctx.accounts.process(amount, &ctx.bumps)
+    }
+    pub fn supporter_withdraw(
+        ctx: Context<SupporterWithdraw>,
+        share_amount: u64,
+        min_sol_out: u64,
+    ) -> Result<()> {
+        ctx.accounts.process(share_amount, min_sol_out, &ctx.bumps)
+    }
+    pub fn organization_withdraw(
+        ctx: Context<OrganizationWithdraw>,
+        amount: u64,
+        category: SpendingCategory,
+        memo_hash: [u8; 32],
+    ) -> Result<()> {
+        ctx.accounts
+            .process(amount, category, memo_hash, &ctx.bumps)
+    }
+    pub fn queue_organization_withdrawal(
+        ctx: Context<QueueOrganizationWithdrawal>,
+        amount: u64,
+        asset: PayoutAsset,
+        category: SpendingCategory,
+        memo_hash: [u8; 32],
+    ) -> Result<()> {
+        ctx.accounts
+            .process(amount, asset, category, memo_hash, &ctx.bumps)
+    }
+    pub fn release_queued_withdrawal(ctx: Context<ReleaseQueuedWithdrawal>) -> Result<()> {
+        ctx.accounts.process(&ctx.bumps)
     }
-    pub fn supporter_withdraw(ctx: Context<SupporterWithdraw>, share_amount: u64) -> Result<()> {
-        ctx.accounts.process(share_amount)
+    pub fn veto_queued_withdrawal(ctx: Context<VetoQueuedWithdrawal>) -> Result<()> {
+        ctx.accounts.process(&ctx.bumps)
+    }
+    pub fn set_withdrawal_controls(
+        ctx: Context<SetWithdrawalControls>,
+        withdraw_cap: u64,
+        withdraw_window: u64,
+        withdraw_delay: u64,
+    ) -> Result<()> {
+        ctx.accounts
+            .process(withdraw_cap, withdraw_window, withdraw_delay, &ctx.bumps)
+    }
+    pub fn organization_claimable(ctx: Context<OrganizationClaimable>) -> Result<u64> {
+        ctx.accounts.process()
     }
-    pub fn organization_withdraw(ctx: Context<OrganizationWithdraw>, amount: u64) -> Result<()> {
-        ctx.accounts.process(amount)
+    pub fn quote_deposit(ctx: Context<QuotePool>, amount: u64) -> Result<DepositQuote> {
+        ctx.accounts.quote_deposit(amount)
+    }
+    pub fn quote_withdraw(ctx: Context<QuotePool>, share_amount: u64) -> Result<WithdrawQuote> {
+        ctx.accounts.quote_withdraw(share_amount)
+    }
+    pub fn pool_nav(ctx: Context<QuotePool>) -> Result<PoolNav> {
+        ctx.accounts.pool_nav()
+    }
+    pub fn pending_org_yield(ctx: Context<QuotePool>) -> Result<PendingOrgYield> {
+        ctx.accounts.pending_org_yield()
+    }
+    pub fn position_value(ctx: Context<QuotePosition>) -> Result<PositionValue> {
+        ctx.accounts.process()
+    }
+    pub fn organization_withdraw_msol(
+        ctx: Context<OrganizationWithdrawMsol>,
+        amount: u64,
+        category: SpendingCategory,
+        memo_hash: [u8; 32],
+    ) -> Result<()> {
+        ctx.accounts
+            .process(amount, category, memo_hash, &ctx.bumps)
     }
     pub fn delegate(ctx: Context<DelegatePool>) -> Result<()> {
         delegate_process(ctx)
@@ -63,4 +172,103 @@ pub mod atharva_refi {
     pub fn undelegate(ctx: Context<UndelegatePool>) -> Result<()> {
         undelegate_process(ctx)
     }
+    pub fn set_badge_thresholds(
+        ctx: Context<SetBadgeThresholds>,
+        thresholds: [u64; BADGE_TIERS],
+    ) -> Result<()> {
+        ctx.accounts.process(thresholds, &ctx.bumps)
+    }
+    pub fn set_stream_cooldown(
+        ctx: Context<SetStreamCooldown>,
+        stream_cooldown: u64,
+    ) -> Result<()> {
+        ctx.accounts.process(stream_cooldown, &ctx.bumps)
+    }
+    pub fn set_payout_asset(ctx: Context<SetPayoutAsset>, payout_asset: PayoutAsset) -> Result<()> {
+        ctx.accounts.process(payout_asset, &ctx.bumps)
+    }
+    pub fn propose_organization_authority(
+        ctx: Context<ProposeOrganizationAuthority>,
+        new_authority: Pubkey,
+    ) -> Result<()> {
+        ctx.accounts.propose(new_authority, &ctx.bumps)
+    }
+    pub fn cancel_organization_authority_rotation(
+        ctx: Context<ProposeOrganizationAuthority>,
+    ) -> Result<()> {
+        ctx.accounts.cancel(&ctx.bumps)
+    }
+    pub fn approve_organization_authority_rotation(
+        ctx: Context<ApproveOrganizationAuthority>,
+        delay: u64,
+    ) -> Result<()> {
+        ctx.accounts.process(delay, &ctx.bumps)
+    }
+    pub fn accept_organization_authority(ctx: Context<AcceptOrganizationAuthority>) -> Result<()> {
+        ctx.accounts.process(&ctx.bumps)
+    }
+    pub fn publish_field_report(
+        ctx: Context<PublishFieldReport>,
+        args: PublishFieldReportArgs,
+    ) -> Result<()> {
+        ctx.accounts.process(args, &ctx.bumps)
+    }
+    pub fn set_attester(
+        ctx: Context<SetAttester>,
+        attester: Pubkey,
+        is_active: bool,
+    ) -> Result<()> {
+        ctx.accounts.process(attester, is_active, &ctx.bumps)
+    }
+    pub fn record_impact_metric(
+        ctx: Context<RecordImpactMetric>,
+        kind: MetricKind,
+        value: u64,
+        survey_date: u64,
+        source_hash: [u8; 32],
+    ) -> Result<()> {
+        ctx.accounts
+            .process(kind, value, survey_date, source_hash, &ctx.bumps)
+    }
+    pub fn set_escrow_mode(
+        ctx: Context<SetEscrowMode>,
+        escrow_mode: bool,
+        fallback_beneficiary: Pubkey,
+    ) -> Result<()> {
+        ctx.accounts
+            .process(escrow_mode, fallback_beneficiary, &ctx.bumps)
+    }
+    pub fn add_milestone(
+        ctx: Context<AddMilestone>,
+        amount: u64,
+        deadline: u64,
+        criteria_hash: [u8; 32],
+    ) -> Result<()> {
+        ctx.accounts
+            .process(amount, deadline, criteria_hash, &ctx.bumps)
+    }
+    pub fn attest_milestone(ctx: Context<AttestMilestone>, evidence_hash: [u8; 32]) -> Result<()> {
+        ctx.accounts.process(evidence_hash, &ctx.bumps)
+    }
+    pub fn reclaim_milestone(ctx: Context<ReclaimMilestone>) -> Result<()> {
+        ctx.accounts.process(&ctx.bumps)
+    }
+    pub fn release_excess_escrow(ctx: Context<ReleaseExcessEscrow>) -> Result<()> {
+        ctx.accounts.process(&ctx.bumps)
+    }
+    pub fn suspend_organization(ctx: Context<SuspendOrganization>) -> Result<()> {
+        ctx.accounts.process(&ctx.bumps)
+    }
+    pub fn reinstate_organization(ctx: Context<ReinstateOrganization>) -> Result<()> {
+        ctx.accounts.process(&ctx.bumps)
+    }
+    pub fn reassign_to_fallback(ctx: Context<ReassignToFallback>) -> Result<()> {
+        ctx.accounts.process(&ctx.bumps)
+    }
+    pub fn sweep_held_funds(ctx: Context<SweepHeldFunds>) -> Result<()> {
+        ctx.accounts.process(&ctx.bumps)
+    }
+    pub fn claim_impact_badge(ctx: Context<ClaimImpactBadge>, tier: u8) -> Result<()> {
+        ctx.accounts.process(tier, &ctx.bumps)
+    }
 }
@@ -0,0 +1,35 @@
+use crate::errors::ErrorCode;
+use crate::magicblock::TaskAccounts;
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::{
+    instruction::{AccountMeta, Instruction},
+    program::invoke_signed,
+};
+use magicblock_magic_program_api::instruction::MagicBlockInstruction;
+
+/// Cancel a previously scheduled crank task on MagicBlock
+pub fn magicblock_cancel_task<'info>(
+    task_id: u64,
+    accounts: TaskAccounts<'info>,
+    signer_seeds: &[&[&[u8]]],
+) -> Result<()> {
+    let ix_data = bincode::serialize(&MagicBlockInstruction::CancelTask { task_id })
+        .map_err(|_| ErrorCode::SerializationError)?;
+
+    let ix = Instruction::new_with_bytes(
+        accounts.magic_program.key(),
+        &ix_data,
+        vec![
+            AccountMeta::new(accounts.task_authority.key(), true),
+            AccountMeta::new(accounts.task_context.key(), false),
+        ],
+    );
+
+    invoke_signed(
+        &ix,
+        &[accounts.task_authority, accounts.task_context],
+        signer_seeds,
+    )?;
+
+    Ok(())
+}
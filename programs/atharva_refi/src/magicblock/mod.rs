@@ -0,0 +1,5 @@
+pub mod cancel_task;
+pub mod schedule_task;
+
+pub use cancel_task::*;
+pub use schedule_task::*;
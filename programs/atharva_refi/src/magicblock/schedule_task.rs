@@ -0,0 +1,41 @@
+use crate::errors::ErrorCode;
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::{
+    instruction::{AccountMeta, Instruction},
+    program::invoke_signed,
+};
+use magicblock_magic_program_api::{args::ScheduleTaskArgs, instruction::MagicBlockInstruction};
+
+/// Accounts needed for the MagicBlock task CPIs
+pub struct TaskAccounts<'info> {
+    pub magic_program: AccountInfo<'info>,
+    pub task_authority: AccountInfo<'info>,
+    pub task_context: AccountInfo<'info>,
+}
+
+/// Schedule a recurring crank task on MagicBlock
+pub fn magicblock_schedule_task<'info>(
+    args: ScheduleTaskArgs,
+    accounts: TaskAccounts<'info>,
+    signer_seeds: &[&[&[u8]]],
+) -> Result<()> {
+    let ix_data = bincode::serialize(&MagicBlockInstruction::ScheduleTask(args))
+        .map_err(|_| ErrorCode::SerializationError)?;
+
+    let ix = Instruction::new_with_bytes(
+        accounts.magic_program.key(),
+        &ix_data,
+        vec![
+            AccountMeta::new(accounts.task_authority.key(), true),
+            AccountMeta::new(accounts.task_context.key(), false),
+        ],
+    );
+
+    invoke_signed(
+        &ix,
+        &[accounts.task_authority, accounts.task_context],
+        signer_seeds,
+    )?;
+
+    Ok(())
+}
@@ -1,5 +1,7 @@
 pub mod liquid_stake;
 pub mod iquid_unstake;
+pub mod rates;
 
 pub use liquid_stake::*;
 pub use iquid_unstake::*;
+pub use rates::*;
@@ -0,0 +1,141 @@
+use crate::constants::MSOL_PRICE_PRECISION;
+use crate::errors::ErrorCode;
+use anchor_lang::prelude::*;
+
+/// Outcome of a Marinade liquid unstake
+#[derive(Clone, Copy, Debug)]
+pub struct LiquidUnstakeQuote {
+    pub fee_bps: u32,
+    /// mSOL kept by the liquidity pool as the fee
+    pub msol_fee: u64,
+    /// SOL paid out for the mSOL left after the fee
+    pub sol_out: u64,
+}
+
+/// mSOL exchange rate and liquidity pool fee curve read from Marinade's
+/// state account
+///
+/// Single source for SOL/mSOL conversions, so streams, withdrawals and
+/// the read-only quote instructions always agree
+pub struct MarinadeRates {
+    pub msol_supply: u64,
+    pub total_virtual_staked: u64,
+    /// SOL leg balance at or above which the minimum fee applies
+    pub lp_liquidity_target: u64,
+    pub lp_max_fee_bps: u32,
+    pub lp_min_fee_bps: u32,
+    /// Reserve Marinade keeps in the SOL leg when paying out unstakes
+    pub rent_exempt_for_token_acc: u64,
+}
+impl MarinadeRates {
+    pub fn load(marinade_state: &AccountInfo) -> Result<Self> {
+        let data = marinade_state.try_borrow_data()?;
+
+        // Marinade State field offsets:
+        // rent_exempt_for_token_acc is at byte 138
+        // liq_pool.lp_liquidity_target is at byte 316
+        // liq_pool.lp_max_fee is at byte 324
+        // liq_pool.lp_min_fee is at byte 328
+        // msol_supply is at byte 368
+        // total_virtual_staked_lamports is at byte 376
+        let read = |offset: usize, len: usize| -> Result<&[u8]> {
+            data.get(offset..offset + len)
+                .ok_or(ErrorCode::InvalidMarinadeState.into())
+        };
+        let read_u64 = |offset| read(offset, 8).map(|b| u64::from_le_bytes(b.try_into().unwrap()));
+        let read_u32 = |offset| read(offset, 4).map(|b| u32::from_le_bytes(b.try_into().unwrap()));
+
+        Ok(Self {
+            msol_supply: read_u64(368)?,
+            total_virtual_staked: read_u64(376)?,
+            lp_liquidity_target: read_u64(316)?,
+            lp_max_fee_bps: read_u32(324)?,
+            lp_min_fee_bps: read_u32(328)?,
+            rent_exempt_for_token_acc: read_u64(138)?,
+        })
+    }
+
+    /// Liquidity pool fee once `lamports_after` remain in the SOL leg
+    ///
+    /// Falls linearly from the max fee at an empty leg to the min fee at
+    /// `lp_liquidity_target`, as in Marinade's `LiqPool::linear_fee`
+    pub fn linear_fee_bps(&self, lamports_after: u64) -> Result<u32> {
+        if lamports_after >= self.lp_liquidity_target {
+            return Ok(self.lp_min_fee_bps);
+        }
+
+        let fee_range = self.lp_max_fee_bps.saturating_sub(self.lp_min_fee_bps);
+        let discount = (fee_range as u128)
+            .checked_mul(lamports_after as u128)
+            .ok_or(ErrorCode::MathError)?
+            .checked_div(self.lp_liquidity_target as u128)
+            .ok_or(ErrorCode::MathError)?;
+
+        Ok(self.lp_max_fee_bps - discount as u32)
+    }
+
+    /// Reproduces Marinade's `liquid_unstake` for `msol_amount` against a
+    /// SOL leg holding `sol_leg_lamports`
+    pub fn liquid_unstake(
+        &self,
+        msol_amount: u64,
+        sol_leg_lamports: u64,
+    ) -> Result<LiquidUnstakeQuote> {
+        // The SOL leg keeps the reserve Marinade itself sets aside
+        let max_lamports = sol_leg_lamports.saturating_sub(self.rent_exempt_for_token_acc);
+
+        let user_remove_lamports = self.msol_to_sol(msol_amount)?;
+        let fee_bps = if user_remove_lamports >= max_lamports {
+            self.lp_max_fee_bps
+        } else {
+            self.linear_fee_bps(max_lamports - user_remove_lamports)?
+        };
+
+        let msol_fee = (msol_amount as u128)
+            .checked_mul(fee_bps as u128)
+            .ok_or(ErrorCode::MathError)?
+            .checked_div(10_000)
+            .ok_or(ErrorCode::MathError)?;
+        let msol_fee = u64::try_from(msol_fee).map_err(|_| ErrorCode::MathError)?;
+
+        let sol_out = self.msol_to_sol(msol_amount - msol_fee)?;
+        require!(sol_out <= max_lamports, ErrorCode::InsufficientLiquidity);
+
+        Ok(LiquidUnstakeQuote {
+            fee_bps,
+            msol_fee,
+            sol_out,
+        })
+    }
+
+    /// SOL per mSOL, scaled by `MSOL_PRICE_PRECISION`
+    pub fn msol_price(&self) -> Result<u128> {
+        require!(self.msol_supply > 0, ErrorCode::MathError);
+
+        (self.total_virtual_staked as u128)
+            .checked_mul(MSOL_PRICE_PRECISION)
+            .ok_or(ErrorCode::MathError)?
+            .checked_div(self.msol_supply as u128)
+            .ok_or(ErrorCode::MathError.into())
+    }
+
+    pub fn msol_to_sol(&self, msol_amount: u64) -> Result<u64> {
+        let sol_amount = (msol_amount as u128)
+            .checked_mul(self.total_virtual_staked as u128)
+            .ok_or(ErrorCode::MathError)?
+            .checked_div(self.msol_supply as u128)
+            .ok_or(ErrorCode::MathError)?;
+
+        u64::try_from(sol_amount).map_err(|_| ErrorCode::MathError.into())
+    }
+
+    pub fn sol_to_msol(&self, sol_amount: u64) -> Result<u64> {
+        let msol_amount = (sol_amount as u128)
+            .checked_mul(self.msol_supply as u128)
+            .ok_or(ErrorCode::MathError)?
+            .checked_div(self.total_virtual_staked as u128)
+            .ok_or(ErrorCode::MathError)?;
+
+        u64::try_from(msol_amount).map_err(|_| ErrorCode::MathError.into())
+    }
+}
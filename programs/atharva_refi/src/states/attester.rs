@@ -0,0 +1,12 @@
+use anchor_lang::prelude::*;
+
+/// Admin-registered key allowed to attest impact data and milestones
+#[account]
+#[derive(InitSpace)]
+pub struct Attester {
+    pub attester: Pubkey,
+    pub is_active: bool,
+    pub registered_by: Pubkey,
+    pub updated_at: u64,
+    pub bump: u8,
+}
@@ -0,0 +1,37 @@
+use anchor_lang::prelude::*;
+
+/// Inclusive range of `SpendingLedger` entries a field report covers
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace, Debug)]
+pub struct LedgerEntryRange {
+    pub first_entry: u64,
+    pub last_entry: u64,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct PublishFieldReportArgs {
+    pub content_hash: [u8; 32], // SHA-256 of the full report
+    pub uri: String,            // Where the full report is hosted
+    pub period_start: u64,      // Unix timestamps of the period reported on
+    pub period_end: u64,
+    pub covered_entries: Option<LedgerEntryRange>, // Withdrawals the report accounts for
+}
+
+/// Tamper-evident anchor of an organization's field report for a pool
+#[account]
+#[derive(InitSpace)]
+pub struct FieldReport {
+    pub pool: Pubkey,
+    /// Organization authority that published the report
+    pub publisher: Pubkey,
+    pub sequence: u64,
+
+    pub content_hash: [u8; 32],
+    #[max_len(200)]
+    pub uri: String,
+    pub period_start: u64,
+    pub period_end: u64,
+    pub covered_entries: Option<LedgerEntryRange>,
+
+    pub published_at: u64,
+    pub bump: u8,
+}
@@ -0,0 +1,67 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::{IMPACT_HISTORY_LEN, METRIC_KINDS};
+use crate::errors::ErrorCode;
+
+/// Kind of impact data an attester can report for a pool
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace, Debug)]
+pub enum MetricKind {
+    PopulationCount,
+    HectaresProtected,
+    Patrols,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace, Debug)]
+pub struct MetricEntry {
+    pub kind: MetricKind,
+    pub value: u64,
+    pub survey_date: u64,
+    /// Hash of the survey or source document backing the value
+    pub source_hash: [u8; 32],
+    pub attester: Pubkey,
+    pub recorded_at: u64,
+}
+
+/// Attested impact data for a pool's species
+///
+/// Holds the most recent survey per metric kind and the last
+/// `IMPACT_HISTORY_LEN` entries of any kind in a ring buffer
+#[account]
+#[derive(InitSpace)]
+pub struct ImpactMetrics {
+    pub pool: Pubkey,
+
+    /// Latest value per metric kind, indexed by `MetricKind`
+    pub latest_values: [u64; METRIC_KINDS],
+    /// Survey date of each latest value, zero if never reported
+    pub latest_survey_dates: [u64; METRIC_KINDS],
+
+    pub history: [MetricEntry; IMPACT_HISTORY_LEN],
+    /// Slot the next entry is written to
+    pub history_head: u8,
+    /// Total entries ever recorded; `history` holds the last `IMPACT_HISTORY_LEN`
+    pub entry_count: u64,
+
+    pub bump: u8,
+}
+impl ImpactMetrics {
+    /// Appends `entry` to the history and updates the latest value for
+    /// its kind if the survey is the newest seen
+    pub fn record(&mut self, entry: MetricEntry) -> Result<()> {
+        let kind = entry.kind as usize;
+        if entry.survey_date >= self.latest_survey_dates[kind] {
+            self.latest_values[kind] = entry.value;
+            self.latest_survey_dates[kind] = entry.survey_date;
+        }
+
+        let head = self.history_head as usize;
+        self.history[head] = entry;
+        self.history_head = ((head + 1) % IMPACT_HISTORY_LEN) as u8;
+        self.entry_count = self
+            .entry_count
+            .checked_add(1)
+            .ok_or(ErrorCode::MathError)?;
+
+        Ok(())
+    }
+}
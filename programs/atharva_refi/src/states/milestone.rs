@@ -0,0 +1,35 @@
+use anchor_lang::prelude::*;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace, Debug)]
+pub enum MilestoneStatus {
+    Pending,
+    /// Attested and paid to the organization vault
+    Released,
+    /// Deadline missed, paid to the pool's fallback beneficiary
+    Reclaimed,
+}
+
+/// Escrowed tranche of organization yield, released once the milestone's
+/// approved attester confirms it was met
+#[account]
+#[derive(InitSpace)]
+pub struct Milestone {
+    pub pool: Pubkey,
+    pub index: u8,
+
+    pub amount: u64,
+    pub deadline: u64,
+    /// Hash of the milestone's success criteria document
+    pub criteria_hash: [u8; 32],
+    /// Registered attester approved to confirm this milestone
+    pub attester: Pubkey,
+
+    pub status: MilestoneStatus,
+    /// Attester that confirmed the milestone, default until released
+    pub attested_by: Pubkey,
+    /// Hash of the evidence the attester reviewed
+    pub evidence_hash: [u8; 32],
+    pub resolved_at: u64,
+
+    pub bump: u8,
+}
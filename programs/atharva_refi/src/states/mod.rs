@@ -1,5 +1,33 @@
+pub mod attester;
+pub mod field_report;
+pub mod impact_metrics;
+pub mod milestone;
+pub mod organization;
 pub mod pool;
+pub mod pool_history;
+pub mod pool_registry;
+pub mod protocol_stats;
+pub mod queued_withdrawal;
+pub mod quotes;
 pub mod schedule;
+pub mod species;
+pub mod spending_ledger;
+pub mod stream_record;
+pub mod supporter;
 
+pub use attester::*;
+pub use field_report::*;
+pub use impact_metrics::*;
+pub use milestone::*;
+pub use organization::*;
 pub use pool::*;
+pub use pool_history::*;
+pub use pool_registry::*;
+pub use protocol_stats::*;
+pub use queued_withdrawal::*;
+pub use quotes::*;
 pub use schedule::*;
+pub use species::*;
+pub use spending_ledger::*;
+pub use stream_record::*;
+pub use supporter::*;
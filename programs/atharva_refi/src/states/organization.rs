@@ -0,0 +1,49 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::MAX_ORGANIZATION_SPECIES;
+
+/// Verification state of an organization in the registry
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace, Debug)]
+pub enum VerificationStatus {
+    Unverified,
+    /// Applied on-chain, awaiting admin review
+    Pending,
+    Verified,
+    Rejected,
+}
+
+/// Canonical registry entry for a conservation organization
+///
+/// Seeded by the organization's pubkey, which also seeds all of its pools
+#[account]
+#[derive(InitSpace)]
+pub struct Organization {
+    pub organization_pubkey: Pubkey,
+    /// Signs for the organization when creating pools; pool authority
+    /// rotations never change it
+    pub authority: Pubkey,
+    #[max_len(50)]
+    pub name: String,
+    #[max_len(200)]
+    pub metadata_uri: String,
+    /// Content hash (SHA-256) of the verification documents submitted
+    pub document_hash: [u8; 32],
+    /// Species IDs the organization applied for. Once verified, its
+    /// authority may create pools for these species itself
+    #[max_len(MAX_ORGANIZATION_SPECIES)]
+    pub species_ids: Vec<[u8; 32]>,
+
+    pub status: VerificationStatus,
+    pub verified_at: u64,
+    /// Admin that verified the organization, default while unverified
+    pub verified_by: Pubkey,
+    /// Reason code of the latest rejection, zero if never rejected
+    pub rejection_reason: u16,
+    pub reviewed_at: u64,
+
+    /// Number of pools created for this organization
+    pub pool_count: u32,
+    pub created_at: u64,
+
+    pub bump: u8,
+}
@@ -1,9 +1,42 @@
 use anchor_lang::prelude::*;
 
+use crate::constants::{ACCRUAL_RATE_PRECISION, BADGE_TIERS, ORG_YIELD_BPS};
+use crate::errors::ErrorCode;
+use crate::states::PoolListingStatus;
+
+/// Standing of the pool's organization as a stream recipient
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace, Debug)]
+pub enum OrganizationStanding {
+    Active,
+    /// Under review; streams are held in the escrow vault
+    Suspended,
+    /// Held and future streams go to the fallback beneficiary
+    Reassigned,
+}
+
+/// Asset the organization's share of yield is paid out in
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace, Debug)]
+pub enum PayoutAsset {
+    /// Liquid-unstaked to SOL and sent to the organization vault
+    Sol,
+    /// Kept as mSOL in the organization vault's mSOL ATA, no unstake fee
+    Msol,
+}
+
 #[account]
 #[derive(InitSpace)]
 pub struct Pool {
+    /// Seeds the pool's PDAs, fixed for the pool's lifetime
     pub organization_pubkey: Pubkey,
+    /// Signs for the organization (withdrawals, pool config), rotatable
+    pub organization_authority: Pubkey,
+    /// Proposed replacement for `organization_authority`, default if none
+    pub pending_organization_authority: Pubkey,
+    /// Earliest time the pending authority can accept the rotation
+    pub authority_rotation_eta: u64,
+    /// Pending rotation is an admin recovery the current authority cannot
+    /// cancel or overwrite
+    pub authority_rotation_by_admin: bool,
     #[max_len(50)]
     pub organization_name: String,
 
@@ -11,26 +44,259 @@ pub struct Pool {
     /// Converted to basis points (bps) in calculations
     pub organization_yield_bps: u8,
 
+    /// Common name copied from the species registry
     #[max_len(50)]
     pub species_name: String,
+    /// Scientific name copied from the species registry
     #[max_len(50)]
-    pub species_id: String,
+    pub scientific_name: String,
+    /// Canonical species ID (SHA-256 of the scientific name), seeds the pool
     pub new_species_id: [u8; 32],
 
     pub vault: Pubkey,
     pub pool_mint: Pubkey,
 
+    pub payout_asset: PayoutAsset,
+
     pub last_streamed_vault_sol: u64,
     pub last_stream_ts: u64,
 
+    /// High-water mark of the mSOL price (lamports per mSOL, scaled by
+    /// `MSOL_PRICE_PRECISION`). Nothing streams while the price is below it
+    pub peak_msol_price: u128,
+    /// Pool SOL value when the high-water mark was last raised
+    pub peak_sol_value: u64,
+    /// Lamports the pool's mSOL is currently worth below the high-water mark
+    pub unrecovered_loss: u64,
+
+    /// Pool SOL value seen by the latest stream call, paid out or not
+    pub last_observed_sol_value: u64,
+    pub last_observed_ts: u64,
+    /// Number of successful streams, used to seed `StreamRecord` PDAs
+    pub stream_count: u64,
+    /// Seconds after `last_stream_ts` before anyone but the crank may stream
+    pub stream_cooldown: u64,
+    /// Org yield streamed by crank runs, not yet added to `ProtocolStats`
+    pub unreported_yield: u64,
+
+    /// SOL yield released to the organization per second, scaled by
+    /// `ACCRUAL_RATE_PRECISION`. Set by each stream for the next period
+    pub org_accrual_rate: u128,
+    /// Accrual is settled up to this timestamp
+    pub org_accrual_last_ts: u64,
+    /// End of the current accrual period
+    pub org_accrual_end_ts: u64,
+    /// Lamports in the organization vault that have not accrued yet
+    pub org_unvested: u64,
+
+    /// Max lamports the organization may withdraw per window, zero for no cap
+    pub withdraw_cap: u64,
+    /// Length of the rolling withdrawal window in seconds
+    pub withdraw_window: u64,
+    pub withdraw_window_start: u64,
+    pub withdrawn_in_window: u64,
+    /// Seconds a queued withdrawal waits before release, zero disables the queue
+    pub withdraw_delay: u64,
+    /// Lamports reserved in the organization vault by queued withdrawals
+    pub queued_withdraw_total: u64,
+    /// mSOL reserved in the organization vault's mSOL ATA by queued withdrawals
+    pub queued_msol_total: u64,
+    /// Number of withdrawals ever queued, used to seed `QueuedWithdrawal` PDAs
+    pub queued_withdraw_count: u64,
+
+    /// Number of field reports published, used to seed `FieldReport` PDAs
+    pub field_report_count: u64,
+    /// Spending ledger entries below this index are covered by a published
+    /// field report; coverage only moves forward
+    pub reported_entry_count: u64,
+
+    /// When set, SOL streams are held in the escrow vault until milestones
+    /// are attested instead of going to the organization vault
+    pub escrow_mode: bool,
+    /// Lamports in the escrow vault not yet released or reclaimed, excluding
+    /// the rent-exempt reserve funded at pool creation
+    pub escrow_balance: u64,
+    /// Sum of pending milestone amounts; `escrow_balance` beyond it is
+    /// released to the organization once escrow mode is off
+    pub escrow_committed: u64,
+    /// Number of milestones defined, used to seed `Milestone` PDAs
+    pub milestone_count: u8,
+    /// Index of the earliest unresolved milestone; milestones are attested
+    /// or reclaimed in order, so escrow always backs this one first
+    pub next_milestone: u8,
+    /// Receives escrowed funds of milestones that miss their deadline
+    pub fallback_beneficiary: Pubkey,
+
+    pub org_standing: OrganizationStanding,
+    pub suspended_at: u64,
+    /// Fallback organization receiving held streams once the pool is
+    /// reassigned, default until then
+    pub reassigned_to: Pubkey,
+    /// Lamports held in the escrow vault while the organization is not active;
+    /// like `escrow_balance`, excludes the vault's rent-exempt reserve
+    pub held_balance: u64,
+
     pub total_deposits: u64,
     pub total_shares: u64,
 
+    /// Cumulative org yield per share, scaled by `YIELD_PER_SHARE_PRECISION`
+    pub donated_yield_per_share: u128,
+
+    /// Lifetime donated yield (lamports) required for each badge tier
+    /// A zero threshold means the tier is not configured
+    pub badge_thresholds: [u64; BADGE_TIERS],
+
     pub is_active: bool,
     pub is_crank_scheduled: bool, // Track if crank is active
+    pub crank_task_id: u64,       // MagicBlock task backing the crank
 
     pub pool_bump: u8,
     pub org_vault_bump: u8,
     pub pool_vault_bump: u8,
     pub pool_mint_bump: u8,
+    pub escrow_vault_bump: u8,
+}
+impl Pool {
+    /// Status reported for this pool in the registry pages
+    pub fn listing_status(&self) -> PoolListingStatus {
+        match self.org_standing {
+            OrganizationStanding::Suspended => PoolListingStatus::Suspended,
+            OrganizationStanding::Reassigned => PoolListingStatus::Reassigned,
+            OrganizationStanding::Active if !self.is_active => PoolListingStatus::Inactive,
+            OrganizationStanding::Active => PoolListingStatus::Active,
+        }
+    }
+
+    /// mSOL claimed by `share_amount` receipt tokens out of `share_supply`
+    pub fn msol_for_shares(share_amount: u64, share_supply: u64, pool_msol: u64) -> Result<u64> {
+        require!(share_supply > 0, ErrorCode::PoolEmpty);
+
+        let msol_amount = (share_amount as u128)
+            .checked_mul(pool_msol as u128)
+            .ok_or(ErrorCode::MathError)?
+            .checked_div(share_supply as u128)
+            .ok_or(ErrorCode::MathError)?;
+
+        u64::try_from(msol_amount).map_err(|_| ErrorCode::MathError.into())
+    }
+
+    /// Organization's cut of `total_yield`
+    pub fn org_yield_share(&self, total_yield: u64) -> Result<u64> {
+        let org_amount = (total_yield as u128)
+            .checked_mul(ORG_YIELD_BPS)
+            .ok_or(ErrorCode::MathError)?
+            .checked_div(10_000)
+            .ok_or(ErrorCode::MathError)?;
+
+        u64::try_from(org_amount).map_err(|_| ErrorCode::MathError.into())
+    }
+
+    /// Whether streams are held back from the organization
+    pub fn holds_org_yield(&self) -> bool {
+        self.org_standing != OrganizationStanding::Active
+    }
+
+    /// Whether SOL streams pay into the escrow vault
+    pub fn pays_into_escrow(&self) -> bool {
+        self.escrow_mode || self.holds_org_yield()
+    }
+
+    /// Releases the organization yield accrued up to `now` and returns
+    /// the amount released
+    pub fn settle_org_accrual(&mut self, now: u64) -> Result<u64> {
+        let accrued = self.pending_org_accrual(now)?;
+
+        self.org_unvested = self
+            .org_unvested
+            .checked_sub(accrued)
+            .ok_or(ErrorCode::MathError)?;
+        self.org_accrual_last_ts = now.min(self.org_accrual_end_ts);
+
+        Ok(accrued)
+    }
+
+    /// Yield accrued since the last settlement, without settling it
+    pub fn pending_org_accrual(&self, now: u64) -> Result<u64> {
+        let until = now.min(self.org_accrual_end_ts);
+        if until <= self.org_accrual_last_ts {
+            return Ok(0);
+        }
+
+        // Release everything at period end so rounding never strands lamports
+        if until == self.org_accrual_end_ts {
+            return Ok(self.org_unvested);
+        }
+
+        let accrued = self
+            .org_accrual_rate
+            .checked_mul((until - self.org_accrual_last_ts) as u128)
+            .ok_or(ErrorCode::MathError)?
+            .checked_div(ACCRUAL_RATE_PRECISION)
+            .ok_or(ErrorCode::MathError)?;
+
+        Ok(u64::try_from(accrued)
+            .map_err(|_| ErrorCode::MathError)?
+            .min(self.org_unvested))
+    }
+
+    /// Lamports of `vault_lamports` the organization can withdraw once
+    /// `unvested` is held back, excluding queued withdrawals
+    pub fn org_withdrawable(&self, vault_lamports: u64, unvested: u64) -> u64 {
+        vault_lamports
+            .saturating_sub(unvested)
+            .saturating_sub(self.queued_withdraw_total)
+    }
+
+    /// mSOL in the organization vault's ATA not reserved by queued withdrawals
+    pub fn org_msol_withdrawable(&self, vault_msol: u64) -> u64 {
+        vault_msol.saturating_sub(self.queued_msol_total)
+    }
+
+    /// Counts `amount` against the rolling withdrawal cap, if one is set
+    pub fn record_capped_withdrawal(&mut self, amount: u64, now: u64) -> Result<()> {
+        if self.withdraw_cap == 0 {
+            return Ok(());
+        }
+
+        let window_end = self
+            .withdraw_window_start
+            .checked_add(self.withdraw_window)
+            .ok_or(ErrorCode::MathError)?;
+        if now >= window_end {
+            self.withdraw_window_start = now;
+            self.withdrawn_in_window = 0;
+        }
+
+        let withdrawn = self
+            .withdrawn_in_window
+            .checked_add(amount)
+            .ok_or(ErrorCode::MathError)?;
+        require!(
+            withdrawn <= self.withdraw_cap,
+            ErrorCode::WithdrawalCapExceeded
+        );
+        self.withdrawn_in_window = withdrawn;
+
+        Ok(())
+    }
+
+    /// Starts a new accrual period spreading `amount` plus anything still
+    /// unvested evenly over `period` seconds
+    pub fn start_org_accrual(&mut self, amount: u64, now: u64, period: u64) -> Result<()> {
+        self.settle_org_accrual(now)?;
+
+        self.org_unvested = self
+            .org_unvested
+            .checked_add(amount)
+            .ok_or(ErrorCode::MathError)?;
+        self.org_accrual_rate = (self.org_unvested as u128)
+            .checked_mul(ACCRUAL_RATE_PRECISION)
+            .ok_or(ErrorCode::MathError)?
+            .checked_div(period.max(1) as u128)
+            .ok_or(ErrorCode::MathError)?;
+        self.org_accrual_last_ts = now;
+        self.org_accrual_end_ts = now.checked_add(period).ok_or(ErrorCode::MathError)?;
+
+        Ok(())
+    }
 }
@@ -0,0 +1,86 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::{POOL_HISTORY_LEN, SECONDS_PER_YEAR};
+use crate::errors::ErrorCode;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace, Debug)]
+pub struct PoolSnapshot {
+    pub timestamp: u64,
+    /// SOL value of the pool's mSOL
+    pub nav: u64,
+    /// SOL per mSOL, scaled by `MSOL_PRICE_PRECISION`
+    pub msol_price: u128,
+    pub total_shares: u64,
+}
+
+/// Rolling NAV and mSOL price history for a pool
+///
+/// Written by public streams and the permissionless `snapshot`
+/// instruction. Holds the last `POOL_HISTORY_LEN` snapshots in a ring
+/// buffer, oldest first starting at `head` once the buffer has wrapped
+#[account]
+#[derive(InitSpace)]
+pub struct PoolHistory {
+    pub pool: Pubkey,
+
+    pub snapshots: [PoolSnapshot; POOL_HISTORY_LEN],
+    /// Slot the next snapshot is written to
+    pub head: u8,
+    /// Total snapshots ever recorded
+    pub snapshot_count: u64,
+
+    pub bump: u8,
+}
+impl PoolHistory {
+    pub fn push(&mut self, snapshot: PoolSnapshot) -> Result<()> {
+        let head = self.head as usize;
+        self.snapshots[head] = snapshot;
+        self.head = ((head + 1) % POOL_HISTORY_LEN) as u8;
+        self.snapshot_count = self
+            .snapshot_count
+            .checked_add(1)
+            .ok_or(ErrorCode::MathError)?;
+
+        Ok(())
+    }
+
+    pub fn latest(&self) -> Option<&PoolSnapshot> {
+        if self.snapshot_count == 0 {
+            return None;
+        }
+        let index = (self.head as usize + POOL_HISTORY_LEN - 1) % POOL_HISTORY_LEN;
+        Some(&self.snapshots[index])
+    }
+
+    pub fn oldest(&self) -> Option<&PoolSnapshot> {
+        if self.snapshot_count == 0 {
+            return None;
+        }
+        if self.snapshot_count < POOL_HISTORY_LEN as u64 {
+            return Some(&self.snapshots[0]);
+        }
+        Some(&self.snapshots[self.head as usize])
+    }
+
+    /// Annualized mSOL price growth across the buffer, in basis points
+    ///
+    /// None until two snapshots at different times exist. A falling
+    /// price reports zero rather than a negative APY
+    pub fn trailing_apy_bps(&self) -> Option<u64> {
+        let (oldest, latest) = (self.oldest()?, self.latest()?);
+
+        let elapsed = latest.timestamp.checked_sub(oldest.timestamp)?;
+        if elapsed == 0 || oldest.msol_price == 0 {
+            return None;
+        }
+
+        let growth = latest.msol_price.saturating_sub(oldest.msol_price);
+        let apy_bps = growth
+            .checked_mul(10_000)?
+            .checked_mul(SECONDS_PER_YEAR as u128)?
+            .checked_div(oldest.msol_price)?
+            .checked_div(elapsed as u128)?;
+
+        u64::try_from(apy_bps).ok()
+    }
+}
@@ -0,0 +1,104 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::REGISTRY_PAGE_CAPACITY;
+use crate::errors::ErrorCode;
+use crate::states::IucnCategory;
+
+/// Listing status of a pool as seen by discovery clients
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace, Debug)]
+pub enum PoolListingStatus {
+    Active,
+    Inactive,
+    Suspended,
+    Reassigned,
+}
+
+/// Which index a registry page belongs to
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace, Debug)]
+pub enum RegistryIndex {
+    /// Every pool, keyed by the `PoolRegistry` account
+    All,
+    /// Pools of one species, keyed by its `Species` account
+    Species,
+    /// Pools of one organization, keyed by its `Organization` account
+    Organization,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, InitSpace, Debug)]
+pub struct PoolListing {
+    pub pool: Pubkey,
+    pub status: PoolListingStatus,
+    /// IUCN category of the pool's species
+    pub category: IucnCategory,
+}
+
+/// Protocol-wide pool counter, keys the global registry pages
+#[account]
+#[derive(InitSpace)]
+pub struct PoolRegistry {
+    pub pool_count: u32,
+    pub updated_at: u64,
+
+    pub bump: u8,
+}
+
+/// Fixed-capacity page of pool listings
+///
+/// Seeded by the index key and page number. Page `n` of an index holds
+/// listings `n * REGISTRY_PAGE_CAPACITY` onwards, in creation order
+#[account]
+#[derive(InitSpace)]
+pub struct RegistryPage {
+    pub index: RegistryIndex,
+    /// Registry, species or organization account this page indexes
+    pub index_key: Pubkey,
+    pub page: u32,
+
+    #[max_len(REGISTRY_PAGE_CAPACITY)]
+    pub listings: Vec<PoolListing>,
+
+    pub bump: u8,
+}
+impl RegistryPage {
+    /// Page holding the listing at position `count` of an index
+    pub fn page_for(count: u32) -> u32 {
+        count / REGISTRY_PAGE_CAPACITY as u32
+    }
+
+    /// Initializes the page on first use and appends a listing
+    pub fn append(
+        &mut self,
+        index: RegistryIndex,
+        index_key: Pubkey,
+        page: u32,
+        bump: u8,
+        listing: PoolListing,
+    ) -> Result<()> {
+        if self.index_key == Pubkey::default() {
+            self.index = index;
+            self.index_key = index_key;
+            self.page = page;
+            self.bump = bump;
+        }
+
+        require!(
+            self.listings.len() < REGISTRY_PAGE_CAPACITY,
+            ErrorCode::RegistryPageFull
+        );
+        self.listings.push(listing);
+
+        Ok(())
+    }
+
+    /// Updates the status of `pool`'s listing on this page
+    pub fn set_status(&mut self, pool: Pubkey, status: PoolListingStatus) -> Result<()> {
+        let listing = self
+            .listings
+            .iter_mut()
+            .find(|listing| listing.pool == pool)
+            .ok_or(ErrorCode::PoolNotListed)?;
+        listing.status = status;
+
+        Ok(())
+    }
+}
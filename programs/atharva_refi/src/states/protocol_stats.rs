@@ -0,0 +1,42 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ErrorCode;
+use crate::states::Pool;
+
+/// Protocol-wide totals, kept in a single account for one-fetch reads
+#[account]
+#[derive(InitSpace)]
+pub struct ProtocolStats {
+    /// Supporter principal across all pools, in lamports
+    pub total_value_locked: u64,
+    /// Organization share of streamed yield, in lamports
+    pub total_yield_streamed: u64,
+    /// SOL withdrawn by organizations, directly or via the queue, with
+    /// mSOL withdrawals counted at their SOL value
+    pub total_org_withdrawn: u64,
+
+    pub pool_count: u64,
+    /// Supporter positions currently holding shares, across all pools
+    pub supporter_count: u64,
+
+    pub updated_at: u64,
+
+    pub bump: u8,
+}
+impl ProtocolStats {
+    /// Adds the organization yield crank runs parked on `pool` to the totals
+    pub fn flush_unreported_yield(&mut self, pool: &mut Pool, now: u64) -> Result<()> {
+        if pool.unreported_yield == 0 {
+            return Ok(());
+        }
+
+        self.total_yield_streamed = self
+            .total_yield_streamed
+            .checked_add(pool.unreported_yield)
+            .ok_or(ErrorCode::MathError)?;
+        self.updated_at = now;
+        pool.unreported_yield = 0;
+
+        Ok(())
+    }
+}
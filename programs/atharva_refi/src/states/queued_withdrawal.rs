@@ -0,0 +1,24 @@
+use anchor_lang::prelude::*;
+
+use crate::states::{PayoutAsset, SpendingCategory};
+
+/// Organization withdrawal waiting out the pool's `withdraw_delay`,
+/// during which the admin may veto it
+#[account]
+#[derive(InitSpace)]
+pub struct QueuedWithdrawal {
+    pub pool: Pubkey,
+    /// Organization authority at queue time, receives the funds and the rent
+    pub recipient: Pubkey,
+    pub sequence: u64,
+    /// Lamports or mSOL base units, per `asset`
+    pub amount: u64,
+    pub asset: PayoutAsset,
+    /// Lamport value at queue time, counted in the spending ledger on release
+    pub sol_value: u64,
+    pub category: SpendingCategory,
+    pub memo_hash: [u8; 32],
+    pub queued_at: u64,
+    pub release_ts: u64,
+    pub bump: u8,
+}
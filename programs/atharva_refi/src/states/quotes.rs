@@ -0,0 +1,64 @@
+use anchor_lang::prelude::*;
+
+/// Return data of `quote_deposit`
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug)]
+pub struct DepositQuote {
+    /// Receipt tokens minted for the deposit
+    pub shares: u64,
+    pub total_shares_after: u64,
+    /// Share of the pool held by the new shares, in basis points
+    pub ownership_bps: u64,
+}
+
+/// Return data of `quote_withdraw`
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug)]
+pub struct WithdrawQuote {
+    /// mSOL liquid-unstaked for the shares
+    pub msol_amount: u64,
+    /// SOL value of `msol_amount` at the current Marinade rate
+    pub sol_value: u64,
+    /// Marinade liquid-unstake fee at the current SOL leg balance
+    pub fee_bps: u32,
+    pub fee_msol: u64,
+    /// SOL the supporter would receive after the fee
+    pub sol_amount: u64,
+}
+
+/// Return data of `pool_nav`
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug)]
+pub struct PoolNav {
+    pub msol_balance: u64,
+    /// SOL per mSOL, scaled by `MSOL_PRICE_PRECISION`
+    pub msol_price: u128,
+    /// SOL value of the pool's mSOL
+    pub nav: u64,
+    /// Deposited SOL not yet staked
+    pub unstaked_lamports: u64,
+    pub total_shares: u64,
+    /// NAV per receipt token, scaled by `MSOL_PRICE_PRECISION`
+    pub nav_per_share: u128,
+}
+
+/// Return data of `pending_org_yield`
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug)]
+pub struct PendingOrgYield {
+    /// Yield since the last stream checkpoint
+    pub total_yield: u64,
+    /// Organization's cut the next stream would pay
+    pub org_yield: u64,
+    /// Whether a stream now would pay out rather than skip
+    pub streamable: bool,
+    /// Earliest time a public caller may stream
+    pub next_public_stream_ts: u64,
+}
+
+/// Return data of `position_value`
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug)]
+pub struct PositionValue {
+    pub shares: u64,
+    /// mSOL the shares currently claim
+    pub msol_amount: u64,
+    pub sol_value: u64,
+    /// Lifetime yield donated, including yield not yet settled
+    pub donated_yield: u64,
+}
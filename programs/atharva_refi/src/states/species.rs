@@ -0,0 +1,57 @@
+use anchor_lang::prelude::*;
+use solana_program::hash;
+
+/// IUCN Red List category of a species
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace, Debug)]
+pub enum IucnCategory {
+    LeastConcern,
+    NearThreatened,
+    Vulnerable,
+    Endangered,
+    CriticallyEndangered,
+    ExtinctInTheWild,
+    DataDeficient,
+}
+
+/// Canonical registry entry for a species
+///
+/// Seeded by `species_id`, the SHA-256 hash of the canonical scientific
+/// name, so any number of organizations can run pools against the same species
+#[account]
+#[derive(InitSpace)]
+pub struct Species {
+    pub species_id: [u8; 32],
+    #[max_len(50)]
+    pub scientific_name: String,
+    #[max_len(50)]
+    pub common_name: String,
+    pub iucn_category: IucnCategory,
+    #[max_len(50)]
+    pub region: String,
+    #[max_len(200)]
+    pub metadata_uri: String,
+
+    /// Number of pools created for this species across all organizations
+    pub pool_count: u32,
+    pub registered_by: Pubkey,
+    pub created_at: u64,
+
+    pub bump: u8,
+}
+impl Species {
+    /// Scientific name with surrounding whitespace trimmed and inner runs
+    /// collapsed to a single space, as stored on the registry entry
+    pub fn normalize_name(scientific_name: &str) -> String {
+        scientific_name
+            .split_whitespace()
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+
+    /// Species ID: SHA-256 of the normalized, lowercased scientific name,
+    /// so "Panthera leo" and " panthera  LEO" map to the same entry
+    pub fn id_for(scientific_name: &str) -> [u8; 32] {
+        let canonical = Self::normalize_name(scientific_name).to_lowercase();
+        hash::hash(canonical.as_bytes()).to_bytes()
+    }
+}
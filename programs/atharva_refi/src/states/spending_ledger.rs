@@ -0,0 +1,80 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::{SPENDING_CATEGORIES, SPENDING_LEDGER_LEN};
+use crate::errors::ErrorCode;
+
+/// What an organization withdrawal was spent on
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace, Debug)]
+pub enum SpendingCategory {
+    AntiPoaching,
+    Habitat,
+    Veterinary,
+    Research,
+    Operations,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace, Debug)]
+pub struct SpendingEntry {
+    pub category: SpendingCategory,
+    /// Lamports withdrawn, or the SOL value of withdrawn mSOL
+    pub amount: u64,
+    pub memo_hash: [u8; 32],
+    pub timestamp: u64,
+}
+
+/// Per-pool running totals of organization withdrawals by spending category
+///
+/// Keeps the last `SPENDING_LEDGER_LEN` entries in a ring buffer, oldest
+/// first starting at `head` once the buffer has wrapped. Every entry is
+/// also emitted as a `SpendingRecorded` event
+#[account]
+#[derive(InitSpace)]
+pub struct SpendingLedger {
+    pub pool: Pubkey,
+
+    /// Lamports withdrawn per category, indexed by `SpendingCategory`
+    pub category_totals: [u64; SPENDING_CATEGORIES],
+    pub total_spent: u64,
+    pub entry_count: u64,
+    pub last_entry_ts: u64,
+
+    pub entries: [SpendingEntry; SPENDING_LEDGER_LEN],
+    /// Slot the next entry is written to
+    pub head: u8,
+
+    pub bump: u8,
+}
+impl SpendingLedger {
+    /// Adds a withdrawal to the running totals and the entry ring buffer
+    /// and returns its entry index
+    pub fn record(
+        &mut self,
+        category: SpendingCategory,
+        amount: u64,
+        memo_hash: [u8; 32],
+        now: u64,
+    ) -> Result<u64> {
+        let total = &mut self.category_totals[category as usize];
+        *total = total.checked_add(amount).ok_or(ErrorCode::MathError)?;
+
+        self.total_spent = self
+            .total_spent
+            .checked_add(amount)
+            .ok_or(ErrorCode::MathError)?;
+
+        let head = self.head as usize;
+        self.entries[head] = SpendingEntry {
+            category,
+            amount,
+            memo_hash,
+            timestamp: now,
+        };
+        self.head = ((head + 1) % SPENDING_LEDGER_LEN) as u8;
+
+        let entry = self.entry_count;
+        self.entry_count = entry.checked_add(1).ok_or(ErrorCode::MathError)?;
+        self.last_entry_ts = now;
+
+        Ok(entry)
+    }
+}
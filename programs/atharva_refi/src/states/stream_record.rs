@@ -0,0 +1,26 @@
+use anchor_lang::prelude::*;
+
+/// Permanent on-chain record of a single yield stream to the organization
+#[account]
+#[derive(InitSpace)]
+pub struct StreamRecord {
+    pub pool: Pubkey,
+    pub organization: Pubkey,
+    pub sequence: u64,
+
+    /// Lamports per mSOL, scaled by `MSOL_PRICE_PRECISION`
+    pub msol_price: u128,
+    pub pool_sol_value: u64,
+
+    pub total_yield: u64,
+    /// Organization's calculated share of `total_yield`
+    pub org_amount: u64,
+    /// Lamports actually received from the unstake and paid to the organization
+    /// Zero when the pool pays out in mSOL
+    pub lamports_received: u64,
+    /// mSOL moved to the organization vault when the pool pays out in mSOL
+    pub msol_amount: u64,
+
+    pub timestamp: u64,
+    pub bump: u8,
+}
@@ -0,0 +1,51 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::YIELD_PER_SHARE_PRECISION;
+use crate::errors::ErrorCode;
+
+/// Per-supporter record of shares held in a pool and the yield those
+/// shares have donated to the organization over their lifetime
+#[account]
+#[derive(InitSpace)]
+pub struct SupporterPosition {
+    pub pool: Pubkey,
+    pub supporter: Pubkey,
+
+    pub shares: u64,
+
+    /// Lifetime lamports streamed to the organization on this supporter's behalf
+    pub donated_yield: u64,
+    /// Snapshot of `Pool::donated_yield_per_share` at the last settlement
+    pub donated_yield_checkpoint: u128,
+
+    /// Bitmask of claimed impact badge tiers (bit n = tier n)
+    pub badges_claimed: u8,
+
+    pub bump: u8,
+}
+impl SupporterPosition {
+    /// Credits the yield donated since the last checkpoint to this position
+    ///
+    /// Only shares still held in the supporter's receipt token account
+    /// (`held_shares`) earn credit, so receipts transferred away stop
+    /// counting toward impact badges
+    pub fn settle(&mut self, donated_yield_per_share: u128, held_shares: u64) -> Result<()> {
+        let accrued = (self.shares.min(held_shares) as u128)
+            .checked_mul(
+                donated_yield_per_share
+                    .checked_sub(self.donated_yield_checkpoint)
+                    .ok_or(ErrorCode::MathError)?,
+            )
+            .ok_or(ErrorCode::MathError)?
+            .checked_div(YIELD_PER_SHARE_PRECISION)
+            .ok_or(ErrorCode::MathError)?;
+
+        self.donated_yield = self
+            .donated_yield
+            .checked_add(u64::try_from(accrued).map_err(|_| ErrorCode::MathError)?)
+            .ok_or(ErrorCode::MathError)?;
+        self.donated_yield_checkpoint = donated_yield_per_share;
+
+        Ok(())
+    }
+}
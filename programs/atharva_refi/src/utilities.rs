@@ -1,3 +1,4 @@
+use anchor_lang::{prelude::*, Event};
 use solana_program::hash;
 
 pub fn calculate_ix_discriminator(ix_name: &str) -> Vec<u8> {
@@ -9,3 +10,34 @@ pub fn calculate_ix_discriminator(ix_name: &str) -> Vec<u8> {
 
     hash_result.to_bytes()[..8].to_vec()
 }
+
+/// Emits `event` through `emit_cpi!` from a `process` method
+///
+/// `emit_cpi!` reads the event authority and its bump off `ctx`, which
+/// handlers on the accounts structs don't have, so hand it just those
+pub fn emit_cpi_event<E: Event>(
+    event_authority: &AccountInfo<'_>,
+    event_authority_bump: u8,
+    event: E,
+) -> Result<()> {
+    struct EventAccounts<'a, 'info> {
+        event_authority: &'a AccountInfo<'info>,
+    }
+    struct EventBumps {
+        event_authority: u8,
+    }
+    struct EventContext<'a, 'info> {
+        accounts: EventAccounts<'a, 'info>,
+        bumps: EventBumps,
+    }
+
+    let ctx = EventContext {
+        accounts: EventAccounts { event_authority },
+        bumps: EventBumps {
+            event_authority: event_authority_bump,
+        },
+    };
+    emit_cpi!(event);
+
+    Ok(())
+}
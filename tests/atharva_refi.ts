@@ -2,21 +2,41 @@ import * as anchor from "@coral-xyz/anchor";
 import { AnchorError, BN, Program } from "@coral-xyz/anchor";
 import { AtharvaRefi } from "../target/types/atharva_refi";
 import { Keypair, LAMPORTS_PER_SOL, SystemProgram } from "@solana/web3.js";
+import { fromWorkspace, LiteSVMProvider } from "anchor-litesvm";
 import {
+  cloneAccounts,
   fetchBalance,
   fetchTokenBalance,
   fundAccount,
   getOrCreateAdminWallet,
+  getAttesterPda,
+  getBadgeMintPda,
+  getFieldReportPda,
+  getImpactMetricsPda,
+  getMilestonePda,
+  getOrganizationPda,
+  getSpendingLedgerPda,
+  getSpeciesId,
+  getSpeciesPda,
   getPoolPdas,
+  getPoolHistoryPda,
+  getPoolRegistryPda,
+  getProtocolStatsPda,
+  getEventAuthorityPda,
+  fetchCpiEvents,
+  getRegistryPagePda,
+  getStreamRecordPda,
+  getSupporterPositionPda,
   lamportsToSol,
   logData,
   logDone,
   logSignature,
-  stringToBytes,
+  svmCpiEvents,
 } from "./utilities";
 import {
   ASSOCIATED_TOKEN_PROGRAM_ID,
   getAssociatedTokenAddressSync,
+  TOKEN_2022_PROGRAM_ID,
   TOKEN_PROGRAM_ID,
 } from "@solana/spl-token";
 import {
@@ -27,6 +47,7 @@ import {
   MSOL_LEG_AUTH,
   MSOL_MINT,
   MSOL_MINT_AUTH,
+  EVENT_SCHEMA_VERSION,
   RESERVE_PDA,
   STREAM_TEST_INTERVAL_MS,
   TREASURY_MSOL,
@@ -57,18 +78,37 @@ describe("atharva refi protocol", () => {
   let supporter: Keypair;
   let supporter2: Keypair;
   let organization: Keypair;
+  const milestoneAttester = Keypair.generate();
+  const applicant = Keypair.generate();
+  let organizationPda: anchor.web3.PublicKey;
+  let speciesPda: anchor.web3.PublicKey;
   let supporterPoolTokenAccount: anchor.web3.PublicKey;
   let supporter2PoolTokenAccount: anchor.web3.PublicKey;
+  let supporterPosition: anchor.web3.PublicKey;
+  let supporter2Position: anchor.web3.PublicKey;
   let poolMsolAccount: anchor.web3.PublicKey;
   let poolPda: anchor.web3.PublicKey;
   let poolMintPda: anchor.web3.PublicKey;
   let poolVaultPda: anchor.web3.PublicKey;
   let orgVaultPda: anchor.web3.PublicKey;
+  let escrowVaultPda: anchor.web3.PublicKey;
+  let poolHistoryPda: anchor.web3.PublicKey;
+  const protocolStatsPda = getProtocolStatsPda();
+  const eventCpi = {
+    eventAuthority: getEventAuthorityPda(),
+    program: program.programId,
+  };
 
   const ORGANIZATION_NAME = "Londolozi Reserve";
+  const ORGANIZATION_METADATA_URI = "https://londolozi.com/atharva.json";
   const SPECIES_NAME = "African Lion";
-  const SPECIES_ID = "panthera_leo";
-  const SPECIES_ID_BYTES = stringToBytes(SPECIES_ID, 32);
+  const SPECIES_ID = "Panthera leo";
+  const SPECIES_ID_BYTES = getSpeciesId(SPECIES_ID);
+  const SPECIES_REGION = "Sub-Saharan Africa";
+  const SPECIES_METADATA_URI = "https://www.iucnredlist.org/species/15951";
+  const SECOND_SPECIES_NAME = "Leopard";
+  const SECOND_SPECIES_ID = "Panthera pardus";
+  const SECOND_SPECIES_ID_BYTES = getSpeciesId(SECOND_SPECIES_ID);
 
   const DEPOSIT_AMOUNT = 0.1;
   const STAKE_AMOUNT = 0.05;
@@ -93,11 +133,16 @@ describe("atharva refi protocol", () => {
     await fundAccount(provider.connection, payer, supporter2.publicKey, 0.5);
     await fundAccount(provider.connection, payer, organization.publicKey, 0.01);
 
+    organizationPda = getOrganizationPda(organization.publicKey);
+    speciesPda = getSpeciesPda(SPECIES_ID_BYTES);
+
     const pdas = getPoolPdas(organization.publicKey, SPECIES_ID_BYTES);
     poolPda = pdas.poolPda;
     poolMintPda = pdas.poolMintPda;
     poolVaultPda = pdas.poolVaultPda;
     orgVaultPda = pdas.orgVaultPda;
+    escrowVaultPda = pdas.escrowVaultPda;
+    poolHistoryPda = getPoolHistoryPda(poolPda);
 
     poolMsolAccount = getAssociatedTokenAddressSync(
       MSOL_MINT,
@@ -114,29 +159,389 @@ describe("atharva refi protocol", () => {
       poolMintPda,
       supporter2.publicKey
     );
+
+    supporterPosition = getSupporterPositionPda(poolPda, supporter.publicKey);
+    supporter2Position = getSupporterPositionPda(poolPda, supporter2.publicKey);
   });
 
-  /* CREATE POOL */
-  describe("create pool", () => {
-    it("should create a lion conservation pool", async () => {
+  const getNextStreamRecordPda = async () => {
+    const pool = await program.account.pool.fetch(poolPda);
+    return getStreamRecordPda(poolPda, pool.streamCount);
+  };
+
+  // Forks the live pool and the Marinade accounts it reads into LiteSVM,
+  // where the Marinade state can be rewritten to move the mSOL price
+  const forkPool = async (extraAccounts: anchor.web3.PublicKey[] = []) => {
+    const svm = fromWorkspace(".");
+    await cloneAccounts(provider.connection, svm, [
+      ...extraAccounts,
+      poolPda,
+      poolVaultPda,
+      orgVaultPda,
+      escrowVaultPda,
+      poolMsolAccount,
+      MAR_STATE,
+      MSOL_MINT,
+      LIQ_POOL_SOL_LEG,
+      LIQ_POOL_MSOL_LEG,
+      TREASURY_MSOL,
+    ]);
+
+    const forkProvider = new LiteSVMProvider(svm);
+    svm.airdrop(forkProvider.wallet.publicKey, BigInt(LAMPORTS_PER_SOL));
+    const forkProgram = new Program<AtharvaRefi>(program.idl, forkProvider);
+
+    return { svm, forkProgram };
+  };
+
+  // Scales Marinade's total_virtual_staked_lamports, moving the mSOL price
+  const scaleMsolPrice = (svm: any, numerator: number, denominator: number) => {
+    const state = svm.getAccount(MAR_STATE);
+    const data = Buffer.from(state.data);
+    const staked = data.readBigUInt64LE(376);
+    data.writeBigUInt64LE(
+      (staked * BigInt(numerator)) / BigInt(denominator),
+      376
+    );
+    svm.setAccount(MAR_STATE, { ...state, data });
+  };
+
+  /* ORGANIZATION REGISTRY */
+  describe("organization registry", () => {
+    it("registers and verifies the organization", async () => {
       const txn = await program.methods
-        .createPool(
-          ORGANIZATION_NAME,
+        .registerOrganization(
           organization.publicKey,
+          ORGANIZATION_NAME,
+          ORGANIZATION_METADATA_URI
+        )
+        .accountsStrict({
+          admin: admin.publicKey,
+          organization: organizationPda,
+          systemProgram: SystemProgram.programId,
+          ...eventCpi,
+        })
+        .transaction();
+
+      const signature = await provider.sendAndConfirm(txn, [admin]);
+      logSignature("Register Organization", signature);
+
+      const org = await program.account.organization.fetch(organizationPda);
+      expect(org.organizationPubkey).to.eql(organization.publicKey);
+      expect(org.name).to.eql(ORGANIZATION_NAME);
+      expect(org.metadataUri).to.eql(ORGANIZATION_METADATA_URI);
+      expect(org.status).to.eql({ verified: {} });
+      expect(org.verifiedBy).to.eql(admin.publicKey);
+      expect(org.poolCount).to.equal(0);
+
+      logDone(`${ORGANIZATION_NAME} registered and verified!`);
+    });
+
+    it("should fail when non-admin registers an organization", async () => {
+      const newOrg = Keypair.generate();
+
+      try {
+        await program.methods
+          .registerOrganization(newOrg.publicKey, "Unverified Org", "")
+          .accountsStrict({
+            authority: supporter.publicKey,
+            organization: organizationPda,
+            systemProgram: SystemProgram.programId,
+            ...eventCpi,
+          })
+          .signers([supporter])
+          .rpc();
+
+        expect.fail("Transaction should have failed");
+      } catch (err) {
+        const anchorErr = err as AnchorError;
+        expect(anchorErr.error.errorCode.code).to.equal("AdminUnauthorized");
+      }
+    });
+  });
+
+  /* ORGANIZATION APPLICATIONS */
+  describe("organization applications", () => {
+    const applicantPda = getOrganizationPda(applicant.publicKey);
+    const DOCUMENT_HASH = Array.from({ length: 32 }, (_, i) => i + 1);
+    const REJECTION_REASON = 3;
+
+    const apply = () =>
+      program.methods
+        .applyOrganization(
+          "Okavango Trust",
+          "https://okavango.org/atharva.json",
+          DOCUMENT_HASH,
+          [SPECIES_ID_BYTES]
+        )
+        .accountsStrict({
+          applicant: applicant.publicKey,
+          organization: applicantPda,
+          systemProgram: SystemProgram.programId,
+          ...eventCpi,
+        })
+        .signers([applicant])
+        .rpc();
+
+    before(async () => {
+      await fundAccount(
+        provider.connection,
+        provider.wallet.payer,
+        applicant.publicKey,
+        0.02
+      );
+    });
+
+    it("submits an organization application", async () => {
+      const signature = await apply();
+      logSignature("Apply Organization", signature);
+
+      const org = await program.account.organization.fetch(applicantPda);
+      expect(org.status).to.eql({ pending: {} });
+      expect(org.documentHash).to.eql(DOCUMENT_HASH);
+
+      logDone("Organization application pending review!");
+    });
+
+    it("should fail to apply again while pending", async () => {
+      try {
+        await apply();
+        expect.fail("Transaction should have failed");
+      } catch (err) {
+        const anchorErr = err as AnchorError;
+        expect(anchorErr.error.errorCode.code).to.equal(
+          "OrganizationAlreadyApplied"
+        );
+      }
+    });
+
+    it("rejects the application with a reason code", async () => {
+      await program.methods
+        .rejectOrganization(REJECTION_REASON)
+        .accountsStrict({
+          admin: admin.publicKey,
+          organization: applicantPda,
+          ...eventCpi,
+        })
+        .signers([admin])
+        .rpc();
+
+      const org = await program.account.organization.fetch(applicantPda);
+      expect(org.status).to.eql({ rejected: {} });
+      expect(org.rejectionReason).to.equal(REJECTION_REASON);
+
+      logDone("Organization application rejected!");
+    });
+
+    it("approves a re-submitted application", async () => {
+      await apply();
+
+      await program.methods
+        .approveOrganization()
+        .accountsStrict({
+          admin: admin.publicKey,
+          organization: applicantPda,
+          ...eventCpi,
+        })
+        .signers([admin])
+        .rpc();
+
+      const org = await program.account.organization.fetch(applicantPda);
+      expect(org.status).to.eql({ verified: {} });
+      expect(org.verifiedBy).to.eql(admin.publicKey);
+      expect(org.speciesIds).to.eql([SPECIES_ID_BYTES]);
+
+      logDone("Organization application approved!");
+    });
+
+    it("should fail when non-admin approves an application", async () => {
+      try {
+        await program.methods
+          .approveOrganization()
+          .accountsStrict({
+            admin: applicant.publicKey,
+            organization: applicantPda,
+            ...eventCpi,
+          })
+          .signers([applicant])
+          .rpc();
+
+        expect.fail("Transaction should have failed");
+      } catch (err) {
+        const anchorErr = err as AnchorError;
+        expect(anchorErr.error.errorCode.code).to.equal("AdminUnauthorized");
+      }
+    });
+  });
+
+  /* SPECIES REGISTRY */
+  describe("species registry", () => {
+    it("registers the lion under its canonical species ID", async () => {
+      const txn = await program.methods
+        .registerSpecies(
+          SPECIES_ID,
           SPECIES_NAME,
-          SPECIES_ID_BYTES
+          { vulnerable: {} },
+          SPECIES_REGION,
+          SPECIES_METADATA_URI
+        )
+        .accountsStrict({
+          admin: admin.publicKey,
+          species: speciesPda,
+          systemProgram: SystemProgram.programId,
+          ...eventCpi,
+        })
+        .transaction();
+
+      const signature = await provider.sendAndConfirm(txn, [admin]);
+      logSignature("Register Species", signature);
+
+      const species = await program.account.species.fetch(speciesPda);
+      expect(species.speciesId).to.eql(SPECIES_ID_BYTES);
+      expect(species.scientificName).to.eql(SPECIES_ID);
+      expect(species.commonName).to.eql(SPECIES_NAME);
+      expect(species.iucnCategory).to.eql({ vulnerable: {} });
+      expect(species.region).to.eql(SPECIES_REGION);
+      expect(species.poolCount).to.equal(0);
+
+      logDone(`${SPECIES_NAME} (${SPECIES_ID}) registered!`);
+    });
+
+    it("registers a second species for access tests", async () => {
+      await program.methods
+        .registerSpecies(
+          SECOND_SPECIES_ID,
+          SECOND_SPECIES_NAME,
+          { vulnerable: {} },
+          "Africa and Asia",
+          ""
         )
         .accountsStrict({
           admin: admin.publicKey,
+          species: getSpeciesPda(SECOND_SPECIES_ID_BYTES),
+          systemProgram: SystemProgram.programId,
+          ...eventCpi,
+        })
+        .signers([admin])
+        .rpc();
+
+      const species = await program.account.species.fetch(
+        getSpeciesPda(SECOND_SPECIES_ID_BYTES)
+      );
+      expect(species.commonName).to.eql(SECOND_SPECIES_NAME);
+    });
+
+    it("should fail to register a variant spelling of a species", async () => {
+      const variant = `  ${SPECIES_ID.toUpperCase().split(" ").join("   ")} `;
+      expect(getSpeciesPda(getSpeciesId(variant)).toBase58()).to.equal(
+        getSpeciesPda(SPECIES_ID_BYTES).toBase58()
+      );
+
+      try {
+        await program.methods
+          .registerSpecies(
+            variant,
+            SPECIES_NAME,
+            { vulnerable: {} },
+            SPECIES_REGION,
+            ""
+          )
+          .accountsStrict({
+            admin: admin.publicKey,
+            species: getSpeciesPda(getSpeciesId(variant)),
+            systemProgram: SystemProgram.programId,
+            ...eventCpi,
+          })
+          .signers([admin])
+          .rpc();
+
+        expect.fail("Transaction should have failed");
+      } catch (err) {
+        expect(String(err)).to.include("already in use");
+      }
+    });
+
+    it("should fail when non-admin registers a species", async () => {
+      try {
+        await program.methods
+          .registerSpecies(
+            "Panthera tigris",
+            "Tiger",
+            { endangered: {} },
+            "Asia",
+            ""
+          )
+          .accountsStrict({
+            admin: supporter.publicKey,
+            species: getSpeciesPda(getSpeciesId("Panthera tigris")),
+            systemProgram: SystemProgram.programId,
+            ...eventCpi,
+          })
+          .signers([supporter])
+          .rpc();
+
+        expect.fail("Transaction should have failed");
+      } catch (err) {
+        const anchorErr = err as AnchorError;
+        expect(anchorErr.error.errorCode.code).to.equal("AdminUnauthorized");
+      }
+    });
+  });
+
+  /* CREATE POOL */
+  describe("create pool", () => {
+    const poolRegistryPda = getPoolRegistryPda();
+
+    // Pages that will receive the next listing of each index
+    const getNextRegistryPages = async (species: anchor.web3.PublicKey) => {
+      const PAGE_CAPACITY = 32;
+      const registry = await program.account.poolRegistry.fetchNullable(
+        poolRegistryPda
+      );
+      const speciesAccount = await program.account.species.fetch(species);
+      const org = await program.account.organization.fetch(organizationPda);
+
+      return {
+        registryPage: getRegistryPagePda(
+          poolRegistryPda,
+          Math.floor((registry?.poolCount ?? 0) / PAGE_CAPACITY)
+        ),
+        speciesPage: getRegistryPagePda(
+          species,
+          Math.floor(speciesAccount.poolCount / PAGE_CAPACITY)
+        ),
+        organizationPage: getRegistryPagePda(
+          organizationPda,
+          Math.floor(org.poolCount / PAGE_CAPACITY)
+        ),
+      };
+    };
+
+    it("should create a lion conservation pool", async () => {
+      const pages = await getNextRegistryPages(speciesPda);
+
+      const txn = await program.methods
+        .createPool()
+        .accountsStrict({
+          authority: admin.publicKey,
+          organization: organizationPda,
+          species: speciesPda,
           msolMint: MSOL_MINT,
           pool: poolPda,
           poolMint: poolMintPda,
           poolVault: poolVaultPda,
           organizationVault: orgVaultPda,
+          escrowVault: escrowVaultPda,
+          poolRegistry: poolRegistryPda,
+          registryPage: pages.registryPage,
+          speciesPage: pages.speciesPage,
+          organizationPage: pages.organizationPage,
+          protocolStats: protocolStatsPda,
           poolMsolAccount,
           tokenProgram: TOKEN_PROGRAM_ID,
           associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
           systemProgram: SystemProgram.programId,
+          ...eventCpi,
         })
         .transaction();
 
@@ -145,14 +550,22 @@ describe("atharva refi protocol", () => {
 
       const pool = await program.account.pool.fetch(poolPda);
       expect(pool.organizationPubkey).to.eql(organization.publicKey);
+      expect(pool.organizationName).to.eql(ORGANIZATION_NAME);
       expect(pool.speciesName).to.eql(SPECIES_NAME);
-      expect(pool.speciesId).to.eql(SPECIES_ID);
+      expect(pool.scientificName).to.eql(SPECIES_ID);
+      expect(pool.newSpeciesId).to.eql(SPECIES_ID_BYTES);
       expect(pool.isActive).to.be.true;
       expect(pool.totalDeposits.toNumber()).to.eql(0);
 
       logData(`Organization: ${pool.organizationName}`);
       logData(`Species Name: ${pool.speciesName}`);
-      logData(`Species ID: ${pool.speciesId}`);
+      logData(`Scientific name: ${pool.scientificName}`);
+
+      const species = await program.account.species.fetch(speciesPda);
+      expect(species.poolCount).to.equal(1);
+
+      const stats = await program.account.protocolStats.fetch(protocolStatsPda);
+      expect(stats.poolCount.toNumber()).to.be.greaterThan(0);
 
       logDone(`${pool.organizationName} ${pool.speciesName} Pool Created!`);
     });
@@ -171,26 +584,75 @@ describe("atharva refi protocol", () => {
       logDone("Pool initialized with correct default values!");
     });
 
+    it("should list the pool in the species and organization indexes", async () => {
+      const speciesPage = await program.account.registryPage.fetch(
+        getRegistryPagePda(speciesPda, 0)
+      );
+      expect(speciesPage.index).to.eql({ species: {} });
+      expect(speciesPage.indexKey).to.eql(speciesPda);
+
+      const listing = speciesPage.listings.find((l) => l.pool.equals(poolPda));
+      expect(listing).to.not.be.undefined;
+      expect(listing.status).to.eql({ active: {} });
+      expect(listing.category).to.eql({ vulnerable: {} });
+
+      const orgPage = await program.account.registryPage.fetch(
+        getRegistryPagePda(organizationPda, 0)
+      );
+      expect(orgPage.listings.map((l) => l.pool)).to.deep.include(poolPda);
+
+      const registry = await program.account.poolRegistry.fetch(
+        poolRegistryPda
+      );
+      expect(registry.poolCount).to.be.greaterThan(0);
+
+      logDone("Pool listed in registry pages!");
+    });
+
+    it("should sync the pool listing status from the pool", async () => {
+      const speciesPage = getRegistryPagePda(speciesPda, 0);
+
+      await program.methods
+        .syncPoolListing()
+        .accountsStrict({
+          pool: poolPda,
+          registryPage: speciesPage,
+          ...eventCpi,
+        })
+        .rpc();
+
+      const page = await program.account.registryPage.fetch(speciesPage);
+      const listing = page.listings.find((l) => l.pool.equals(poolPda));
+      expect(listing.status).to.eql({ active: {} });
+    });
+
     it("should fail when non-admin tries to create pool", async () => {
-      const newOrg = Keypair.generate();
-      const newSpeciesBytes = stringToBytes("test_species", 32);
-      const newPdas = getPoolPdas(newOrg.publicKey, newSpeciesBytes);
+      const newPdas = getPoolPdas(
+        organization.publicKey,
+        SECOND_SPECIES_ID_BYTES
+      );
+      const pages = await getNextRegistryPages(
+        getSpeciesPda(SECOND_SPECIES_ID_BYTES)
+      );
 
       try {
         await program.methods
-          .createPool(
-            "Unauthorized Pool",
-            newOrg.publicKey,
-            "Test Species",
-            newSpeciesBytes
-          )
+          .createPool()
           .accountsStrict({
-            admin: supporter.publicKey,
+            authority: supporter.publicKey,
+            organization: organizationPda,
+            species: getSpeciesPda(SECOND_SPECIES_ID_BYTES),
             msolMint: MSOL_MINT,
             pool: newPdas.poolPda,
             poolMint: newPdas.poolMintPda,
             poolVault: newPdas.poolVaultPda,
             organizationVault: newPdas.orgVaultPda,
+            escrowVault: newPdas.escrowVaultPda,
+            poolRegistry: poolRegistryPda,
+            registryPage: pages.registryPage,
+            speciesPage: pages.speciesPage,
+            organizationPage: pages.organizationPage,
+            protocolStats: protocolStatsPda,
             poolMsolAccount: getAssociatedTokenAddressSync(
               MSOL_MINT,
               newPdas.poolVaultPda,
@@ -199,6 +661,7 @@ describe("atharva refi protocol", () => {
             tokenProgram: TOKEN_PROGRAM_ID,
             associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
             systemProgram: SystemProgram.programId,
+            ...eventCpi,
           })
           .signers([supporter])
           .rpc();
@@ -212,12 +675,141 @@ describe("atharva refi protocol", () => {
         logDone("Non-admin correctly prevented from creating pool!");
       }
     });
+
+    it("should fail when an organization creates a pool for an unapproved species", async () => {
+      const applicantPda = getOrganizationPda(applicant.publicKey);
+      const newPdas = getPoolPdas(applicant.publicKey, SECOND_SPECIES_ID_BYTES);
+      const applicantOrg = await program.account.organization.fetch(applicantPda);
+      const pages = await getNextRegistryPages(
+        getSpeciesPda(SECOND_SPECIES_ID_BYTES)
+      );
+
+      try {
+        await program.methods
+          .createPool()
+          .accountsStrict({
+            authority: applicant.publicKey,
+            organization: applicantPda,
+            species: getSpeciesPda(SECOND_SPECIES_ID_BYTES),
+            msolMint: MSOL_MINT,
+            pool: newPdas.poolPda,
+            poolMint: newPdas.poolMintPda,
+            poolVault: newPdas.poolVaultPda,
+            organizationVault: newPdas.orgVaultPda,
+            escrowVault: newPdas.escrowVaultPda,
+            poolRegistry: poolRegistryPda,
+            registryPage: pages.registryPage,
+            speciesPage: pages.speciesPage,
+            organizationPage: getRegistryPagePda(
+              applicantPda,
+              Math.floor(applicantOrg.poolCount / 32)
+            ),
+            protocolStats: protocolStatsPda,
+            poolMsolAccount: getAssociatedTokenAddressSync(
+              MSOL_MINT,
+              newPdas.poolVaultPda,
+              true
+            ),
+            tokenProgram: TOKEN_PROGRAM_ID,
+            associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+            systemProgram: SystemProgram.programId,
+            ...eventCpi,
+          })
+          .signers([applicant])
+          .rpc();
+
+        expect.fail("Transaction should have failed");
+      } catch (err) {
+        const anchorErr = err as AnchorError;
+        expect(anchorErr.error.errorCode.code).to.equal("SpeciesNotApproved");
+      }
+    });
+  });
+
+  /* MILESTONE SETUP */
+  describe("milestone setup", () => {
+    const CRITERIA_HASH = Array.from({ length: 32 }, (_, i) => (i * 3) % 256);
+    const TRANCHE_AMOUNT = new BN(0.01 * LAMPORTS_PER_SOL);
+
+    it("adds an escrow milestone before the pool takes deposits", async () => {
+      await program.methods
+        .setAttester(milestoneAttester.publicKey, true)
+        .accountsStrict({
+          admin: admin.publicKey,
+          attesterAccount: getAttesterPda(milestoneAttester.publicKey),
+          systemProgram: SystemProgram.programId,
+          ...eventCpi,
+        })
+        .signers([admin])
+        .rpc();
+
+      const pool = await program.account.pool.fetch(poolPda);
+      const milestone = getMilestonePda(poolPda, pool.milestoneCount);
+      const deadline = new BN(Math.floor(Date.now() / 1000) + 180 * 86_400);
+
+      await program.methods
+        .addMilestone(TRANCHE_AMOUNT, deadline, CRITERIA_HASH)
+        .accountsStrict({
+          admin: admin.publicKey,
+          pool: poolPda,
+          attesterAccount: getAttesterPda(milestoneAttester.publicKey),
+          milestone,
+          systemProgram: SystemProgram.programId,
+          ...eventCpi,
+        })
+        .signers([admin])
+        .rpc();
+
+      const account = await program.account.milestone.fetch(milestone);
+      expect(account.amount.toString()).to.equal(TRANCHE_AMOUNT.toString());
+      expect(account.status).to.eql({ pending: {} });
+      expect(account.criteriaHash).to.eql(CRITERIA_HASH);
+      expect(account.attester).to.eql(milestoneAttester.publicKey);
+
+      const poolAfter = await program.account.pool.fetch(poolPda);
+      expect(poolAfter.escrowCommitted.toString()).to.equal(
+        TRANCHE_AMOUNT.toString()
+      );
+
+      logDone("Escrow milestone added!");
+    });
+
+    it("adds a second milestone due before the first", async () => {
+      const pool = await program.account.pool.fetch(poolPda);
+      const milestone = getMilestonePda(poolPda, pool.milestoneCount);
+      const deadline = new BN(Math.floor(Date.now() / 1000) + 90 * 86_400);
+
+      await program.methods
+        .addMilestone(TRANCHE_AMOUNT, deadline, CRITERIA_HASH)
+        .accountsStrict({
+          admin: admin.publicKey,
+          pool: poolPda,
+          attesterAccount: getAttesterPda(milestoneAttester.publicKey),
+          milestone,
+          systemProgram: SystemProgram.programId,
+          ...eventCpi,
+        })
+        .signers([admin])
+        .rpc();
+
+      const poolAfter = await program.account.pool.fetch(poolPda);
+      expect(poolAfter.milestoneCount).to.equal(2);
+      expect(poolAfter.nextMilestone).to.equal(0);
+      expect(poolAfter.escrowCommitted.toString()).to.equal(
+        TRANCHE_AMOUNT.muln(2).toString()
+      );
+
+      logDone("Second escrow milestone added!");
+    });
   });
 
   /* DEPOSIT */
   describe("deposit", () => {
     it("should deposit SOL into the pool", async () => {
       const poolBefore = await program.account.pool.fetch(poolPda);
+      const statsBefore = await program.account.protocolStats.fetch(
+        protocolStatsPda
+      );
 
       const txn = await program.methods
         .deposit(new BN(DEPOSIT_AMOUNT * LAMPORTS_PER_SOL))
@@ -227,17 +819,30 @@ describe("atharva refi protocol", () => {
           poolMint: poolMintPda,
           poolVault: poolVaultPda,
           supporterPoolTokenAccount,
+          supporterPosition,
+          protocolStats: protocolStatsPda,
           tokenProgram: TOKEN_PROGRAM_ID,
           associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
           systemProgram: SystemProgram.programId,
+          ...eventCpi,
         })
         .transaction();
 
       const signature = await provider.sendAndConfirm(txn, [supporter]);
       logSignature("Deposit", signature);
 
-      const supporterBalance = await fetchBalance(
-        provider,
+      const [deposited] = (await fetchCpiEvents(program, signature)).filter(
+        (event) => event.name === "supporterDeposited"
+      );
+      expect(deposited.data.version).to.equal(EVENT_SCHEMA_VERSION);
+      expect(deposited.data.pool).to.eql(poolPda);
+      expect(deposited.data.supporter).to.eql(supporter.publicKey);
+      expect(deposited.data.sharesMinted.toNumber()).to.equal(
+        DEPOSIT_AMOUNT * LAMPORTS_PER_SOL
+      );
+
+      const supporterBalance = await fetchBalance(
+        provider,
         supporter.publicKey
       );
       const poolVaultBalance = await fetchBalance(provider, poolVaultPda);
@@ -254,6 +859,16 @@ describe("atharva refi protocol", () => {
         poolBefore.totalShares.toNumber()
       );
 
+      const statsAfter = await program.account.protocolStats.fetch(
+        protocolStatsPda
+      );
+      expect(
+        statsAfter.totalValueLocked.sub(statsBefore.totalValueLocked).toNumber()
+      ).to.equal(DEPOSIT_AMOUNT * LAMPORTS_PER_SOL);
+      expect(
+        statsAfter.supporterCount.sub(statsBefore.supporterCount).toNumber()
+      ).to.equal(1);
+
       logData(`Supporter Balance: ${supporterBalance} SOL`);
       logData(`Pool Vault Balance: ${poolVaultBalance} SOL`);
       logData(`Supporter Token Balance: ${supporterPoolTokenBal} ARFI`);
@@ -282,9 +897,12 @@ describe("atharva refi protocol", () => {
           poolMint: poolMintPda,
           poolVault: poolVaultPda,
           supporterPoolTokenAccount,
+          supporterPosition,
+          protocolStats: protocolStatsPda,
           tokenProgram: TOKEN_PROGRAM_ID,
           associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
           systemProgram: SystemProgram.programId,
+          ...eventCpi,
         })
         .transaction();
 
@@ -307,9 +925,12 @@ describe("atharva refi protocol", () => {
           poolMint: poolMintPda,
           poolVault: poolVaultPda,
           supporterPoolTokenAccount: supporter2PoolTokenAccount,
+          supporterPosition: supporter2Position,
+          protocolStats: protocolStatsPda,
           tokenProgram: TOKEN_PROGRAM_ID,
           associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
           systemProgram: SystemProgram.programId,
+          ...eventCpi,
         })
         .transaction();
 
@@ -337,9 +958,12 @@ describe("atharva refi protocol", () => {
           poolMint: poolMintPda,
           poolVault: poolVaultPda,
           supporterPoolTokenAccount,
+          supporterPosition,
+          protocolStats: protocolStatsPda,
           tokenProgram: TOKEN_PROGRAM_ID,
           associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
           systemProgram: SystemProgram.programId,
+          ...eventCpi,
         })
         .signers([supporter])
         .rpc();
@@ -364,9 +988,12 @@ describe("atharva refi protocol", () => {
             poolMint: poolMintPda,
             poolVault: poolVaultPda,
             supporterPoolTokenAccount,
+            supporterPosition,
+            protocolStats: protocolStatsPda,
             tokenProgram: TOKEN_PROGRAM_ID,
             associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
             systemProgram: SystemProgram.programId,
+            ...eventCpi,
           })
           .signers([supporter])
           .rpc();
@@ -412,6 +1039,7 @@ describe("atharva refi protocol", () => {
           associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
           systemProgram: SystemProgram.programId,
           marinadeProgram: MAR_PROGRAM_ID,
+          ...eventCpi,
         })
         .transaction();
 
@@ -460,6 +1088,7 @@ describe("atharva refi protocol", () => {
           associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
           systemProgram: SystemProgram.programId,
           marinadeProgram: MAR_PROGRAM_ID,
+          ...eventCpi,
         })
         .rpc();
 
@@ -476,6 +1105,160 @@ describe("atharva refi protocol", () => {
     });
   });
 
+  /* POOL HISTORY */
+  describe("pool history", () => {
+    it("should record a pool snapshot", async () => {
+      const txn = await program.methods
+        .snapshot()
+        .accountsStrict({
+          payer: supporter.publicKey,
+          pool: poolPda,
+          poolHistory: poolHistoryPda,
+          marinadeState: MAR_STATE,
+          poolMsolAccount,
+          systemProgram: SystemProgram.programId,
+          ...eventCpi,
+        })
+        .transaction();
+
+      const signature = await provider.sendAndConfirm(txn, [supporter]);
+      logSignature("Snapshot", signature);
+
+      const history = await program.account.poolHistory.fetch(poolHistoryPda);
+      const pool = await program.account.pool.fetch(poolPda);
+      const snapshot = history.snapshots[0];
+
+      expect(history.snapshotCount.toNumber()).to.equal(1);
+      expect(history.head).to.equal(1);
+      expect(snapshot.nav.toNumber()).to.be.greaterThan(0);
+      expect(snapshot.msolPrice.gtn(0)).to.be.true;
+      expect(snapshot.totalShares.eq(pool.totalShares)).to.be.true;
+
+      logDone("Pool snapshot recorded!");
+    });
+
+    it("should fail to snapshot twice within the interval", async () => {
+      try {
+        await program.methods
+          .snapshot()
+          .accountsStrict({
+            payer: supporter.publicKey,
+            pool: poolPda,
+            poolHistory: poolHistoryPda,
+            marinadeState: MAR_STATE,
+            poolMsolAccount,
+            systemProgram: SystemProgram.programId,
+            ...eventCpi,
+          })
+          .signers([supporter])
+          .rpc();
+
+        expect.fail("Transaction should have failed");
+      } catch (err) {
+        const anchorErr = err as AnchorError;
+        expect(anchorErr.error.errorCode.code).to.equal("SnapshotTooFrequent");
+      }
+    });
+  });
+
+  /* QUOTES */
+  describe("quotes", () => {
+    const quoteAccounts = () => ({
+      pool: poolPda,
+      poolMint: poolMintPda,
+      poolVault: poolVaultPda,
+      poolMsolAccount,
+      marinadeState: MAR_STATE,
+      liqPoolSolLeg: LIQ_POOL_SOL_LEG,
+    });
+
+    it("quotes a deposit at one share per lamport", async () => {
+      const amount = new BN(0.1 * LAMPORTS_PER_SOL);
+      const quote = await program.methods
+        .quoteDeposit(amount)
+        .accountsStrict(quoteAccounts())
+        .view();
+
+      const pool = await program.account.pool.fetch(poolPda);
+      expect(quote.shares.eq(amount)).to.be.true;
+      expect(quote.totalSharesAfter.eq(pool.totalShares.add(amount))).to.be
+        .true;
+      expect(quote.ownershipBps.toNumber()).to.be.within(1, 10_000);
+    });
+
+    it("quotes a withdrawal net of the liquid-unstake fee", async () => {
+      const shares = new BN(SUPPORTER_WITHDRAW_AMOUNT * LAMPORTS_PER_SOL);
+      const quote = await program.methods
+        .quoteWithdraw(shares)
+        .accountsStrict(quoteAccounts())
+        .view();
+
+      expect(quote.msolAmount.toNumber()).to.be.greaterThan(0);
+      expect(quote.solValue.toNumber()).to.be.at.least(
+        quote.msolAmount.toNumber()
+      );
+      expect(quote.feeBps).to.be.greaterThan(0);
+      expect(quote.feeMsol.toNumber()).to.be.greaterThan(0);
+      expect(quote.solAmount.toNumber()).to.be.below(
+        quote.solValue.toNumber()
+      );
+
+      logData(
+        `Withdraw quote: ${lamportsToSol(
+          quote.solAmount.toNumber()
+        )} SOL after a ${quote.feeBps} bps fee`
+      );
+    });
+
+    it("reports the pool NAV", async () => {
+      const nav = await program.methods
+        .poolNav()
+        .accountsStrict(quoteAccounts())
+        .view();
+
+      const msolBalance = await provider.connection.getTokenAccountBalance(
+        poolMsolAccount
+      );
+      expect(nav.msolBalance.toString()).to.equal(msolBalance.value.amount);
+      expect(nav.nav.toNumber()).to.be.at.least(nav.msolBalance.toNumber());
+      expect(nav.navPerShare.gtn(0)).to.be.true;
+
+      logData(`Pool NAV: ${lamportsToSol(nav.nav.toNumber())} SOL`);
+    });
+
+    it("reports the organization yield pending for the next stream", async () => {
+      const pending = await program.methods
+        .pendingOrgYield()
+        .accountsStrict(quoteAccounts())
+        .view();
+
+      expect(pending.orgYield.toNumber()).to.be.at.most(
+        pending.totalYield.toNumber()
+      );
+    });
+
+    it("reports a supporter position's value", async () => {
+      const value = await program.methods
+        .positionValue()
+        .accountsStrict({
+          pool: poolPda,
+          poolMint: poolMintPda,
+          supporterPosition,
+          supporterPoolTokenAccount,
+          poolMsolAccount,
+          marinadeState: MAR_STATE,
+        })
+        .view();
+
+      const position = await program.account.supporterPosition.fetch(
+        supporterPosition
+      );
+      expect(value.shares.eq(position.shares)).to.be.true;
+      expect(value.solValue.toNumber()).to.be.greaterThan(0);
+      expect(value.donatedYield.gte(position.donatedYield)).to.be.true;
+    });
+  });
+
   /* STREAM */
   describe("stream", () => {
     it("should stream yield to organization vault", async () => {
@@ -486,6 +1269,7 @@ describe("atharva refi protocol", () => {
         .accountsStrict({
           pool: poolPda,
           organizationVault: orgVaultPda,
+          escrowVault: escrowVaultPda,
           marinadeState: MAR_STATE,
           msolMint: MSOL_MINT,
           liqPoolSolLeg: LIQ_POOL_SOL_LEG,
@@ -496,6 +1280,12 @@ describe("atharva refi protocol", () => {
           tokenProgram: TOKEN_PROGRAM_ID,
           systemProgram: SystemProgram.programId,
           marinadeProgram: MAR_PROGRAM_ID,
+          payer: provider.wallet.publicKey,
+          streamRecord: await getNextStreamRecordPda(),
+          organizationMsolAccount: null,
+          protocolStats: protocolStatsPda,
+          poolHistory: poolHistoryPda,
+          ...eventCpi,
         })
         .transaction();
 
@@ -507,6 +1297,16 @@ describe("atharva refi protocol", () => {
 
       expect(streamed).to.be.greaterThan(0);
 
+      const pool = await program.account.pool.fetch(poolPda);
+      const record = await program.account.streamRecord.fetch(
+        getStreamRecordPda(poolPda, pool.streamCount.subn(1))
+      );
+      expect(record.pool).to.eql(poolPda);
+      expect(lamportsToSol(record.lamportsReceived.toNumber())).to.be.closeTo(
+        streamed,
+        0.000001
+      );
+
       logData(
         `Organization Vault Balance: ${lamportsToSol(orgBalanceAfter)} SOL`
       );
@@ -514,6 +1314,350 @@ describe("atharva refi protocol", () => {
 
       logDone(`Streamed ${streamed.toFixed(4)} SOL to organization vault!`);
     });
+
+    it("should reject a public stream before the cooldown elapses", async () => {
+      try {
+        await program.methods
+          .stream()
+          .accountsStrict({
+            pool: poolPda,
+            organizationVault: orgVaultPda,
+            escrowVault: escrowVaultPda,
+            marinadeState: MAR_STATE,
+            msolMint: MSOL_MINT,
+            liqPoolSolLeg: LIQ_POOL_SOL_LEG,
+            liqPoolMsolLeg: LIQ_POOL_MSOL_LEG,
+            treasuryMsolAccount: TREASURY_MSOL,
+            poolMsolAccount,
+            poolVault: poolVaultPda,
+            tokenProgram: TOKEN_PROGRAM_ID,
+            systemProgram: SystemProgram.programId,
+            marinadeProgram: MAR_PROGRAM_ID,
+            payer: provider.wallet.publicKey,
+            streamRecord: await getNextStreamRecordPda(),
+            organizationMsolAccount: null,
+            protocolStats: protocolStatsPda,
+            poolHistory: poolHistoryPda,
+            ...eventCpi,
+          })
+          .rpc();
+
+        expect.fail("Transaction should have failed");
+      } catch (err) {
+        const anchorErr = err as AnchorError;
+        expect(anchorErr.error.errorCode.code).to.equal(
+          "SettlementTooFrequent"
+        );
+        logDone("Early public stream correctly rejected!");
+      }
+    });
+  });
+
+  /* LOSS TRACKING */
+  describe("loss tracking", () => {
+    it("records a loss below the high-water mark and clears it on recovery", async () => {
+      const { svm, forkProgram } = await forkPool();
+
+      // Past the public stream cooldown
+      const poolBefore = await forkProgram.account.pool.fetch(poolPda);
+      const clock = svm.getClock();
+      clock.unixTimestamp = BigInt(
+        poolBefore.lastStreamTs.add(poolBefore.streamCooldown).addn(1).toString()
+      );
+      svm.setClock(clock);
+
+      const streamInFork = async () => {
+        svm.expireBlockhash();
+        await forkProgram.methods
+          .stream()
+          .accountsStrict({
+            pool: poolPda,
+            organizationVault: orgVaultPda,
+            escrowVault: escrowVaultPda,
+            marinadeState: MAR_STATE,
+            msolMint: MSOL_MINT,
+            liqPoolSolLeg: LIQ_POOL_SOL_LEG,
+            liqPoolMsolLeg: LIQ_POOL_MSOL_LEG,
+            treasuryMsolAccount: TREASURY_MSOL,
+            poolMsolAccount,
+            poolVault: poolVaultPda,
+            tokenProgram: TOKEN_PROGRAM_ID,
+            systemProgram: SystemProgram.programId,
+            marinadeProgram: MAR_PROGRAM_ID,
+            payer: forkProgram.provider.publicKey,
+            streamRecord: getStreamRecordPda(poolPda, poolBefore.streamCount),
+            organizationMsolAccount: null,
+            protocolStats: null,
+            poolHistory: null,
+            ...eventCpi,
+          })
+          .rpc();
+
+        return forkProgram.account.pool.fetch(poolPda);
+      };
+
+      // mSOL drops 1% below the peak
+      scaleMsolPrice(svm, 99, 100);
+      const afterLoss = await streamInFork();
+
+      expect(afterLoss.unrecoveredLoss.toNumber()).to.be.greaterThan(0);
+      expect(afterLoss.peakMsolPrice.eq(poolBefore.peakMsolPrice)).to.be.true;
+      expect(afterLoss.lastStreamTs.eq(poolBefore.lastStreamTs)).to.be.true;
+      expect(afterLoss.streamCount.eq(poolBefore.streamCount)).to.be.true;
+      logData(`Unrecovered Loss: ${afterLoss.unrecoveredLoss} lamports`);
+
+      // mSOL climbs back just past the peak, yield stays below the minimum
+      scaleMsolPrice(svm, 100 * 1001, 99 * 1000);
+      const afterRecovery = await streamInFork();
+
+      expect(afterRecovery.unrecoveredLoss.toNumber()).to.equal(0);
+      expect(afterRecovery.peakMsolPrice.gt(poolBefore.peakMsolPrice)).to.be
+        .true;
+      expect(afterRecovery.streamCount.eq(poolBefore.streamCount)).to.be.true;
+
+      logDone("Loss recorded and cleared on recovery!");
+    });
+  });
+
+  /* SKIPPED STREAMS */
+  describe("skipped streams", () => {
+    // Forks taken right after the stream above hold yield far below
+    // MIN_YIELD_AMOUNT
+
+    const streamAccounts = (payer, streamRecord) => ({
+      pool: poolPda,
+      organizationVault: orgVaultPda,
+      escrowVault: escrowVaultPda,
+      marinadeState: MAR_STATE,
+      msolMint: MSOL_MINT,
+      liqPoolSolLeg: LIQ_POOL_SOL_LEG,
+      liqPoolMsolLeg: LIQ_POOL_MSOL_LEG,
+      treasuryMsolAccount: TREASURY_MSOL,
+      poolMsolAccount,
+      poolVault: poolVaultPda,
+      tokenProgram: TOKEN_PROGRAM_ID,
+      systemProgram: SystemProgram.programId,
+      marinadeProgram: MAR_PROGRAM_ID,
+      payer,
+      streamRecord,
+      organizationMsolAccount: null,
+      protocolStats: null,
+      poolHistory: null,
+      ...eventCpi,
+    });
+
+    // Checks the run only recorded what it observed and paid nothing out
+    const expectSkipped = async (
+      svm,
+      forkProgram,
+      meta,
+      poolBefore,
+      orgBalanceBefore
+    ) => {
+      const [skipped] = svmCpiEvents(forkProgram, meta).filter(
+        (e) => e.name === "streamSkipped"
+      );
+      expect(skipped.data.reason).to.eql({ yieldBelowMinimum: {} });
+      expect(skipped.data.pendingYield.lte(skipped.data.minYield)).to.be.true;
+
+      const pool = await forkProgram.account.pool.fetch(poolPda);
+      expect(pool.lastObservedSolValue.eq(skipped.data.observedSolValue)).to.be
+        .true;
+      expect(pool.lastObservedTs.toString()).to.equal(
+        svm.getClock().unixTimestamp.toString()
+      );
+      expect(pool.lastStreamTs.eq(poolBefore.lastStreamTs)).to.be.true;
+      expect(pool.streamCount.eq(poolBefore.streamCount)).to.be.true;
+      expect(svm.getBalance(orgVaultPda)).to.equal(orgBalanceBefore);
+    };
+
+    it("skips a public stream below the minimum yield without failing", async () => {
+      const { svm, forkProgram } = await forkPool();
+      const poolBefore = await forkProgram.account.pool.fetch(poolPda);
+      const orgBalanceBefore = svm.getBalance(orgVaultPda);
+
+      const clock = svm.getClock();
+      clock.unixTimestamp = BigInt(
+        poolBefore.lastStreamTs.add(poolBefore.streamCooldown).addn(1).toString()
+      );
+      svm.setClock(clock);
+
+      const signature = await forkProgram.methods
+        .stream()
+        .accountsStrict(
+          streamAccounts(
+            forkProgram.provider.publicKey,
+            getStreamRecordPda(poolPda, poolBefore.streamCount)
+          )
+        )
+        .rpc();
+      const meta = svm.getTransaction(
+        anchor.utils.bytes.bs58.decode(signature)
+      );
+
+      await expectSkipped(svm, forkProgram, meta, poolBefore, orgBalanceBefore);
+
+      logDone("Public stream below the minimum recorded and skipped!");
+    });
+
+    it("lets a scheduled crank run succeed below the minimum yield", async () => {
+      const { svm, forkProgram } = await forkPool();
+      svm.withSigverify(false);
+
+      // The crank only runs once scheduled
+      const poolInfo = svm.getAccount(poolPda);
+      const scheduled = program.coder.accounts.decode(
+        "pool",
+        Buffer.from(poolInfo.data)
+      );
+      scheduled.isCrankScheduled = true;
+      const data = Buffer.from(poolInfo.data);
+      (await program.coder.accounts.encode("pool", scheduled)).copy(data);
+      svm.setAccount(poolPda, { ...poolInfo, data });
+
+      const poolBefore = await forkProgram.account.pool.fetch(poolPda);
+      const orgBalanceBefore = svm.getBalance(orgVaultPda);
+
+      // Crank runs carry no payer or record and sign as the pool vault,
+      // which only the MagicBlock scheduler can do, so fake its signature
+      const ix = await forkProgram.methods
+        .stream()
+        .accountsStrict(streamAccounts(null, null))
+        .instruction();
+      ix.keys.find((key) => key.pubkey.equals(poolVaultPda)).isSigner = true;
+
+      const forkPayer = (forkProgram.provider as LiteSVMProvider).wallet.payer;
+      const txn = new anchor.web3.Transaction().add(ix);
+      txn.feePayer = forkPayer.publicKey;
+      txn.recentBlockhash = svm.latestBlockhash();
+      txn.partialSign(forkPayer);
+      txn.addSignature(poolVaultPda, Buffer.alloc(64));
+
+      // Failed runs come back as FailedTransactionMetadata, with an `err()`
+      const meta = svm.sendTransaction(txn);
+      expect(meta.err, "crank run failed").to.be.undefined;
+
+      await expectSkipped(svm, forkProgram, meta, poolBefore, orgBalanceBefore);
+
+      logDone("Crank run below the minimum succeeded as a no-op!");
+    });
+  });
+
+  /* ESCROW VAULT STREAMS */
+  describe("escrow vault streams", () => {
+    // Yield just above MIN_YIELD_AMOUNT; the org's 20% share sits well
+    // below the rent-exempt minimum of a fresh system account
+    const SMALL_YIELD = 2_000_000;
+
+    // Forks the pool past its cooldown with a small pending yield and the
+    // pool fields returned by `overrides` applied
+    const forkSmallStream = async (overrides: (pool) => object) => {
+      const { svm, forkProgram } = await forkPool();
+
+      const poolInfo = svm.getAccount(poolPda);
+      const pool = program.coder.accounts.decode(
+        "pool",
+        Buffer.from(poolInfo.data)
+      );
+      Object.assign(pool, overrides(pool), {
+        lastStreamedVaultSol: pool.lastStreamedVaultSol.subn(SMALL_YIELD),
+      });
+      const data = Buffer.from(poolInfo.data);
+      (await program.coder.accounts.encode("pool", pool)).copy(data);
+      svm.setAccount(poolPda, { ...poolInfo, data });
+
+      const clock = svm.getClock();
+      clock.unixTimestamp = BigInt(
+        pool.lastStreamTs.add(pool.streamCooldown).addn(1).toString()
+      );
+      svm.setClock(clock);
+
+      return { svm, forkProgram, poolBefore: pool };
+    };
+
+    const streamInFork = (forkProgram, poolBefore) =>
+      forkProgram.methods
+        .stream()
+        .accountsStrict({
+          pool: poolPda,
+          organizationVault: orgVaultPda,
+          escrowVault: escrowVaultPda,
+          marinadeState: MAR_STATE,
+          msolMint: MSOL_MINT,
+          liqPoolSolLeg: LIQ_POOL_SOL_LEG,
+          liqPoolMsolLeg: LIQ_POOL_MSOL_LEG,
+          treasuryMsolAccount: TREASURY_MSOL,
+          poolMsolAccount,
+          poolVault: poolVaultPda,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+          marinadeProgram: MAR_PROGRAM_ID,
+          payer: forkProgram.provider.publicKey,
+          streamRecord: getStreamRecordPda(poolPda, poolBefore.streamCount),
+          organizationMsolAccount: null,
+          protocolStats: null,
+          poolHistory: null,
+          ...eventCpi,
+        })
+        .rpc();
+
+    it("funds the escrow vault rent-exempt at pool creation", async () => {
+      const rent = await provider.connection.getMinimumBalanceForRentExemption(
+        0
+      );
+      const pool = await program.account.pool.fetch(poolPda);
+
+      expect(await provider.connection.getBalance(escrowVaultPda)).to.equal(
+        rent + pool.escrowBalance.toNumber() + pool.heldBalance.toNumber()
+      );
+
+      logDone("Escrow vault holds its rent-exempt reserve!");
+    });
+
+    it("streams a small yield into the escrow vault in escrow mode", async () => {
+      const { svm, forkProgram, poolBefore } = await forkSmallStream(() => ({
+        escrowMode: true,
+        fallbackBeneficiary: admin.publicKey,
+      }));
+      const escrowBefore = svm.getBalance(escrowVaultPda);
+      const orgBalanceBefore = svm.getBalance(orgVaultPda);
+
+      await streamInFork(forkProgram, poolBefore);
+
+      const pool = await forkProgram.account.pool.fetch(poolPda);
+      const escrowed = pool.escrowBalance.sub(poolBefore.escrowBalance);
+      expect(escrowed.toNumber()).to.be.greaterThan(0);
+      expect(escrowed.toNumber()).to.be.lessThan(SMALL_YIELD);
+      expect(pool.streamCount.eq(poolBefore.streamCount.addn(1))).to.be.true;
+      expect(svm.getBalance(escrowVaultPda) - escrowBefore).to.equal(
+        BigInt(escrowed.toString())
+      );
+      expect(svm.getBalance(orgVaultPda)).to.equal(orgBalanceBefore);
+
+      logDone(`Escrowed ${escrowed} lamports from a small stream!`);
+    });
+
+    it("holds a small yield in the escrow vault while the org is suspended", async () => {
+      const { svm, forkProgram, poolBefore } = await forkSmallStream((pool) => ({
+        orgStanding: { suspended: {} },
+        suspendedAt: pool.lastStreamTs,
+      }));
+      const escrowBefore = svm.getBalance(escrowVaultPda);
+      const orgBalanceBefore = svm.getBalance(orgVaultPda);
+
+      await streamInFork(forkProgram, poolBefore);
+
+      const pool = await forkProgram.account.pool.fetch(poolPda);
+      const held = pool.heldBalance.sub(poolBefore.heldBalance);
+      expect(held.toNumber()).to.be.greaterThan(0);
+      expect(pool.escrowBalance.eq(poolBefore.escrowBalance)).to.be.true;
+      expect(svm.getBalance(escrowVaultPda) - escrowBefore).to.equal(
+        BigInt(held.toString())
+      );
+      expect(svm.getBalance(orgVaultPda)).to.equal(orgBalanceBefore);
+
+      logDone(`Held ${held} lamports for the suspended organization!`);
+    });
   });
 
   /* DELEGATE */
@@ -538,6 +1682,51 @@ describe("atharva refi protocol", () => {
 
   /* SCHEDULE STREAMS */
   describe("schedule streams", () => {
+    it("should fail when a non-authority schedules the crank", async () => {
+      const txn = await program.methods
+        .scheduleStreams({
+          taskId: new BN(2),
+          executionIntervalMillis: new BN(STREAM_TEST_INTERVAL_MS),
+          iterations: new BN(SCHEDULE_ITERATIONS),
+        })
+        .accountsStrict({
+          authority: supporter.publicKey,
+          pool: poolPda,
+          poolVault: poolVaultPda,
+          organizationVault: orgVaultPda,
+          marinadeState: MAR_STATE,
+          msolMint: MSOL_MINT,
+          liqPoolSolLeg: LIQ_POOL_SOL_LEG,
+          liqPoolMsolLeg: LIQ_POOL_MSOL_LEG,
+          treasuryMsolAccount: TREASURY_MSOL,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          marinadeProgram: MAR_PROGRAM_ID,
+          magicProgram: MAGIC_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+          ...eventCpi,
+        })
+        .transaction();
+
+      txn.feePayer = supporter.publicKey;
+      txn.recentBlockhash = (
+        await providerER.connection.getLatestBlockhash()
+      ).blockhash;
+      txn.sign(supporter);
+
+      const signature = await providerER.connection.sendRawTransaction(
+        txn.serialize(),
+        { skipPreflight: true }
+      );
+      const { value } = await providerER.connection.confirmTransaction(
+        signature
+      );
+
+      expect(value.err).to.not.be.null;
+      const pool = await program.account.pool.fetch(poolPda);
+      expect(pool.isCrankScheduled).to.be.false;
+      logDone("Crank scheduling by a non-authority correctly rejected!");
+    });
+
     it("schedules automatic yield streaming", async () => {
       const orgBalanceBefore = await provider.connection.getBalance(
         orgVaultPda
@@ -562,8 +1751,8 @@ describe("atharva refi protocol", () => {
           tokenProgram: TOKEN_PROGRAM_ID,
           marinadeProgram: MAR_PROGRAM_ID,
           magicProgram: MAGIC_PROGRAM_ID,
-          program: program.programId,
           systemProgram: SystemProgram.programId,
+          ...eventCpi,
         })
         .transaction();
 
@@ -610,6 +1799,37 @@ describe("atharva refi protocol", () => {
     });
   });
 
+  /* CANCEL STREAM SCHEDULE */
+  describe("cancel stream schedule", () => {
+    it("cancels the scheduled yield streaming crank", async () => {
+      const txn = await program.methods
+        .cancelStreamSchedule()
+        .accountsStrict({
+          authority: admin.publicKey,
+          pool: poolPda,
+          poolVault: poolVaultPda,
+          magicProgram: MAGIC_PROGRAM_ID,
+          ...eventCpi,
+        })
+        .transaction();
+
+      txn.feePayer = admin.publicKey;
+      txn.recentBlockhash = (
+        await providerER.connection.getLatestBlockhash()
+      ).blockhash;
+      txn.sign(admin);
+
+      const signature = await providerER.connection.sendRawTransaction(
+        txn.serialize(),
+        { skipPreflight: true }
+      );
+      await providerER.connection.confirmTransaction(signature);
+      logSignature("Cancel Stream Schedule", signature);
+
+      logDone("Stream crank cancelled on ephemeral rollup!");
+    });
+  });
+
   /* UNDELEGATE */
   describe("undelegate", () => {
     it("undelegates pool from ephemeral rollups", async () => {
@@ -666,6 +1886,7 @@ describe("atharva refi protocol", () => {
           tokenProgram: TOKEN_PROGRAM_ID,
           systemProgram: SystemProgram.programId,
           marinadeProgram: MAR_PROGRAM_ID,
+          ...eventCpi,
         })
         .transaction();
 
@@ -691,79 +1912,909 @@ describe("atharva refi protocol", () => {
         )} mSOL`
       );
 
-      logDone(
-        `Unstaked ${UNSTAKE_AMOUNT} mSOL and received ${(
-          poolVaultBalanceAfter - poolVaultBalanceBefore
-        ).toFixed(4)} SOL!`
-      );
+      logDone(
+        `Unstaked ${UNSTAKE_AMOUNT} mSOL and received ${(
+          poolVaultBalanceAfter - poolVaultBalanceBefore
+        ).toFixed(4)} SOL!`
+      );
+    });
+
+    it("should handle multiple unstaking operations", async () => {
+      const additionalUnstake = 0.01;
+      const vaultBalanceBefore = await fetchBalance(provider, poolVaultPda);
+
+      await program.methods
+        .unstake(new BN(additionalUnstake * LAMPORTS_PER_SOL))
+        .accountsStrict({
+          pool: poolPda,
+          marinadeState: MAR_STATE,
+          msolMint: MSOL_MINT,
+          liqPoolSolLeg: LIQ_POOL_SOL_LEG,
+          liqPoolMsolLeg: LIQ_POOL_MSOL_LEG,
+          treasuryMsolAccount: TREASURY_MSOL,
+          poolVault: poolVaultPda,
+          poolMsolAccount,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+          marinadeProgram: MAR_PROGRAM_ID,
+          ...eventCpi,
+        })
+        .rpc();
+
+      const vaultBalance = await fetchBalance(provider, poolVaultPda);
+
+      expect(vaultBalance).to.be.greaterThan(vaultBalanceBefore);
+
+      logDone(
+        `Additional ${additionalUnstake} SOL unstaked, mSOL balance decreased!`
+      );
+    });
+  });
+
+  /* ORGANIZATION WITHDRAW */
+  describe("organization withdraw", () => {
+    const MEMO_HASH = Array.from({ length: 32 }, (_, i) => 32 - i);
+    let spendingLedger: anchor.web3.PublicKey;
+
+    before(() => {
+      spendingLedger = getSpendingLedgerPda(poolPda);
+    });
+
+    it("reports the organization's claimable balance", async () => {
+      const claimable = await program.methods
+        .organizationClaimable()
+        .accountsStrict({
+          pool: poolPda,
+          orgVault: orgVaultPda,
+        })
+        .view();
+
+      const pool = await program.account.pool.fetch(poolPda);
+      const vaultBalance = await provider.connection.getBalance(orgVaultPda);
+      expect(claimable.toNumber()).to.be.at.least(
+        vaultBalance - pool.orgUnvested.toNumber()
+      );
+      expect(claimable.toNumber()).to.be.at.most(vaultBalance);
+
+      logData(`Claimable: ${lamportsToSol(claimable.toNumber())} SOL`);
+    });
+
+    it("withdraws organization yields", async () => {
+      const orgBalanceBefore = await fetchBalance(provider, orgVaultPda);
+
+      const txn = await program.methods
+        .organizationWithdraw(new BN(ORG_WITHDRAW_AMOUNT * LAMPORTS_PER_SOL), { habitat: {} }, MEMO_HASH)
+        .accountsStrict({
+          organization: organization.publicKey,
+          pool: poolPda,
+          orgVault: orgVaultPda,
+          spendingLedger,
+          protocolStats: protocolStatsPda,
+          systemProgram: SystemProgram.programId,
+          ...eventCpi,
+        })
+        .transaction();
+
+      const signature = await provider.sendAndConfirm(txn, [organization]);
+      logSignature("Organization Withdraw", signature);
+
+      const orgBalanceAfter = await fetchBalance(provider, orgVaultPda);
+
+      logData(`Organization Vault Balance: ${orgBalanceAfter} SOL`);
+      logData(`Withdrawn Amount: ${ORG_WITHDRAW_AMOUNT} SOL`);
+
+      const ledger = await program.account.spendingLedger.fetch(spendingLedger);
+      expect(ledger.categoryTotals[1].toNumber()).to.be.at.least(
+        ORG_WITHDRAW_AMOUNT * LAMPORTS_PER_SOL
+      );
+
+      const latest =
+        ledger.entries[(ledger.head + ledger.entries.length - 1) % ledger.entries.length];
+      expect(latest.category).to.eql({ habitat: {} });
+      expect(latest.amount.toNumber()).to.equal(ORG_WITHDRAW_AMOUNT * LAMPORTS_PER_SOL);
+      expect(latest.memoHash).to.eql(MEMO_HASH);
+
+      logDone(`Organization withdrew ${ORG_WITHDRAW_AMOUNT} SOL from yields!`);
+    });
+
+    it("should fail when withdrawing more than balance", async () => {
+      try {
+        await program.methods
+          .organizationWithdraw(new BN(1000 * LAMPORTS_PER_SOL), { habitat: {} }, MEMO_HASH)
+          .accountsStrict({
+            organization: organization.publicKey,
+            pool: poolPda,
+            orgVault: orgVaultPda,
+            spendingLedger,
+            protocolStats: protocolStatsPda,
+            systemProgram: SystemProgram.programId,
+            ...eventCpi,
+          })
+          .signers([organization])
+          .rpc();
+
+        expect.fail("Transaction should have failed");
+      } catch (err) {
+        const anchorErr = err as AnchorError;
+        expect(anchorErr.error.errorCode.code).to.equal(
+          "InsufficientWithdrawFunds"
+        );
+        expect(anchorErr.error.errorMessage).to.equal(
+          "Not enough SOL to withdraw"
+        );
+      }
+    });
+
+    it("should fail when non-organization tries to withdraw", async () => {
+      try {
+        await program.methods
+          .organizationWithdraw(new BN(0.001 * LAMPORTS_PER_SOL), { habitat: {} }, MEMO_HASH)
+          .accountsStrict({
+            organization: supporter.publicKey, // wrong authority
+            pool: poolPda,
+            orgVault: orgVaultPda,
+            spendingLedger,
+            protocolStats: protocolStatsPda,
+            systemProgram: SystemProgram.programId,
+            ...eventCpi,
+          })
+          .signers([supporter])
+          .rpc();
+
+        expect.fail("Transaction should have failed");
+      } catch (err) {
+        const anchorErr = err as AnchorError;
+
+        expect(anchorErr.error.errorCode.code).to.equal(
+          "UnauthorizedOrganization"
+        );
+        expect(anchorErr.error.errorMessage).to.equal(
+          "Only the organization can withdraw"
+        );
+      }
+    });
+  });
+
+  /* FIELD REPORTS */
+  describe("field reports", () => {
+    const CONTENT_HASH = Array.from({ length: 32 }, (_, i) => (i * 7) % 256);
+    const REPORT_URI = "https://londolozi.com/reports/2026-q3.json";
+
+    it("publishes a field report for the pool", async () => {
+      const pool = await program.account.pool.fetch(poolPda);
+      const fieldReport = getFieldReportPda(poolPda, pool.fieldReportCount);
+      const periodEnd = Math.floor(Date.now() / 1000) - 60;
+
+      const txn = await program.methods
+        .publishFieldReport({
+          contentHash: CONTENT_HASH,
+          uri: REPORT_URI,
+          periodStart: new BN(periodEnd - 90 * 86_400),
+          periodEnd: new BN(periodEnd),
+          coveredEntries: { firstEntry: new BN(0), lastEntry: new BN(0) },
+        })
+        .accountsStrict({
+          organization: organization.publicKey,
+          pool: poolPda,
+          fieldReport,
+          spendingLedger: getSpendingLedgerPda(poolPda),
+          systemProgram: SystemProgram.programId,
+          ...eventCpi,
+        })
+        .transaction();
+
+      const signature = await provider.sendAndConfirm(txn, [organization]);
+      logSignature("Publish Field Report", signature);
+
+      const report = await program.account.fieldReport.fetch(fieldReport);
+      expect(report.contentHash).to.eql(CONTENT_HASH);
+      expect(report.uri).to.equal(REPORT_URI);
+      expect(report.sequence.toString()).to.equal(
+        pool.fieldReportCount.toString()
+      );
+
+      const poolAfter = await program.account.pool.fetch(poolPda);
+      expect(poolAfter.reportedEntryCount.toNumber()).to.equal(1);
+
+      logDone("Field report anchored on-chain!");
+    });
+
+    const publishCovering = async (firstEntry: number, lastEntry: number) => {
+      const pool = await program.account.pool.fetch(poolPda);
+      const periodEnd = Math.floor(Date.now() / 1000) - 60;
+
+      return program.methods
+        .publishFieldReport({
+          contentHash: CONTENT_HASH,
+          uri: REPORT_URI,
+          periodStart: new BN(periodEnd - 86_400),
+          periodEnd: new BN(periodEnd),
+          coveredEntries: {
+            firstEntry: new BN(firstEntry),
+            lastEntry: new BN(lastEntry),
+          },
+        })
+        .accountsStrict({
+          organization: organization.publicKey,
+          pool: poolPda,
+          fieldReport: getFieldReportPda(poolPda, pool.fieldReportCount),
+          spendingLedger: getSpendingLedgerPda(poolPda),
+          systemProgram: SystemProgram.programId,
+          ...eventCpi,
+        })
+        .signers([organization])
+        .rpc();
+    };
+
+    it("should fail to cover entries an earlier report covered", async () => {
+      try {
+        await publishCovering(0, 0);
+        expect.fail("Transaction should have failed");
+      } catch (err) {
+        const anchorErr = err as AnchorError;
+        expect(anchorErr.error.errorCode.code).to.equal(
+          "InvalidCoveredEntries"
+        );
+      }
+    });
+
+    it("should fail to cover entries not yet recorded", async () => {
+      const ledger = await program.account.spendingLedger.fetch(
+        getSpendingLedgerPda(poolPda)
+      );
+
+      try {
+        await publishCovering(1, ledger.entryCount.toNumber());
+        expect.fail("Transaction should have failed");
+      } catch (err) {
+        const anchorErr = err as AnchorError;
+        expect(anchorErr.error.errorCode.code).to.equal(
+          "InvalidCoveredEntries"
+        );
+      }
+    });
+
+    it("should fail when non-organization publishes a report", async () => {
+      const pool = await program.account.pool.fetch(poolPda);
+      const periodEnd = Math.floor(Date.now() / 1000) - 60;
+
+      try {
+        await program.methods
+          .publishFieldReport({
+            contentHash: CONTENT_HASH,
+            uri: REPORT_URI,
+            periodStart: new BN(periodEnd - 86_400),
+            periodEnd: new BN(periodEnd),
+            coveredEntries: null,
+          })
+          .accountsStrict({
+            organization: supporter.publicKey,
+            pool: poolPda,
+            fieldReport: getFieldReportPda(poolPda, pool.fieldReportCount),
+            spendingLedger: null,
+            systemProgram: SystemProgram.programId,
+            ...eventCpi,
+          })
+          .signers([supporter])
+          .rpc();
+
+        expect.fail("Transaction should have failed");
+      } catch (err) {
+        const anchorErr = err as AnchorError;
+        expect(anchorErr.error.errorCode.code).to.equal(
+          "UnauthorizedOrganization"
+        );
+      }
+    });
+  });
+
+  /* IMPACT METRICS */
+  describe("impact metrics", () => {
+    const attester = Keypair.generate();
+    const attesterAccount = getAttesterPda(attester.publicKey);
+    const SOURCE_HASH = Array.from({ length: 32 }, (_, i) => 255 - i);
+    const LION_POPULATION = 79;
+
+    let impactMetrics: anchor.web3.PublicKey;
+
+    before(async () => {
+      impactMetrics = getImpactMetricsPda(poolPda);
+      await fundAccount(
+        provider.connection,
+        provider.wallet.payer,
+        attester.publicKey,
+        0.03
+      );
+    });
+
+    const recordPopulation = (signer: Keypair) =>
+      program.methods
+        .recordImpactMetric(
+          { populationCount: {} },
+          new BN(LION_POPULATION),
+          new BN(Math.floor(Date.now() / 1000) - 3600),
+          SOURCE_HASH
+        )
+        .accountsStrict({
+          attester: signer.publicKey,
+          attesterAccount: getAttesterPda(signer.publicKey),
+          pool: poolPda,
+          impactMetrics,
+          systemProgram: SystemProgram.programId,
+          ...eventCpi,
+        })
+        .signers([signer])
+        .rpc();
+
+    it("registers an impact attester", async () => {
+      await program.methods
+        .setAttester(attester.publicKey, true)
+        .accountsStrict({
+          admin: admin.publicKey,
+          attesterAccount,
+          systemProgram: SystemProgram.programId,
+          ...eventCpi,
+        })
+        .signers([admin])
+        .rpc();
+
+      const account = await program.account.attester.fetch(attesterAccount);
+      expect(account.isActive).to.be.true;
+
+      logDone("Impact attester registered!");
+    });
+
+    it("records an attested population count", async () => {
+      const signature = await recordPopulation(attester);
+      logSignature("Record Impact Metric", signature);
+
+      const metrics = await program.account.impactMetrics.fetch(impactMetrics);
+      expect(metrics.latestValues[0].toNumber()).to.equal(LION_POPULATION);
+      expect(metrics.entryCount.toNumber()).to.be.greaterThan(0);
+
+      logDone(`Population: ${LION_POPULATION} lions recorded!`);
+    });
+
+    it("should fail when an unregistered key records a metric", async () => {
+      try {
+        await recordPopulation(supporter);
+        expect.fail("Transaction should have failed");
+      } catch (err) {
+        const anchorErr = err as AnchorError;
+        expect(anchorErr.error.errorCode.code).to.equal(
+          "AccountNotInitialized"
+        );
+      }
+    });
+  });
+
+  /* MILESTONE ESCROW */
+  describe("milestone escrow", () => {
+    it("should fail to add a milestone once the pool takes deposits", async () => {
+      const pool = await program.account.pool.fetch(poolPda);
+      const deadline = new BN(Math.floor(Date.now() / 1000) + 180 * 86_400);
+
+      try {
+        await program.methods
+          .addMilestone(
+            new BN(0.01 * LAMPORTS_PER_SOL),
+            deadline,
+            Array.from({ length: 32 }, (_, i) => i + 1)
+          )
+          .accountsStrict({
+            admin: admin.publicKey,
+            pool: poolPda,
+            attesterAccount: getAttesterPda(milestoneAttester.publicKey),
+            milestone: getMilestonePda(poolPda, pool.milestoneCount),
+            systemProgram: SystemProgram.programId,
+            ...eventCpi,
+          })
+          .signers([admin])
+          .rpc();
+
+        expect.fail("Transaction should have failed");
+      } catch (err) {
+        const anchorErr = err as AnchorError;
+        expect(anchorErr.error.errorCode.code).to.equal("MilestonesLocked");
+      }
+    });
+
+    it("resolves milestones in order so a later one cannot take earlier escrow", async () => {
+      const [first, second] = [0, 1].map((i) => getMilestonePda(poolPda, i));
+      const fallback = Keypair.generate();
+      const { svm, forkProgram } = await forkPool([
+        first,
+        second,
+        getAttesterPda(milestoneAttester.publicKey),
+      ]);
+
+      // Escrow holds exactly the first milestone's tranche
+      const firstMilestone = await forkProgram.account.milestone.fetch(first);
+      const secondMilestone = await forkProgram.account.milestone.fetch(second);
+      const poolInfo = svm.getAccount(poolPda);
+      const funded = program.coder.accounts.decode(
+        "pool",
+        Buffer.from(poolInfo.data)
+      );
+      funded.escrowBalance = firstMilestone.amount;
+      funded.fallbackBeneficiary = fallback.publicKey;
+      const data = Buffer.from(poolInfo.data);
+      (await program.coder.accounts.encode("pool", funded)).copy(data);
+      svm.setAccount(poolPda, { ...poolInfo, data });
+      svm.airdrop(escrowVaultPda, BigInt(firstMilestone.amount.toString()));
+
+      // Past the second milestone's deadline, before the first's
+      const clock = svm.getClock();
+      clock.unixTimestamp = BigInt(secondMilestone.deadline.addn(1).toString());
+      svm.setClock(clock);
+
+      const reclaimSecond = () =>
+        forkProgram.methods
+          .reclaimMilestone()
+          .accountsStrict({
+            pool: poolPda,
+            milestone: second,
+            escrowVault: escrowVaultPda,
+            fallbackBeneficiary: fallback.publicKey,
+            systemProgram: SystemProgram.programId,
+            ...eventCpi,
+          })
+          .rpc();
+
+      try {
+        await reclaimSecond();
+        expect.fail("Transaction should have failed");
+      } catch (err) {
+        const anchorErr = err as AnchorError;
+        expect(anchorErr.error.errorCode.code).to.equal(
+          "MilestoneOutOfOrder"
+        );
+      }
+
+      const orgBalanceBefore = svm.getBalance(orgVaultPda);
+      await forkProgram.methods
+        .attestMilestone(Array.from({ length: 32 }, (_, i) => i + 7))
+        .accountsStrict({
+          attester: milestoneAttester.publicKey,
+          attesterAccount: getAttesterPda(milestoneAttester.publicKey),
+          pool: poolPda,
+          milestone: first,
+          escrowVault: escrowVaultPda,
+          organizationVault: orgVaultPda,
+          systemProgram: SystemProgram.programId,
+          ...eventCpi,
+        })
+        .signers([milestoneAttester])
+        .rpc();
+      expect(svm.getBalance(orgVaultPda) - orgBalanceBefore).to.equal(
+        BigInt(firstMilestone.amount.toString())
+      );
+
+      // Nothing is left backing the second tranche
+      svm.expireBlockhash();
+      await reclaimSecond();
+
+      const pool = await forkProgram.account.pool.fetch(poolPda);
+      expect(pool.nextMilestone).to.equal(2);
+      expect(pool.escrowBalance.toNumber()).to.equal(0);
+      expect(pool.escrowCommitted.toNumber()).to.equal(0);
+      expect(
+        (await forkProgram.account.milestone.fetch(second)).status
+      ).to.eql({ reclaimed: {} });
+
+      logDone("Milestones resolved in order!");
+    });
+
+    it("should fail when non-admin enables escrow mode", async () => {
+      try {
+        await program.methods
+          .setEscrowMode(true, supporter.publicKey)
+          .accountsStrict({
+            admin: supporter.publicKey,
+            pool: poolPda,
+            ...eventCpi,
+          })
+          .signers([supporter])
+          .rpc();
+
+        expect.fail("Transaction should have failed");
+      } catch (err) {
+        const anchorErr = err as AnchorError;
+        expect(anchorErr.error.errorCode.code).to.equal("AdminUnauthorized");
+      }
+    });
+  });
+
+  /* ORGANIZATION STANDING */
+  describe("organization standing", () => {
+    // The lion pool was the first listing on every index
+    const listingPages = () => ({
+      registryPage: getRegistryPagePda(getPoolRegistryPda(), 0),
+      speciesPage: getRegistryPagePda(speciesPda, 0),
+      organizationPage: getRegistryPagePda(organizationPda, 0),
+    });
+
+    it("should fail when non-admin suspends the organization", async () => {
+      try {
+        await program.methods
+          .suspendOrganization()
+          .accountsStrict({
+            admin: supporter.publicKey,
+            pool: poolPda,
+            ...listingPages(),
+            ...eventCpi,
+          })
+          .signers([supporter])
+          .rpc();
+
+        expect.fail("Transaction should have failed");
+      } catch (err) {
+        const anchorErr = err as AnchorError;
+        expect(anchorErr.error.errorCode.code).to.equal("AdminUnauthorized");
+      }
+    });
+
+    it("should fail to sweep held funds of an active pool", async () => {
+      const pool = await program.account.pool.fetch(poolPda);
+      expect(pool.orgStanding).to.eql({ active: {} });
+
+      try {
+        await program.methods
+          .sweepHeldFunds()
+          .accountsStrict({
+            pool: poolPda,
+            escrowVault: escrowVaultPda,
+            fallbackBeneficiary: pool.reassignedTo,
+            systemProgram: SystemProgram.programId,
+            ...eventCpi,
+          })
+          .rpc();
+
+        expect.fail("Transaction should have failed");
+      } catch (err) {
+        const anchorErr = err as AnchorError;
+        expect(anchorErr.error.errorCode.code).to.equal("PoolNotReassigned");
+      }
+    });
+
+    it("reassigns a suspended pool without touching the milestone fallback", async () => {
+      const fallbackOrganizationPda = getOrganizationPda(applicant.publicKey);
+      const milestoneFallback = Keypair.generate().publicKey;
+      const pages = listingPages();
+      const { svm, forkProgram } = await forkPool([
+        fallbackOrganizationPda,
+        ...Object.values(pages),
+      ]);
+
+      const poolInfo = svm.getAccount(poolPda);
+      const suspended = program.coder.accounts.decode(
+        "pool",
+        Buffer.from(poolInfo.data)
+      );
+      suspended.orgStanding = { suspended: {} };
+      suspended.suspendedAt = suspended.lastStreamTs;
+      suspended.fallbackBeneficiary = milestoneFallback;
+      const data = Buffer.from(poolInfo.data);
+      (await program.coder.accounts.encode("pool", suspended)).copy(data);
+      svm.setAccount(poolPda, { ...poolInfo, data });
+
+      // Past the suspension review window
+      const clock = svm.getClock();
+      clock.unixTimestamp = BigInt(
+        suspended.suspendedAt.addn(7 * 86_400).toString()
+      );
+      svm.setClock(clock);
+
+      await forkProgram.methods
+        .reassignToFallback()
+        .accountsStrict({
+          admin: admin.publicKey,
+          pool: poolPda,
+          ...pages,
+          fallbackOrganization: fallbackOrganizationPda,
+          organizationVault: orgVaultPda,
+          escrowVault: escrowVaultPda,
+          systemProgram: SystemProgram.programId,
+          ...eventCpi,
+        })
+        .signers([admin])
+        .rpc();
+
+      const pool = await forkProgram.account.pool.fetch(poolPda);
+      expect(pool.orgStanding).to.eql({ reassigned: {} });
+      expect(pool.reassignedTo).to.eql(applicant.publicKey);
+      expect(pool.fallbackBeneficiary).to.eql(milestoneFallback);
+
+      for (const page of Object.values(pages)) {
+        const { listings } = await forkProgram.account.registryPage.fetch(page);
+        const listing = listings.find((l) => l.pool.equals(poolPda));
+        expect(listing.status).to.eql({ reassigned: {} });
+      }
+
+      logDone("Pool reassigned, milestone fallback kept!");
+    });
+  });
+
+  /* WITHDRAWAL CONTROLS */
+  describe("withdrawal controls", () => {
+    const WITHDRAW_CAP = new BN(0.05 * LAMPORTS_PER_SOL);
+    const WITHDRAW_WINDOW = new BN(86_400);
+    const WITHDRAW_DELAY = new BN(172_800);
+
+    const setControls = (
+      authority: Keypair,
+      cap: BN,
+      window: BN,
+      delay: BN
+    ) =>
+      program.methods
+        .setWithdrawalControls(cap, window, delay)
+        .accountsStrict({
+          authority: authority.publicKey,
+          pool: poolPda,
+          ...eventCpi,
+        })
+        .signers([authority])
+        .rpc();
+
+    it("lets the organization tighten its withdrawal controls", async () => {
+      await setControls(
+        organization,
+        WITHDRAW_CAP,
+        WITHDRAW_WINDOW,
+        WITHDRAW_DELAY
+      );
+
+      const pool = await program.account.pool.fetch(poolPda);
+      expect(pool.withdrawCap.toString()).to.equal(WITHDRAW_CAP.toString());
+      expect(pool.withdrawDelay.toString()).to.equal(WITHDRAW_DELAY.toString());
+
+      logDone("Withdrawal cap and queue delay configured!");
+    });
+
+    it("keeps the window counters when the organization lengthens its window", async () => {
+      const WINDOW_TEST_AMOUNT = new BN(1_000);
+
+      await program.methods
+        .organizationWithdraw(
+          WINDOW_TEST_AMOUNT,
+          { operations: {} },
+          Array.from({ length: 32 }, () => 7)
+        )
+        .accountsStrict({
+          organization: organization.publicKey,
+          pool: poolPda,
+          orgVault: orgVaultPda,
+          spendingLedger: getSpendingLedgerPda(poolPda),
+          protocolStats: protocolStatsPda,
+          systemProgram: SystemProgram.programId,
+          ...eventCpi,
+        })
+        .signers([organization])
+        .rpc();
+
+      const before = await program.account.pool.fetch(poolPda);
+      expect(before.withdrawnInWindow.toString()).to.equal(
+        WINDOW_TEST_AMOUNT.toString()
+      );
+
+      await setControls(
+        organization,
+        WITHDRAW_CAP,
+        WITHDRAW_WINDOW.addn(1),
+        WITHDRAW_DELAY
+      );
+
+      const after = await program.account.pool.fetch(poolPda);
+      expect(after.withdrawWindow.toString()).to.equal(
+        WITHDRAW_WINDOW.addn(1).toString()
+      );
+      expect(after.withdrawnInWindow.toString()).to.equal(
+        before.withdrawnInWindow.toString()
+      );
+      expect(after.withdrawWindowStart.toString()).to.equal(
+        before.withdrawWindowStart.toString()
+      );
+
+      logDone("Window counters survive an organization window change!");
+    });
+
+    it("should fail when the organization loosens its controls", async () => {
+      try {
+        await setControls(
+          organization,
+          WITHDRAW_CAP.muln(2),
+          WITHDRAW_WINDOW,
+          WITHDRAW_DELAY
+        );
+
+        expect.fail("Transaction should have failed");
+      } catch (err) {
+        const anchorErr = err as AnchorError;
+        expect(anchorErr.error.errorCode.code).to.equal(
+          "WithdrawalControlsLoosened"
+        );
+      }
+    });
+
+    it("lets the admin lift the withdrawal controls", async () => {
+      await setControls(admin, new BN(0), WITHDRAW_WINDOW, new BN(0));
+
+      const pool = await program.account.pool.fetch(poolPda);
+      expect(pool.withdrawCap.toNumber()).to.equal(0);
+      expect(pool.withdrawDelay.toNumber()).to.equal(0);
+
+      logDone("Withdrawal controls lifted by admin!");
+    });
+  });
+
+  /* PAYOUT ASSET */
+  describe("payout asset", () => {
+    it("should fail when non-organization sets the payout asset", async () => {
+      try {
+        await program.methods
+          .setPayoutAsset({ msol: {} })
+          .accountsStrict({
+            authority: supporter.publicKey,
+            pool: poolPda,
+            organizationVault: orgVaultPda,
+            msolMint: MSOL_MINT,
+            organizationMsolAccount: getAssociatedTokenAddressSync(
+              MSOL_MINT,
+              orgVaultPda,
+              true
+            ),
+            tokenProgram: TOKEN_PROGRAM_ID,
+            associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+            systemProgram: SystemProgram.programId,
+            ...eventCpi,
+          })
+          .signers([supporter])
+          .rpc();
+
+        expect.fail("Transaction should have failed");
+      } catch (err) {
+        const anchorErr = err as AnchorError;
+        expect(anchorErr.error.errorCode.code).to.equal(
+          "PoolConfigUnauthorized"
+        );
+        logDone("Payout asset correctly restricted to admin or organization!");
+      }
+    });
+  });
+
+  /* ORGANIZATION AUTHORITY ROTATION */
+  describe("organization authority rotation", () => {
+    const newAuthority = Keypair.generate();
+    const MIN_RECOVERY_DELAY = new BN(86_400);
+
+    it("proposes a new organization authority", async () => {
+      await program.methods
+        .proposeOrganizationAuthority(newAuthority.publicKey)
+        .accountsStrict({
+          authority: organization.publicKey,
+          pool: poolPda,
+          ...eventCpi,
+        })
+        .signers([organization])
+        .rpc();
+
+      const pool = await program.account.pool.fetch(poolPda);
+      expect(pool.organizationAuthority).to.eql(organization.publicKey);
+      expect(pool.pendingOrganizationAuthority).to.eql(newAuthority.publicKey);
+      expect(pool.authorityRotationEta.toNumber()).to.be.greaterThan(0);
+
+      logDone("Organization authority rotation proposed!");
+    });
+
+    it("should fail to accept before the timelock elapses", async () => {
+      try {
+        await program.methods
+          .acceptOrganizationAuthority()
+          .accountsStrict({
+            newAuthority: newAuthority.publicKey,
+            pool: poolPda,
+            ...eventCpi,
+          })
+          .signers([newAuthority])
+          .rpc();
+
+        expect.fail("Transaction should have failed");
+      } catch (err) {
+        const anchorErr = err as AnchorError;
+        expect(anchorErr.error.errorCode.code).to.equal(
+          "AuthorityRotationTimelocked"
+        );
+      }
     });
 
-    it("should handle multiple unstaking operations", async () => {
-      const additionalUnstake = 0.01;
-      const vaultBalanceBefore = await fetchBalance(provider, poolVaultPda);
+    it("should fail when non-organization proposes an authority", async () => {
+      try {
+        await program.methods
+          .proposeOrganizationAuthority(supporter.publicKey)
+          .accountsStrict({
+            authority: supporter.publicKey,
+            pool: poolPda,
+            ...eventCpi,
+          })
+          .signers([supporter])
+          .rpc();
+
+        expect.fail("Transaction should have failed");
+      } catch (err) {
+        const anchorErr = err as AnchorError;
+        expect(anchorErr.error.errorCode.code).to.equal(
+          "PoolConfigUnauthorized"
+        );
+      }
+    });
 
+    it("cancels the pending rotation as admin", async () => {
       await program.methods
-        .unstake(new BN(additionalUnstake * LAMPORTS_PER_SOL))
+        .cancelOrganizationAuthorityRotation()
         .accountsStrict({
+          authority: admin.publicKey,
           pool: poolPda,
-          marinadeState: MAR_STATE,
-          msolMint: MSOL_MINT,
-          liqPoolSolLeg: LIQ_POOL_SOL_LEG,
-          liqPoolMsolLeg: LIQ_POOL_MSOL_LEG,
-          treasuryMsolAccount: TREASURY_MSOL,
-          poolVault: poolVaultPda,
-          poolMsolAccount,
-          tokenProgram: TOKEN_PROGRAM_ID,
-          systemProgram: SystemProgram.programId,
-          marinadeProgram: MAR_PROGRAM_ID,
+          ...eventCpi,
         })
+        .signers([admin])
         .rpc();
 
-      const vaultBalance = await fetchBalance(provider, poolVaultPda);
-
-      expect(vaultBalance).to.be.greaterThan(vaultBalanceBefore);
-
-      logDone(
-        `Additional ${additionalUnstake} SOL unstaked, mSOL balance decreased!`
+      const pool = await program.account.pool.fetch(poolPda);
+      expect(pool.pendingOrganizationAuthority).to.eql(
+        anchor.web3.PublicKey.default
       );
-    });
-  });
 
-  /* ORGANIZATION WITHDRAW */
-  describe("organization withdraw", () => {
-    it("withdraws organization yields", async () => {
-      const orgBalanceBefore = await fetchBalance(provider, orgVaultPda);
+      logDone("Organization authority rotation cancelled!");
+    });
 
-      const txn = await program.methods
-        .organizationWithdraw(new BN(ORG_WITHDRAW_AMOUNT * LAMPORTS_PER_SOL))
+    it("proposes a recovery authority as admin", async () => {
+      await program.methods
+        .proposeOrganizationAuthority(newAuthority.publicKey)
         .accountsStrict({
-          organization: organization.publicKey,
+          authority: admin.publicKey,
           pool: poolPda,
-          orgVault: orgVaultPda,
-          systemProgram: SystemProgram.programId,
+          ...eventCpi,
         })
-        .transaction();
+        .signers([admin])
+        .rpc();
 
-      const signature = await provider.sendAndConfirm(txn, [organization]);
-      logSignature("Organization Withdraw", signature);
+      const pool = await program.account.pool.fetch(poolPda);
+      expect(pool.pendingOrganizationAuthority).to.eql(newAuthority.publicKey);
+      expect(pool.authorityRotationByAdmin).to.be.true;
 
-      const orgBalanceAfter = await fetchBalance(provider, orgVaultPda);
+      logDone("Recovery authority proposed!");
+    });
 
-      logData(`Organization Vault Balance: ${orgBalanceAfter} SOL`);
-      logData(`Withdrawn Amount: ${ORG_WITHDRAW_AMOUNT} SOL`);
+    it("should fail when the organization cancels an admin recovery", async () => {
+      try {
+        await program.methods
+          .cancelOrganizationAuthorityRotation()
+          .accountsStrict({
+            authority: organization.publicKey,
+            pool: poolPda,
+            ...eventCpi,
+          })
+          .signers([organization])
+          .rpc();
 
-      logDone(`Organization withdrew ${ORG_WITHDRAW_AMOUNT} SOL from yields!`);
+        expect.fail("Transaction should have failed");
+      } catch (err) {
+        const anchorErr = err as AnchorError;
+        expect(anchorErr.error.errorCode.code).to.equal(
+          "AdminRotationPending"
+        );
+      }
     });
 
-    it("should fail when withdrawing more than balance", async () => {
+    it("should fail when the organization overwrites an admin recovery", async () => {
       try {
         await program.methods
-          .organizationWithdraw(new BN(1000 * LAMPORTS_PER_SOL))
+          .proposeOrganizationAuthority(supporter.publicKey)
           .accountsStrict({
-            organization: organization.publicKey,
+            authority: organization.publicKey,
             pool: poolPda,
-            orgVault: orgVaultPda,
-            systemProgram: SystemProgram.programId,
+            ...eventCpi,
           })
           .signers([organization])
           .rpc();
@@ -772,68 +2823,141 @@ describe("atharva refi protocol", () => {
       } catch (err) {
         const anchorErr = err as AnchorError;
         expect(anchorErr.error.errorCode.code).to.equal(
-          "InsufficientWithdrawFunds"
-        );
-        expect(anchorErr.error.errorMessage).to.equal(
-          "Not enough SOL to withdraw"
+          "AdminRotationPending"
         );
       }
     });
 
-    it("should fail when non-organization tries to withdraw", async () => {
+    it("should fail when the admin approves a recovery below the minimum delay", async () => {
       try {
         await program.methods
-          .organizationWithdraw(new BN(0.001 * LAMPORTS_PER_SOL))
+          .approveOrganizationAuthorityRotation(new BN(0))
           .accountsStrict({
-            organization: supporter.publicKey, // wrong authority
+            admin: admin.publicKey,
             pool: poolPda,
-            orgVault: orgVaultPda,
-            systemProgram: SystemProgram.programId,
+            ...eventCpi,
           })
-          .signers([supporter])
+          .signers([admin])
           .rpc();
 
         expect.fail("Transaction should have failed");
       } catch (err) {
         const anchorErr = err as AnchorError;
-
         expect(anchorErr.error.errorCode.code).to.equal(
-          "UnauthorizedOrganization"
-        );
-        expect(anchorErr.error.errorMessage).to.equal(
-          "Only the organization can withdraw"
+          "RecoveryDelayTooShort"
         );
       }
     });
+
+    it("accepts the recovery once the shortened timelock elapses", async () => {
+      const poolBefore = await program.account.pool.fetch(poolPda);
+
+      await program.methods
+        .approveOrganizationAuthorityRotation(MIN_RECOVERY_DELAY)
+        .accountsStrict({
+          admin: admin.publicKey,
+          pool: poolPda,
+          ...eventCpi,
+        })
+        .signers([admin])
+        .rpc();
+
+      const approved = await program.account.pool.fetch(poolPda);
+      expect(approved.authorityRotationEta.lt(poolBefore.authorityRotationEta))
+        .to.be.true;
+
+      // Accepted on a fork, past the shortened ETA
+      const { svm, forkProgram } = await forkPool();
+      const clock = svm.getClock();
+      clock.unixTimestamp = BigInt(approved.authorityRotationEta.toString());
+      svm.setClock(clock);
+
+      await forkProgram.methods
+        .acceptOrganizationAuthority()
+        .accountsStrict({
+          newAuthority: newAuthority.publicKey,
+          pool: poolPda,
+          ...eventCpi,
+        })
+        .signers([newAuthority])
+        .rpc();
+
+      const pool = await forkProgram.account.pool.fetch(poolPda);
+      expect(pool.organizationAuthority).to.eql(newAuthority.publicKey);
+      expect(pool.authorityRotationByAdmin).to.be.false;
+
+      logDone("Organization authority recovered by the admin!");
+    });
   });
 
   /* SUPPORTER WITHDRAW */
   describe("supporter withdraw", () => {
-    it("withdraws supporter stake and yields", async () => {
-      const supporterBalanceBefore = await fetchBalance(
-        provider,
-        supporter.publicKey
-      );
-      const poolVaultBalanceBefore = await fetchBalance(provider, poolVaultPda);
+    const shares = new BN(SUPPORTER_WITHDRAW_AMOUNT * LAMPORTS_PER_SOL);
+
+    const withdrawAccounts = () => ({
+      supporter: supporter.publicKey,
+      pool: poolPda,
+      poolMint: poolMintPda,
+      supporterPoolTokenAccount,
+      supporterPosition,
+      protocolStats: protocolStatsPda,
+      marinadeState: MAR_STATE,
+      msolMint: MSOL_MINT,
+      liqPoolSolLeg: LIQ_POOL_SOL_LEG,
+      liqPoolMsolLeg: LIQ_POOL_MSOL_LEG,
+      treasuryMsolAccount: TREASURY_MSOL,
+      poolMsolAccount,
+      poolVault: poolVaultPda,
+      systemProgram: SystemProgram.programId,
+      tokenProgram: TOKEN_PROGRAM_ID,
+      marinadeProgram: MAR_PROGRAM_ID,
+      ...eventCpi,
+    });
 
-      const txn = await program.methods
-        .supporterWithdraw(new BN(SUPPORTER_WITHDRAW_AMOUNT * LAMPORTS_PER_SOL))
+    const quoteWithdraw = () =>
+      program.methods
+        .quoteWithdraw(shares)
         .accountsStrict({
-          supporter: supporter.publicKey,
           pool: poolPda,
           poolMint: poolMintPda,
-          supporterPoolTokenAccount,
+          poolVault: poolVaultPda,
+          poolMsolAccount,
           marinadeState: MAR_STATE,
-          msolMint: MSOL_MINT,
           liqPoolSolLeg: LIQ_POOL_SOL_LEG,
-          liqPoolMsolLeg: LIQ_POOL_MSOL_LEG,
-          treasuryMsolAccount: TREASURY_MSOL,
-          poolMsolAccount,
-          poolVault: poolVaultPda,
-          systemProgram: SystemProgram.programId,
-          tokenProgram: TOKEN_PROGRAM_ID,
-          marinadeProgram: MAR_PROGRAM_ID,
         })
+        .view();
+
+    it("should fail when the minimum SOL out is above the quote", async () => {
+      const quote = await quoteWithdraw();
+
+      try {
+        await program.methods
+          .supporterWithdraw(shares, quote.solValue.addn(1))
+          .accountsStrict(withdrawAccounts())
+          .signers([supporter])
+          .rpc();
+
+        expect.fail("Transaction should have failed");
+      } catch (err) {
+        const anchorErr = err as AnchorError;
+        expect(anchorErr.error.errorCode.code).to.equal("SlippageExceeded");
+      }
+    });
+
+    it("withdraws supporter stake and yields", async () => {
+      const supporterBalanceBefore = await fetchBalance(
+        provider,
+        supporter.publicKey
+      );
+      const quote = await quoteWithdraw();
+      const poolBefore = await program.account.pool.fetch(poolPda);
+      const statsBefore = await program.account.protocolStats.fetch(
+        protocolStatsPda
+      );
+
+      const txn = await program.methods
+        .supporterWithdraw(shares, quote.solAmount)
+        .accountsStrict(withdrawAccounts())
         .transaction();
 
       const signature = await provider.sendAndConfirm(txn, [supporter]);
@@ -845,6 +2969,22 @@ describe("atharva refi protocol", () => {
       );
       const poolVaultBalanceAfter = await fetchBalance(provider, poolVaultPda);
 
+      // Only the principal behind the burned shares leaves the totals
+      const principal = poolBefore.totalDeposits
+        .mul(shares)
+        .div(poolBefore.totalShares);
+      const pool = await program.account.pool.fetch(poolPda);
+      const stats = await program.account.protocolStats.fetch(protocolStatsPda);
+      expect(poolBefore.totalDeposits.sub(pool.totalDeposits).toString()).to.equal(
+        principal.toString()
+      );
+      expect(poolBefore.totalShares.sub(pool.totalShares).toString()).to.equal(
+        shares.toString()
+      );
+      expect(
+        statsBefore.totalValueLocked.sub(stats.totalValueLocked).toString()
+      ).to.equal(principal.toString());
+
       logData(`Supporter Balance: ${supporterBalanceAfter} SOL`);
       logData(`Pool Vault Balance: ${poolVaultBalanceAfter} SOL`);
       logData(
@@ -857,6 +2997,87 @@ describe("atharva refi protocol", () => {
     });
   });
 
+  /* IMPACT BADGES */
+  describe("impact badges", () => {
+    const BADGE_THRESHOLDS = [1, 5, 10].map(
+      (sol) => new BN(sol * LAMPORTS_PER_SOL)
+    );
+
+    it("sets badge thresholds for the pool", async () => {
+      await program.methods
+        .setBadgeThresholds(BADGE_THRESHOLDS)
+        .accountsStrict({
+          admin: admin.publicKey,
+          pool: poolPda,
+          ...eventCpi,
+        })
+        .signers([admin])
+        .rpc();
+
+      const pool = await program.account.pool.fetch(poolPda);
+      expect(pool.badgeThresholds.map((t) => t.toString())).to.eql(
+        BADGE_THRESHOLDS.map((t) => t.toString())
+      );
+
+      logDone("Badge thresholds configured!");
+    });
+
+    it("should fail when non-admin sets badge thresholds", async () => {
+      try {
+        await program.methods
+          .setBadgeThresholds(BADGE_THRESHOLDS)
+          .accountsStrict({
+            admin: supporter.publicKey,
+            pool: poolPda,
+            ...eventCpi,
+          })
+          .signers([supporter])
+          .rpc();
+
+        expect.fail("Transaction should have failed");
+      } catch (err) {
+        const anchorErr = err as AnchorError;
+        expect(anchorErr.error.errorCode.code).to.equal("AdminUnauthorized");
+      }
+    });
+
+    it("should fail to claim a badge below the tier threshold", async () => {
+      const badgeMint = getBadgeMintPda(poolPda, supporter.publicKey, 2);
+
+      try {
+        await program.methods
+          .claimImpactBadge(2)
+          .accountsStrict({
+            supporter: supporter.publicKey,
+            pool: poolPda,
+            supporterPosition,
+            supporterPoolTokenAccount,
+            badgeMint,
+            supporterBadgeAccount: getAssociatedTokenAddressSync(
+              badgeMint,
+              supporter.publicKey,
+              false,
+              TOKEN_2022_PROGRAM_ID
+            ),
+            token2022Program: TOKEN_2022_PROGRAM_ID,
+            associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+            systemProgram: SystemProgram.programId,
+            ...eventCpi,
+          })
+          .signers([supporter])
+          .rpc();
+
+        expect.fail("Transaction should have failed");
+      } catch (err) {
+        const anchorErr = err as AnchorError;
+        expect(anchorErr.error.errorCode.code).to.equal(
+          "BadgeThresholdNotReached"
+        );
+        logDone("Badge claim correctly gated by donated yield!");
+      }
+    });
+  });
+
   describe("complete lifecycle", () => {
     it("should handle full deposit-stake-stream-withdraw cycle", async () => {
       const newSupporter = Keypair.generate();
@@ -871,6 +3092,10 @@ describe("atharva refi protocol", () => {
         poolMintPda,
         newSupporter.publicKey
       );
+      const newSupporterPosition = getSupporterPositionPda(
+        poolPda,
+        newSupporter.publicKey
+      );
 
       // Deposit
       const depositAmount = 0.5;
@@ -882,9 +3107,12 @@ describe("atharva refi protocol", () => {
           poolMint: poolMintPda,
           poolVault: poolVaultPda,
           supporterPoolTokenAccount: newSupporterTokenAccount,
+          supporterPosition: newSupporterPosition,
+          protocolStats: protocolStatsPda,
           tokenProgram: TOKEN_PROGRAM_ID,
           associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
           systemProgram: SystemProgram.programId,
+          ...eventCpi,
         })
         .signers([newSupporter])
         .rpc();
@@ -907,27 +3135,42 @@ describe("atharva refi protocol", () => {
           associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
           systemProgram: SystemProgram.programId,
           marinadeProgram: MAR_PROGRAM_ID,
+          ...eventCpi,
         })
         .rpc();
 
-      // Stream yields
-      await program.methods
-        .stream()
-        .accountsStrict({
-          pool: poolPda,
-          organizationVault: orgVaultPda,
-          marinadeState: MAR_STATE,
-          msolMint: MSOL_MINT,
-          liqPoolSolLeg: LIQ_POOL_SOL_LEG,
-          liqPoolMsolLeg: LIQ_POOL_MSOL_LEG,
-          treasuryMsolAccount: TREASURY_MSOL,
-          poolMsolAccount,
-          poolVault: poolVaultPda,
-          tokenProgram: TOKEN_PROGRAM_ID,
-          systemProgram: SystemProgram.programId,
-          marinadeProgram: MAR_PROGRAM_ID,
-        })
-        .rpc();
+      // Stream yields (public calls are rate limited by the pool cooldown)
+      try {
+        await program.methods
+          .stream()
+          .accountsStrict({
+            pool: poolPda,
+            organizationVault: orgVaultPda,
+            escrowVault: escrowVaultPda,
+            marinadeState: MAR_STATE,
+            msolMint: MSOL_MINT,
+            liqPoolSolLeg: LIQ_POOL_SOL_LEG,
+            liqPoolMsolLeg: LIQ_POOL_MSOL_LEG,
+            treasuryMsolAccount: TREASURY_MSOL,
+            poolMsolAccount,
+            poolVault: poolVaultPda,
+            tokenProgram: TOKEN_PROGRAM_ID,
+            systemProgram: SystemProgram.programId,
+            marinadeProgram: MAR_PROGRAM_ID,
+            payer: provider.wallet.publicKey,
+            streamRecord: await getNextStreamRecordPda(),
+            organizationMsolAccount: null,
+            protocolStats: protocolStatsPda,
+            poolHistory: poolHistoryPda,
+            ...eventCpi,
+          })
+          .rpc();
+      } catch (err) {
+        const anchorErr = err as AnchorError;
+        expect(anchorErr.error.errorCode.code).to.equal(
+          "SettlementTooFrequent"
+        );
+      }
 
       // Withdraw
       const balanceBefore = await fetchBalance(
@@ -936,12 +3179,14 @@ describe("atharva refi protocol", () => {
       );
 
       await program.methods
-        .supporterWithdraw(new BN(0.1 * LAMPORTS_PER_SOL))
+        .supporterWithdraw(new BN(0.1 * LAMPORTS_PER_SOL), new BN(0))
         .accountsStrict({
           supporter: newSupporter.publicKey,
           pool: poolPda,
           poolMint: poolMintPda,
           supporterPoolTokenAccount: newSupporterTokenAccount,
+          supporterPosition: newSupporterPosition,
+          protocolStats: protocolStatsPda,
           marinadeState: MAR_STATE,
           msolMint: MSOL_MINT,
           liqPoolSolLeg: LIQ_POOL_SOL_LEG,
@@ -952,6 +3197,7 @@ describe("atharva refi protocol", () => {
           systemProgram: SystemProgram.programId,
           tokenProgram: TOKEN_PROGRAM_ID,
           marinadeProgram: MAR_PROGRAM_ID,
+          ...eventCpi,
         })
         .signers([newSupporter])
         .rpc();
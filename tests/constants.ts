@@ -6,6 +6,23 @@ export const POOL_SEED = "pool";
 export const POOL_VAULT_SEED = "pool_vault";
 export const POOL_MINT_SEED = "pool_mint";
 export const ORG_VAULT_SEED = "organization_vault";
+export const ORGANIZATION_SEED = "organization";
+export const SPECIES_SEED = "species";
+export const POOL_REGISTRY_SEED = "pool_registry";
+export const REGISTRY_PAGE_SEED = "registry_page";
+export const PROTOCOL_STATS_SEED = "protocol_stats";
+export const POOL_HISTORY_SEED = "pool_history";
+export const ESCROW_VAULT_SEED = "escrow_vault";
+export const MILESTONE_SEED = "milestone";
+export const SUPPORTER_POSITION_SEED = "supporter_position";
+export const BADGE_MINT_SEED = "badge_mint";
+export const STREAM_RECORD_SEED = "stream_record";
+export const SPENDING_LEDGER_SEED = "spending_ledger";
+export const FIELD_REPORT_SEED = "field_report";
+export const ATTESTER_SEED = "attester";
+export const IMPACT_METRICS_SEED = "impact_metrics";
+export const EVENT_AUTHORITY_SEED = "__event_authority";
+export const EVENT_SCHEMA_VERSION = 1;
 export const PROGRAM_ID = new PublicKey(idl.address);
 export const STREAM_INTERVAL_MS = 172_800_000; // 2 days in milliseconds
 export const STREAM_TEST_INTERVAL_MS = 5000; // 5 seconds for testing
@@ -11,10 +11,27 @@ import {
   POOL_SEED,
   POOL_VAULT_SEED,
   ORG_VAULT_SEED,
+  ORGANIZATION_SEED,
+  SPECIES_SEED,
+  POOL_REGISTRY_SEED,
+  REGISTRY_PAGE_SEED,
+  PROTOCOL_STATS_SEED,
+  POOL_HISTORY_SEED,
+  ESCROW_VAULT_SEED,
+  MILESTONE_SEED,
   walletPath,
   POOL_MINT_SEED,
+  SUPPORTER_POSITION_SEED,
+  BADGE_MINT_SEED,
+  STREAM_RECORD_SEED,
+  SPENDING_LEDGER_SEED,
+  FIELD_REPORT_SEED,
+  ATTESTER_SEED,
+  IMPACT_METRICS_SEED,
+  EVENT_AUTHORITY_SEED,
 } from "./constants";
 import fs from "fs";
+import { createHash } from "crypto";
 import * as anchor from "@coral-xyz/anchor";
 
 // Simulate wait times for rollup processing
@@ -78,6 +95,25 @@ export function loadAccount(
   }
 }
 
+// Copy live accounts into a LiteSVM instance
+export async function cloneAccounts(
+  connection: anchor.web3.Connection,
+  svm: any,
+  addresses: PublicKey[]
+) {
+  for (const address of addresses) {
+    const info = await connection.getAccountInfo(address);
+    if (!info) continue;
+
+    svm.setAccount(address, {
+      lamports: info.lamports,
+      data: info.data,
+      owner: info.owner,
+      executable: info.executable,
+    });
+  }
+}
+
 export function getOrCreateAdminWallet(): Keypair {
   if (fs.existsSync(walletPath)) {
     return loadKeypairFromFile(walletPath);
@@ -117,6 +153,13 @@ export function bytesToString(bytes: number[] | Uint8Array): string {
   return validBytes.toString("utf-8");
 }
 
+// Canonical species ID: SHA-256 of the trimmed, whitespace-collapsed,
+// lowercased scientific name (mirrors `Species::id_for`)
+export function getSpeciesId(scientificName: string): number[] {
+  const canonical = scientificName.trim().split(/\s+/).join(" ").toLowerCase();
+  return Array.from(createHash("sha256").update(canonical).digest());
+}
+
 // Get PDAs
 export const getPoolPdas = (
   organizationPubkey: PublicKey,
@@ -139,12 +182,188 @@ export const getPoolPdas = (
     PROGRAM_ID
   );
 
+  const [escrowVaultPda] = PublicKey.findProgramAddressSync(
+    [
+      Buffer.from(ESCROW_VAULT_SEED),
+      organizationPubkey.toBuffer(),
+      speciesSeed,
+    ],
+    PROGRAM_ID
+  );
+
   const [poolMintPda] = PublicKey.findProgramAddressSync(
     [Buffer.from(POOL_MINT_SEED), organizationPubkey.toBuffer(), speciesSeed],
     PROGRAM_ID
   );
 
-  return { poolPda, poolMintPda, poolVaultPda, orgVaultPda };
+  return { poolPda, poolMintPda, poolVaultPda, orgVaultPda, escrowVaultPda };
+};
+
+export const getOrganizationPda = (organizationPubkey: PublicKey) => {
+  const [organizationPda] = PublicKey.findProgramAddressSync(
+    [Buffer.from(ORGANIZATION_SEED), organizationPubkey.toBuffer()],
+    PROGRAM_ID
+  );
+
+  return organizationPda;
+};
+
+export const getSpeciesPda = (speciesIdBytes: number[]) => {
+  const [speciesPda] = PublicKey.findProgramAddressSync(
+    [Buffer.from(SPECIES_SEED), Buffer.from(speciesIdBytes)],
+    PROGRAM_ID
+  );
+
+  return speciesPda;
+};
+
+export const getPoolRegistryPda = () => {
+  const [poolRegistryPda] = PublicKey.findProgramAddressSync(
+    [Buffer.from(POOL_REGISTRY_SEED)],
+    PROGRAM_ID
+  );
+
+  return poolRegistryPda;
+};
+
+export const getProtocolStatsPda = () => {
+  const [protocolStatsPda] = PublicKey.findProgramAddressSync(
+    [Buffer.from(PROTOCOL_STATS_SEED)],
+    PROGRAM_ID
+  );
+
+  return protocolStatsPda;
+};
+
+// Signs the self-CPI that `emit_cpi!` events are logged through
+export const getEventAuthorityPda = () => {
+  const [eventAuthorityPda] = PublicKey.findProgramAddressSync(
+    [Buffer.from(EVENT_AUTHORITY_SEED)],
+    PROGRAM_ID
+  );
+
+  return eventAuthorityPda;
+};
+
+export const getPoolHistoryPda = (poolPda: PublicKey) => {
+  const [poolHistoryPda] = PublicKey.findProgramAddressSync(
+    [Buffer.from(POOL_HISTORY_SEED), poolPda.toBuffer()],
+    PROGRAM_ID
+  );
+
+  return poolHistoryPda;
+};
+
+// Index key is the pool registry, species or organization account
+export const getRegistryPagePda = (indexKey: PublicKey, page: number) => {
+  const pageBuffer = Buffer.alloc(4);
+  pageBuffer.writeUInt32LE(page);
+
+  const [registryPagePda] = PublicKey.findProgramAddressSync(
+    [Buffer.from(REGISTRY_PAGE_SEED), indexKey.toBuffer(), pageBuffer],
+    PROGRAM_ID
+  );
+
+  return registryPagePda;
+};
+
+export const getSupporterPositionPda = (
+  poolPda: PublicKey,
+  supporterPubkey: PublicKey
+) => {
+  const [supporterPositionPda] = PublicKey.findProgramAddressSync(
+    [
+      Buffer.from(SUPPORTER_POSITION_SEED),
+      poolPda.toBuffer(),
+      supporterPubkey.toBuffer(),
+    ],
+    PROGRAM_ID
+  );
+
+  return supporterPositionPda;
+};
+
+export const getBadgeMintPda = (
+  poolPda: PublicKey,
+  supporterPubkey: PublicKey,
+  tier: number
+) => {
+  const [badgeMintPda] = PublicKey.findProgramAddressSync(
+    [
+      Buffer.from(BADGE_MINT_SEED),
+      poolPda.toBuffer(),
+      supporterPubkey.toBuffer(),
+      Buffer.from([tier]),
+    ],
+    PROGRAM_ID
+  );
+
+  return badgeMintPda;
+};
+
+export const getStreamRecordPda = (
+  poolPda: PublicKey,
+  sequence: anchor.BN
+) => {
+  const [streamRecordPda] = PublicKey.findProgramAddressSync(
+    [
+      Buffer.from(STREAM_RECORD_SEED),
+      poolPda.toBuffer(),
+      sequence.toArrayLike(Buffer, "le", 8),
+    ],
+    PROGRAM_ID
+  );
+
+  return streamRecordPda;
+};
+
+export const getSpendingLedgerPda = (poolPda: PublicKey) => {
+  const [spendingLedgerPda] = PublicKey.findProgramAddressSync(
+    [Buffer.from(SPENDING_LEDGER_SEED), poolPda.toBuffer()],
+    PROGRAM_ID
+  );
+
+  return spendingLedgerPda;
+};
+
+export const getFieldReportPda = (poolPda: PublicKey, sequence: anchor.BN) => {
+  const [fieldReportPda] = PublicKey.findProgramAddressSync(
+    [
+      Buffer.from(FIELD_REPORT_SEED),
+      poolPda.toBuffer(),
+      sequence.toArrayLike(Buffer, "le", 8),
+    ],
+    PROGRAM_ID
+  );
+
+  return fieldReportPda;
+};
+
+export const getAttesterPda = (attesterPubkey: PublicKey) => {
+  const [attesterPda] = PublicKey.findProgramAddressSync(
+    [Buffer.from(ATTESTER_SEED), attesterPubkey.toBuffer()],
+    PROGRAM_ID
+  );
+
+  return attesterPda;
+};
+
+export const getImpactMetricsPda = (poolPda: PublicKey) => {
+  const [impactMetricsPda] = PublicKey.findProgramAddressSync(
+    [Buffer.from(IMPACT_METRICS_SEED), poolPda.toBuffer()],
+    PROGRAM_ID
+  );
+
+  return impactMetricsPda;
+};
+
+export const getMilestonePda = (poolPda: PublicKey, index: number) => {
+  const [milestonePda] = PublicKey.findProgramAddressSync(
+    [Buffer.from(MILESTONE_SEED), poolPda.toBuffer(), Buffer.from([index])],
+    PROGRAM_ID
+  );
+
+  return milestonePda;
 };
 
 // Fund an account with SOL
@@ -213,6 +432,53 @@ export async function fetchTokenBalance(
   return balance.value.uiAmount;
 }
 
+// Sha256("anchor:event")[..8], the tag on `emit_cpi!` self-CPIs
+const EVENT_IX_TAG = Buffer.from("e445a52e51cb9a1d", "hex");
+
+// Decodes the events a transaction emitted through `emit_cpi!`
+export async function fetchCpiEvents(
+  program: anchor.Program<any>,
+  signature: string
+): Promise<anchor.Event[]> {
+  const txn = await program.provider.connection.getTransaction(signature, {
+    commitment: "confirmed",
+    maxSupportedTransactionVersion: 0,
+  });
+  const accountKeys = txn.transaction.message.getAccountKeys();
+
+  return (txn.meta.innerInstructions ?? [])
+    .flatMap((inner) => inner.instructions)
+    .filter((ix) =>
+      accountKeys.get(ix.programIdIndex).equals(program.programId)
+    )
+    .map((ix) => Buffer.from(anchor.utils.bytes.bs58.decode(ix.data)))
+    .filter((data) => data.subarray(0, 8).equals(EVENT_IX_TAG))
+    .map((data) =>
+      program.coder.events.decode(
+        anchor.utils.bytes.base64.encode(data.subarray(8))
+      )
+    )
+    .filter((event) => event !== null);
+}
+
+// Anchor CPI events from a transaction sent straight to LiteSVM
+export function svmCpiEvents(
+  program: anchor.Program<any>,
+  meta: any
+): anchor.Event[] {
+  return meta
+    .innerInstructions()
+    .flat()
+    .map((inner) => Buffer.from(inner.instruction().data()))
+    .filter((data) => data.subarray(0, 8).equals(EVENT_IX_TAG))
+    .map((data) =>
+      program.coder.events.decode(
+        anchor.utils.bytes.base64.encode(data.subarray(8))
+      )
+    )
+    .filter((event) => event !== null);
+}
+
 export function logSignature(label: string, signature: string) {
   console.log(`\n${label} Txn Signature: ${signature}`);
 }